@@ -0,0 +1,117 @@
+use serde_json::{Map, Value};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+// The messages of the TypeScript submodule, so that codes and texts follow upstream.
+const UPSTREAM_DIAGNOSTIC_MESSAGES: &str = "TypeScript/src/compiler/diagnosticMessages.json";
+// A vendored copy, used only when the submodule isn't checked out.
+const VENDORED_DIAGNOSTIC_MESSAGES: &str = "src/compiler/diagnosticMessages.json";
+// Messages reported by this crate that the pinned TypeScript version doesn't have, merged into
+// whichever of the above is used.
+const EXTRA_DIAGNOSTIC_MESSAGES: &str = "src/compiler/extraDiagnosticMessages.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", UPSTREAM_DIAGNOSTIC_MESSAGES);
+    println!("cargo:rerun-if-changed={}", VENDORED_DIAGNOSTIC_MESSAGES);
+    println!("cargo:rerun-if-changed={}", EXTRA_DIAGNOSTIC_MESSAGES);
+
+    let source = if Path::new(UPSTREAM_DIAGNOSTIC_MESSAGES).is_file() {
+        UPSTREAM_DIAGNOSTIC_MESSAGES
+    } else {
+        VENDORED_DIAGNOSTIC_MESSAGES
+    };
+    let mut messages = read_messages(source);
+    for (message, details) in read_messages(EXTRA_DIAGNOSTIC_MESSAGES) {
+        if !messages.contains_key(&message) {
+            if let Some((existing, _)) = messages
+                .iter()
+                .find(|(_, existing)| existing["code"] == details["code"])
+            {
+                panic!(
+                    "{} reuses code {} of {:?} in {}",
+                    EXTRA_DIAGNOSTIC_MESSAGES, details["code"], existing, source
+                );
+            }
+            messages.insert(message, details);
+        }
+    }
+    let mut messages: Vec<(&String, &Value)> = messages.iter().collect();
+    messages.sort_by_key(|(_, details)| details["code"].as_u64());
+
+    let mut output = format!(
+        "// <auto-generated />\n// generated from '{}' and '{}' by 'build.rs'\n\n",
+        source, EXTRA_DIAGNOSTIC_MESSAGES
+    );
+    for (message, details) in messages {
+        let code = details["code"]
+            .as_u64()
+            .expect("diagnostic code must be a number");
+        let category = details["category"]
+            .as_str()
+            .expect("diagnostic category must be a string");
+        let reports_unnecessary = details["reportsUnnecessary"].as_bool().unwrap_or(false);
+        let name = convert_property_name(message);
+        writeln!(
+            output,
+            "pub const {}: DiagnosticMessage = DiagnosticMessage {{ key: \"{}_{}\", category: DiagnosticCategory::{}, code: {}, message: {:?}, reports_unnecessary: {} }};",
+            name.to_uppercase(),
+            name,
+            code,
+            category,
+            code,
+            message,
+            reports_unnecessary,
+        )
+        .unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("diagnostic_information_map.rs"),
+        output,
+    )
+    .unwrap();
+}
+
+fn read_messages(path: &str) -> Map<String, Value> {
+    let json = fs::read_to_string(path).expect("cannot read diagnostic messages");
+    match serde_json::from_str(&json).expect("invalid diagnostic messages") {
+        Value::Object(messages) => messages,
+        _ => panic!("diagnostic messages must be an object"),
+    }
+}
+
+/// Turns a message into an identifier the same way TypeScript's processDiagnosticMessages does,
+/// e.g. `'*/' expected.` becomes `Asterisk_Slash_expected`.
+fn convert_property_name(original_name: &str) -> String {
+    let mapped: String = original_name
+        .chars()
+        .map(|ch| match ch {
+            '*' => String::from("_Asterisk"),
+            '/' => String::from("_Slash"),
+            ':' => String::from("_Colon"),
+            _ if ch.is_ascii_alphanumeric() || ch == '_' => ch.to_string(),
+            _ => String::from("_"),
+        })
+        .collect();
+
+    // get rid of all multi-underscores
+    let mut result = String::new();
+    for ch in mapped.chars() {
+        if !(ch == '_' && result.ends_with('_')) {
+            result.push(ch);
+        }
+    }
+
+    // remove any leading underscore, unless it is followed by a number.
+    if result.starts_with('_') && !result[1..].starts_with(|ch: char| ch.is_ascii_digit()) {
+        result.remove(0);
+    }
+    // get rid of all trailing underscores.
+    if result.ends_with('_') {
+        result.pop();
+    }
+    result
+}
@@ -0,0 +1,718 @@
+use crate::types::{
+    LanguageVariant, ModifierFlags, NodeFlags, ScriptTarget, SyntaxKind, TokenFlags,
+};
+use std::ops::{Index, IndexMut};
+
+/// The index of a node in its `NodeArena`. Ids are only meaningful for the arena that allocated
+/// them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A list of child nodes, together with the span of source it was parsed from. The span of an
+/// empty list is where the list would have been.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeList {
+    pub pos: usize,
+    pub end: usize,
+    pub nodes: Vec<NodeId>,
+    pub has_trailing_comma: bool,
+}
+
+impl NodeList {
+    pub fn new(pos: usize, end: usize, nodes: Vec<NodeId>) -> NodeList {
+        NodeList {
+            pos,
+            end,
+            nodes,
+            has_trailing_comma: false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes.iter().cloned()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub kind: SyntaxKind,
+    pub pos: usize,
+    pub end: usize,
+    pub flags: NodeFlags,
+    pub decorators: Option<NodeList>,
+    pub modifiers: Option<NodeList>,
+    /// Set by `NodeArena::alloc` once the parent itself is allocated; `None` for the root.
+    pub parent: Option<NodeId>,
+    pub data: NodeData,
+}
+
+impl Node {
+    pub fn new(kind: SyntaxKind, pos: usize, end: usize, data: NodeData) -> Node {
+        Node {
+            kind,
+            pos,
+            end,
+            flags: NodeFlags::NONE,
+            decorators: None,
+            modifiers: None,
+            parent: None,
+            data,
+        }
+    }
+}
+
+/// The fields of a node that can hold child nodes, so that `NodeData::for_each_child` can be
+/// derived from the field list alone. Fields of any other type have no children.
+trait Children {
+    fn for_each(&self, _cb: &mut dyn FnMut(NodeId)) {}
+}
+
+impl Children for NodeId {
+    fn for_each(&self, cb: &mut dyn FnMut(NodeId)) {
+        cb(*self);
+    }
+}
+
+impl Children for Option<NodeId> {
+    fn for_each(&self, cb: &mut dyn FnMut(NodeId)) {
+        if let Some(id) = self {
+            cb(*id);
+        }
+    }
+}
+
+impl Children for NodeList {
+    fn for_each(&self, cb: &mut dyn FnMut(NodeId)) {
+        for id in &self.nodes {
+            cb(*id);
+        }
+    }
+}
+
+impl Children for Option<NodeList> {
+    fn for_each(&self, cb: &mut dyn FnMut(NodeId)) {
+        if let Some(list) = self {
+            list.for_each(cb);
+        }
+    }
+}
+
+impl Children for String {}
+impl Children for bool {}
+impl Children for SyntaxKind {}
+impl Children for Option<SyntaxKind> {}
+impl Children for TokenFlags {}
+impl Children for ScriptTarget {}
+impl Children for LanguageVariant {}
+
+macro_rules! node_data {
+    ($($(#[$attr:meta])* $variant:ident { $($(#[$field_attr:meta])* $field:ident: $ty:ty),* $(,)? },)*) => {
+        /// The kind-specific part of a node. Kinds that share an interface in TypeScript share a
+        /// variant here, and `Node::kind` tells them apart. Fields are named after their
+        /// TypeScript counterparts, with `type` spelled `ty`.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum NodeData {
+            $($(#[$attr])* $variant { $($(#[$field_attr])* $field: $ty),* },)*
+        }
+
+        impl NodeData {
+            /// Calls `cb` with every child in source order, like `forEachChild`, not including
+            /// the decorators and modifiers that live on `Node`.
+            pub fn for_each_child(&self, cb: &mut dyn FnMut(NodeId)) {
+                match self {
+                    $(NodeData::$variant { $($field),* } => {
+                        $(Children::for_each($field, cb);)*
+                    })*
+                }
+            }
+        }
+    };
+}
+
+node_data! {
+    /// Punctuation, keywords used as modifiers or keyword types, and the `this`, `super`,
+    /// `null`, `true`, `false` and `import` expressions.
+    Token {},
+    /// Nodes with nothing but a kind and a span: `EmptyStatement`, `DebuggerStatement`,
+    /// `OmittedExpression`, `SemicolonClassElement`, `ThisType`, `JsxOpeningFragment`,
+    /// `JsxClosingFragment` and `MissingDeclaration`.
+    Empty {},
+    Identifier {
+        escaped_text: String,
+        original_keyword_kind: Option<SyntaxKind>,
+    },
+    /// Numeric, bigint, string, regular expression and template literals, and `JsxText`.
+    Literal {
+        text: String,
+        token_flags: TokenFlags,
+    },
+
+    // Names
+    QualifiedName {
+        left: NodeId,
+        right: NodeId,
+    },
+    ComputedPropertyName {
+        expression: NodeId,
+    },
+
+    // Signature elements
+    TypeParameter {
+        name: NodeId,
+        constraint: Option<NodeId>,
+        default: Option<NodeId>,
+        /// Only set when a constraint turned out to be an expression, which is an error.
+        expression: Option<NodeId>,
+    },
+    Parameter {
+        dot_dot_dot_token: Option<NodeId>,
+        name: NodeId,
+        question_token: Option<NodeId>,
+        ty: Option<NodeId>,
+        initializer: Option<NodeId>,
+    },
+    Decorator {
+        expression: NodeId,
+    },
+
+    // Type members and class elements
+    PropertySignature {
+        name: NodeId,
+        question_token: Option<NodeId>,
+        ty: Option<NodeId>,
+        initializer: Option<NodeId>,
+    },
+    PropertyDeclaration {
+        name: NodeId,
+        question_token: Option<NodeId>,
+        exclamation_token: Option<NodeId>,
+        ty: Option<NodeId>,
+        initializer: Option<NodeId>,
+    },
+    /// `MethodSignature`, `CallSignature`, `ConstructSignature`, `IndexSignature`,
+    /// `FunctionType` and `ConstructorType`.
+    Signature {
+        name: Option<NodeId>,
+        question_token: Option<NodeId>,
+        type_parameters: Option<NodeList>,
+        parameters: NodeList,
+        ty: Option<NodeId>,
+    },
+    /// `MethodDeclaration`, `Constructor`, `GetAccessor`, `SetAccessor`,
+    /// `FunctionDeclaration`, `FunctionExpression` and `ArrowFunction`.
+    FunctionLike {
+        asterisk_token: Option<NodeId>,
+        name: Option<NodeId>,
+        question_token: Option<NodeId>,
+        exclamation_token: Option<NodeId>,
+        type_parameters: Option<NodeList>,
+        parameters: NodeList,
+        ty: Option<NodeId>,
+        equals_greater_than_token: Option<NodeId>,
+        body: Option<NodeId>,
+    },
+
+    // Types
+    TypePredicate {
+        parameter_name: NodeId,
+        ty: NodeId,
+    },
+    TypeReference {
+        type_name: NodeId,
+        type_arguments: Option<NodeList>,
+    },
+    TypeQuery {
+        expr_name: NodeId,
+    },
+    TypeLiteral {
+        members: NodeList,
+    },
+    ArrayType {
+        element_type: NodeId,
+    },
+    TupleType {
+        element_types: NodeList,
+    },
+    /// `OptionalType`, `RestType` and `ParenthesizedType`.
+    WrappedType {
+        ty: NodeId,
+    },
+    /// `UnionType` and `IntersectionType`.
+    UnionOrIntersectionType {
+        types: NodeList,
+    },
+    ConditionalType {
+        check_type: NodeId,
+        extends_type: NodeId,
+        true_type: NodeId,
+        false_type: NodeId,
+    },
+    InferType {
+        type_parameter: NodeId,
+    },
+    TypeOperator {
+        operator: SyntaxKind,
+        ty: NodeId,
+    },
+    IndexedAccessType {
+        object_type: NodeId,
+        index_type: NodeId,
+    },
+    MappedType {
+        readonly_token: Option<NodeId>,
+        type_parameter: NodeId,
+        question_token: Option<NodeId>,
+        ty: Option<NodeId>,
+    },
+    LiteralType {
+        literal: NodeId,
+    },
+    ImportType {
+        is_type_of: bool,
+        argument: NodeId,
+        qualifier: Option<NodeId>,
+        type_arguments: Option<NodeList>,
+    },
+
+    // Binding patterns
+    /// `ObjectBindingPattern` and `ArrayBindingPattern`.
+    BindingPattern {
+        elements: NodeList,
+    },
+    BindingElement {
+        dot_dot_dot_token: Option<NodeId>,
+        property_name: Option<NodeId>,
+        name: NodeId,
+        initializer: Option<NodeId>,
+    },
+
+    // Expressions
+    ArrayLiteralExpression {
+        elements: NodeList,
+        multi_line: bool,
+    },
+    ObjectLiteralExpression {
+        properties: NodeList,
+        multi_line: bool,
+    },
+    PropertyAccessExpression {
+        expression: NodeId,
+        name: NodeId,
+    },
+    ElementAccessExpression {
+        expression: NodeId,
+        argument_expression: NodeId,
+    },
+    /// `CallExpression` and `NewExpression`; `arguments` is only `None` for `new X`.
+    CallOrNewExpression {
+        expression: NodeId,
+        type_arguments: Option<NodeList>,
+        arguments: Option<NodeList>,
+    },
+    TaggedTemplateExpression {
+        tag: NodeId,
+        type_arguments: Option<NodeList>,
+        template: NodeId,
+    },
+    TypeAssertion {
+        ty: NodeId,
+        expression: NodeId,
+    },
+    /// `ParenthesizedExpression`, `DeleteExpression`, `TypeOfExpression`, `VoidExpression`,
+    /// `AwaitExpression`, `SpreadElement` and `NonNullExpression`.
+    WrappedExpression {
+        expression: NodeId,
+    },
+    PrefixUnaryExpression {
+        operator: SyntaxKind,
+        operand: NodeId,
+    },
+    PostfixUnaryExpression {
+        operand: NodeId,
+        operator: SyntaxKind,
+    },
+    BinaryExpression {
+        left: NodeId,
+        operator_token: NodeId,
+        right: NodeId,
+    },
+    ConditionalExpression {
+        condition: NodeId,
+        question_token: NodeId,
+        when_true: NodeId,
+        colon_token: NodeId,
+        when_false: NodeId,
+    },
+    TemplateExpression {
+        head: NodeId,
+        template_spans: NodeList,
+    },
+    TemplateSpan {
+        expression: NodeId,
+        literal: NodeId,
+    },
+    YieldExpression {
+        asterisk_token: Option<NodeId>,
+        expression: Option<NodeId>,
+    },
+    /// `ClassExpression` and `ClassDeclaration`; the name is only optional for expressions
+    /// and `export default class`.
+    ClassLike {
+        name: Option<NodeId>,
+        type_parameters: Option<NodeList>,
+        heritage_clauses: Option<NodeList>,
+        members: NodeList,
+    },
+    ExpressionWithTypeArguments {
+        expression: NodeId,
+        type_arguments: Option<NodeList>,
+    },
+    AsExpression {
+        expression: NodeId,
+        ty: NodeId,
+    },
+    MetaProperty {
+        keyword_token: SyntaxKind,
+        name: NodeId,
+    },
+
+    // Object literal members
+    PropertyAssignment {
+        name: NodeId,
+        question_token: Option<NodeId>,
+        initializer: NodeId,
+    },
+    ShorthandPropertyAssignment {
+        name: NodeId,
+        question_token: Option<NodeId>,
+        exclamation_token: Option<NodeId>,
+        equals_token: Option<NodeId>,
+        object_assignment_initializer: Option<NodeId>,
+    },
+    SpreadAssignment {
+        expression: NodeId,
+    },
+
+    // Statements
+    /// `Block` and `ModuleBlock`.
+    Block {
+        statements: NodeList,
+        multi_line: bool,
+    },
+    VariableStatement {
+        declaration_list: NodeId,
+    },
+    /// Whether the list is `var`, `let` or `const` is in its `NodeFlags`.
+    VariableDeclarationList {
+        declarations: NodeList,
+    },
+    VariableDeclaration {
+        name: NodeId,
+        exclamation_token: Option<NodeId>,
+        ty: Option<NodeId>,
+        initializer: Option<NodeId>,
+    },
+    ExpressionStatement {
+        expression: NodeId,
+    },
+    IfStatement {
+        expression: NodeId,
+        then_statement: NodeId,
+        else_statement: Option<NodeId>,
+    },
+    DoStatement {
+        statement: NodeId,
+        expression: NodeId,
+    },
+    /// `WhileStatement` and `WithStatement`.
+    WhileOrWithStatement {
+        expression: NodeId,
+        statement: NodeId,
+    },
+    ForStatement {
+        initializer: Option<NodeId>,
+        condition: Option<NodeId>,
+        incrementor: Option<NodeId>,
+        statement: NodeId,
+    },
+    /// `ForInStatement` and `ForOfStatement`; only `for await (... of ...)` has an
+    /// `await_modifier`.
+    ForInOrOfStatement {
+        await_modifier: Option<NodeId>,
+        initializer: NodeId,
+        expression: NodeId,
+        statement: NodeId,
+    },
+    /// `BreakStatement` and `ContinueStatement`.
+    BreakOrContinueStatement {
+        label: Option<NodeId>,
+    },
+    ReturnStatement {
+        expression: Option<NodeId>,
+    },
+    SwitchStatement {
+        expression: NodeId,
+        case_block: NodeId,
+    },
+    CaseBlock {
+        clauses: NodeList,
+    },
+    CaseClause {
+        expression: NodeId,
+        statements: NodeList,
+    },
+    DefaultClause {
+        statements: NodeList,
+    },
+    LabeledStatement {
+        label: NodeId,
+        statement: NodeId,
+    },
+    /// `expression` is `None` when a line break follows `throw`, which is an error.
+    ThrowStatement {
+        expression: Option<NodeId>,
+    },
+    TryStatement {
+        try_block: NodeId,
+        catch_clause: Option<NodeId>,
+        finally_block: Option<NodeId>,
+    },
+    CatchClause {
+        variable_declaration: Option<NodeId>,
+        block: NodeId,
+    },
+
+    // Declarations
+    InterfaceDeclaration {
+        name: NodeId,
+        type_parameters: Option<NodeList>,
+        heritage_clauses: Option<NodeList>,
+        members: NodeList,
+    },
+    HeritageClause {
+        token: SyntaxKind,
+        types: NodeList,
+    },
+    TypeAliasDeclaration {
+        name: NodeId,
+        type_parameters: Option<NodeList>,
+        ty: NodeId,
+    },
+    EnumDeclaration {
+        name: NodeId,
+        members: NodeList,
+    },
+    EnumMember {
+        name: NodeId,
+        initializer: Option<NodeId>,
+    },
+    /// `body` is a `ModuleBlock`, or another `ModuleDeclaration` for `namespace A.B {}`, and is
+    /// `None` for `declare module "m";`.
+    ModuleDeclaration {
+        name: NodeId,
+        body: Option<NodeId>,
+    },
+    ImportEqualsDeclaration {
+        name: NodeId,
+        module_reference: NodeId,
+    },
+    ExternalModuleReference {
+        expression: NodeId,
+    },
+    ImportDeclaration {
+        import_clause: Option<NodeId>,
+        module_specifier: NodeId,
+    },
+    ImportClause {
+        name: Option<NodeId>,
+        named_bindings: Option<NodeId>,
+    },
+    /// `NamespaceImport` and `NamespaceExportDeclaration`.
+    NamespaceImportOrExport {
+        name: NodeId,
+    },
+    /// `NamedImports` and `NamedExports`.
+    NamedImportsOrExports {
+        elements: NodeList,
+    },
+    /// `ImportSpecifier` and `ExportSpecifier`.
+    ImportOrExportSpecifier {
+        property_name: Option<NodeId>,
+        name: NodeId,
+    },
+    ExportAssignment {
+        is_export_equals: bool,
+        expression: NodeId,
+    },
+    ExportDeclaration {
+        export_clause: Option<NodeId>,
+        module_specifier: Option<NodeId>,
+    },
+
+    // JSX
+    JsxElement {
+        opening_element: NodeId,
+        children: NodeList,
+        closing_element: NodeId,
+    },
+    JsxFragment {
+        opening_fragment: NodeId,
+        children: NodeList,
+        closing_fragment: NodeId,
+    },
+    /// `JsxOpeningElement` and `JsxSelfClosingElement`.
+    JsxOpeningLikeElement {
+        tag_name: NodeId,
+        type_arguments: Option<NodeList>,
+        attributes: NodeId,
+    },
+    JsxClosingElement {
+        tag_name: NodeId,
+    },
+    JsxAttributes {
+        properties: NodeList,
+    },
+    JsxAttribute {
+        name: NodeId,
+        initializer: Option<NodeId>,
+    },
+    JsxSpreadAttribute {
+        expression: NodeId,
+    },
+    JsxExpression {
+        dot_dot_dot_token: Option<NodeId>,
+        expression: Option<NodeId>,
+    },
+
+    SourceFile {
+        statements: NodeList,
+        end_of_file_token: NodeId,
+        file_name: String,
+        language_version: ScriptTarget,
+        language_variant: LanguageVariant,
+        is_declaration_file: bool,
+    },
+}
+
+/// Owns every node of a tree. Nodes refer to each other by `NodeId`, so a finished arena can be
+/// shared by reference between the parser's callers and the checker without reference counting.
+#[derive(Clone, Debug, Default)]
+pub struct NodeArena {
+    nodes: Vec<Node>,
+}
+
+impl NodeArena {
+    pub fn new() -> NodeArena {
+        NodeArena { nodes: Vec::new() }
+    }
+
+    /// Adds a node whose children have already been allocated, and points their parents at it.
+    pub fn alloc(&mut self, node: Node) -> NodeId {
+        let id = NodeId(self.nodes.len() as u32);
+        self.nodes.push(node);
+        for child in self.children(id) {
+            self.nodes[child.index()].parent = Some(id);
+        }
+        id
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    // Drops the nodes allocated after the first `len`, when the parser abandons a speculative
+    // parse. None of the remaining nodes can have been given one of them as a parent.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.nodes.truncate(len);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &Node)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (NodeId(index as u32), node))
+    }
+
+    /// Calls `cb` with every child of `id` in source order, starting with its decorators and
+    /// modifiers.
+    pub fn for_each_child(&self, id: NodeId, cb: &mut dyn FnMut(NodeId)) {
+        let node = &self[id];
+        Children::for_each(&node.decorators, cb);
+        Children::for_each(&node.modifiers, cb);
+        node.data.for_each_child(cb);
+    }
+
+    pub fn children(&self, id: NodeId) -> Vec<NodeId> {
+        let mut children = Vec::new();
+        self.for_each_child(id, &mut |child| children.push(child));
+        children
+    }
+
+    /// The ancestors of `id`, nearest first.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(self[id].parent, move |&parent| self[parent].parent)
+    }
+
+    pub fn get_modifier_flags(&self, id: NodeId) -> ModifierFlags {
+        let mut flags = ModifierFlags::NONE;
+        if let Some(modifiers) = &self[id].modifiers {
+            for modifier in modifiers.iter() {
+                flags |= modifier_to_flag(self[modifier].kind);
+            }
+        }
+        flags
+    }
+
+    pub fn has_modifier(&self, id: NodeId, flags: ModifierFlags) -> bool {
+        self.get_modifier_flags(id).intersects(flags)
+    }
+}
+
+impl Index<NodeId> for NodeArena {
+    type Output = Node;
+
+    fn index(&self, id: NodeId) -> &Node {
+        &self.nodes[id.index()]
+    }
+}
+
+impl IndexMut<NodeId> for NodeArena {
+    fn index_mut(&mut self, id: NodeId) -> &mut Node {
+        &mut self.nodes[id.index()]
+    }
+}
+
+pub fn modifier_to_flag(kind: SyntaxKind) -> ModifierFlags {
+    match kind {
+        SyntaxKind::StaticKeyword => ModifierFlags::STATIC,
+        SyntaxKind::PublicKeyword => ModifierFlags::PUBLIC,
+        SyntaxKind::ProtectedKeyword => ModifierFlags::PROTECTED,
+        SyntaxKind::PrivateKeyword => ModifierFlags::PRIVATE,
+        SyntaxKind::AbstractKeyword => ModifierFlags::ABSTRACT,
+        SyntaxKind::ExportKeyword => ModifierFlags::EXPORT,
+        SyntaxKind::DeclareKeyword => ModifierFlags::AMBIENT,
+        SyntaxKind::ConstKeyword => ModifierFlags::CONST,
+        SyntaxKind::DefaultKeyword => ModifierFlags::DEFAULT,
+        SyntaxKind::AsyncKeyword => ModifierFlags::ASYNC,
+        SyntaxKind::ReadonlyKeyword => ModifierFlags::READONLY,
+        _ => ModifierFlags::NONE,
+    }
+}
@@ -0,0 +1,55 @@
+//! The diagnostic message catalog, generated by the build script from diagnosticMessages.json
+//! so that codes stay identical to those reported by TypeScript.
+
+use crate::types::{DiagnosticCategory, DiagnosticMessage};
+
+include!(concat!(env!("OUT_DIR"), "/diagnostic_information_map.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_keep_typescript_codes() {
+        assert_eq!(UNTERMINATED_STRING_LITERAL.code, 1002);
+        assert_eq!(
+            UNTERMINATED_STRING_LITERAL.key,
+            "Unterminated_string_literal_1002"
+        );
+        assert_eq!(
+            UNTERMINATED_STRING_LITERAL.message,
+            "Unterminated string literal."
+        );
+        assert_eq!(HEXADECIMAL_DIGIT_EXPECTED.code, 1125);
+        assert_eq!(ASTERISK_SLASH_EXPECTED.key, "Asterisk_Slash_expected_1010");
+        assert_eq!(
+            OCTAL_LITERALS_ARE_NOT_AVAILABLE_WHEN_TARGETING_ECMASCRIPT_5_AND_HIGHER_USE_THE_SYNTAX_0
+                .message,
+            "Octal literals are not available when targeting ECMAScript 5 and higher. Use the syntax '{0}'."
+        );
+        assert_eq!(MERGE_CONFLICT_MARKER_ENCOUNTERED.code, 1185);
+        assert_eq!(
+            MERGE_CONFLICT_MARKER_ENCOUNTERED.category,
+            DiagnosticCategory::Error
+        );
+    }
+
+    #[test]
+    fn extra_messages_are_merged() {
+        assert_eq!(
+            UNKNOWN_REGULAR_EXPRESSION_FLAG,
+            DiagnosticMessage {
+                key: "Unknown_regular_expression_flag_1499",
+                category: DiagnosticCategory::Error,
+                code: 1499,
+                message: "Unknown regular expression flag.",
+                reports_unnecessary: false,
+            }
+        );
+        assert_eq!(DUPLICATE_REGULAR_EXPRESSION_FLAG.code, 1500);
+        assert_eq!(
+            DUPLICATE_REGULAR_EXPRESSION_FLAG.key,
+            "Duplicate_regular_expression_flag_1500"
+        );
+    }
+}
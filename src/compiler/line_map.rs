@@ -0,0 +1,251 @@
+use crate::compiler::scanner::{is_line_break, SourceText};
+use crate::types::{CharacterCodes, LineAndCharacter};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "computeLineStarts"))]
+pub fn compute_line_starts(text: &str) -> Vec<usize> {
+    let text = SourceText::new(text);
+    let mut result = Vec::new();
+    let mut pos = 0;
+    let mut line_start = 0;
+    while pos < text.len() {
+        let ch = text[pos] as u32;
+        pos += 1;
+        if ch == CharacterCodes::CarriageReturn as u32 {
+            if text.char_code_at(pos) == Some(CharacterCodes::LineFeed as u32) {
+                pos += 1;
+            }
+            result.push(line_start);
+            line_start = pos;
+        } else if ch == CharacterCodes::LineFeed as u32
+            || ch > CharacterCodes::MaxAsciiCharacter as u32 && is_line_break(ch)
+        {
+            result.push(line_start);
+            line_start = pos;
+        }
+    }
+    result.push(line_start);
+    result
+}
+
+/// Computes the position of a line and character. With `allow_edits`, out of range values are
+/// clamped to the nearest allowable position; otherwise they are an error, which JavaScript
+/// receives as an exception like the one TypeScript's `Debug.fail` throws.
+pub fn compute_position_of_line_and_character(
+    line_starts: &[usize],
+    mut line: usize,
+    character: usize,
+    debug_text: Option<&str>,
+    allow_edits: bool,
+) -> Result<usize, String> {
+    if line >= line_starts.len() {
+        if allow_edits {
+            // Clamp line to nearest allowable value
+            line = line_starts.len() - 1;
+        } else {
+            return Err(format!(
+                "Bad line number. Line: {}, line_starts.len(): {} , line map is correct? {}",
+                line,
+                line_starts.len(),
+                debug_text.map_or("unknown".to_string(), |text| {
+                    (compute_line_starts(text) == line_starts).to_string()
+                })
+            ));
+        }
+    }
+
+    let res = line_starts[line] + character;
+    let text_len = debug_text.map(|text| text.encode_utf16().count());
+    if allow_edits {
+        // Clamp to nearest allowable values to allow the underlying to be edited without crashing (accuracy is lost, instead)
+        return Ok(match (line_starts.get(line + 1), text_len) {
+            (Some(&next_line_start), _) if res > next_line_start => next_line_start,
+            (_, Some(text_len)) if res > text_len => text_len,
+            _ => res,
+        });
+    }
+    let in_range = if line < line_starts.len() - 1 {
+        res < line_starts[line + 1]
+    } else {
+        // Allow single character overflow for trailing newline
+        text_len.is_none_or(|text_len| res <= text_len)
+    };
+    if in_range {
+        Ok(res)
+    } else {
+        Err(format!(
+            "Bad character number. Line: {}, character: {}",
+            line, character
+        ))
+    }
+}
+
+/// We assume the first line starts at position 0 and 'position' is non-negative.
+pub fn compute_line_and_character_of_position(
+    line_starts: &[usize],
+    position: usize,
+) -> LineAndCharacter {
+    let line_number = match line_starts.binary_search(&position) {
+        Ok(line_number) => line_number,
+        // If the actual position was not found, the binary search returns the index of the
+        // next line start, e.g. if the line starts at [5, 10, 23, 80] and the position
+        // requested was 20 then the search will return 2.
+        //
+        // We want the index of the previous line start, so we subtract 1.
+        Err(next_line_number) => {
+            debug_assert_ne!(
+                next_line_number, 0,
+                "position cannot precede the beginning of the file"
+            );
+            next_line_number - 1
+        }
+    };
+    LineAndCharacter {
+        line: line_number,
+        character: position - line_starts[line_number],
+    }
+}
+
+/// The line starts of a piece of text, computed once so that positions can be mapped to lines
+/// and characters (and back) without passing the text across the wasm boundary again.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct LineMap {
+    line_starts: Vec<usize>,
+    text_len: usize,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl LineMap {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(text: &str) -> LineMap {
+        LineMap {
+            line_starts: compute_line_starts(text),
+            text_len: text.encode_utf16().count(),
+        }
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getLineStarts"))]
+    pub fn get_line_starts(&self) -> Vec<usize> {
+        self.line_starts.clone()
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getLineCount"))]
+    pub fn get_line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    #[cfg_attr(
+        feature = "wasm",
+        wasm_bindgen(js_name = "getLineAndCharacterOfPosition")
+    )]
+    pub fn get_line_and_character_of_position(&self, position: usize) -> LineAndCharacter {
+        compute_line_and_character_of_position(&self.line_starts, position)
+    }
+
+    #[cfg_attr(
+        feature = "wasm",
+        wasm_bindgen(js_name = "getPositionOfLineAndCharacter")
+    )]
+    pub fn get_position_of_line_and_character(
+        &self,
+        line: usize,
+        character: usize,
+        allow_edits: Option<bool>,
+    ) -> Result<usize, String> {
+        let allow_edits = allow_edits.unwrap_or(false);
+        let position = compute_position_of_line_and_character(
+            &self.line_starts,
+            line,
+            character,
+            None,
+            allow_edits,
+        )?;
+        // Without the text at hand, check against its length here like `debug_text` would
+        if allow_edits {
+            Ok(position.min(self.text_len))
+        } else if position > self.text_len {
+            Err(format!(
+                "Bad character number. Line: {}, character: {}",
+                line, character
+            ))
+        } else {
+            Ok(position)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Lines end at "\r\n", "\r", "\n" and U+2028 respectively.
+    const TEXT: &str = "a\r\nb\rc\nd\u{2028}e";
+
+    #[test]
+    fn line_starts() {
+        assert_eq!(compute_line_starts(TEXT), vec![0, 3, 5, 7, 9]);
+        assert_eq!(compute_line_starts(""), vec![0]);
+        assert_eq!(compute_line_starts("a\n"), vec![0, 2]);
+        assert_eq!(compute_line_starts("\u{1F600}\u{2029}x"), vec![0, 3]);
+    }
+
+    #[test]
+    fn line_and_character_of_position() {
+        let line_map = LineMap::new(TEXT);
+        let at = |position| {
+            let LineAndCharacter { line, character } =
+                line_map.get_line_and_character_of_position(position);
+            (line, character)
+        };
+        assert_eq!(at(0), (0, 0));
+        assert_eq!(at(2), (0, 2));
+        assert_eq!(at(4), (1, 1));
+        assert_eq!(at(9), (4, 0));
+        assert_eq!(at(10), (4, 1));
+    }
+
+    #[test]
+    fn position_of_line_and_character() {
+        let line_map = LineMap::new(TEXT);
+        assert_eq!(
+            line_map.get_position_of_line_and_character(1, 1, None),
+            Ok(4)
+        );
+        assert_eq!(
+            line_map.get_position_of_line_and_character(4, 1, None),
+            Ok(10)
+        );
+        assert!(line_map
+            .get_position_of_line_and_character(5, 0, None)
+            .is_err());
+        assert!(line_map
+            .get_position_of_line_and_character(1, 2, None)
+            .is_err());
+        assert!(line_map
+            .get_position_of_line_and_character(4, 2, None)
+            .is_err());
+    }
+
+    #[test]
+    fn position_of_line_and_character_with_edits() {
+        let line_map = LineMap::new(TEXT);
+        let clamped = |line, character| {
+            line_map.get_position_of_line_and_character(line, character, Some(true))
+        };
+        assert_eq!(clamped(5, 0), Ok(9));
+        assert_eq!(clamped(1, 5), Ok(5));
+        assert_eq!(clamped(4, 5), Ok(10));
+    }
+
+    #[test]
+    fn bad_line_message() {
+        let line_starts = compute_line_starts(TEXT);
+        assert_eq!(
+            compute_position_of_line_and_character(&line_starts, 7, 0, Some(TEXT), false),
+            Err(String::from(
+                "Bad line number. Line: 7, line_starts.len(): 5 , line map is correct? true"
+            ))
+        );
+    }
+}
@@ -1 +1,7 @@
+pub mod ast;
+pub mod diagnostics;
+pub mod line_map;
+pub mod parser;
 pub mod scanner;
+pub mod tokenizer;
+pub mod utilities;
@@ -0,0 +1,6269 @@
+//! A recursive descent parser producing the arena AST of `compiler::ast`, ported from
+//! TypeScript's parser.ts. Node kinds, spans and flags match what TypeScript produces for the
+//! same source, except that JSX and JSDoc are not parsed.
+
+use crate::compiler::ast::{modifier_to_flag, Node, NodeArena, NodeData, NodeId, NodeList};
+use crate::compiler::diagnostics;
+use crate::compiler::scanner::{skip_trivia_in, token_to_string, Scanner, ScannerState};
+use crate::compiler::utilities::{
+    create_diagnostic, escape_leading_underscores, get_binary_operator_precedence,
+};
+use crate::types::{
+    Diagnostic, DiagnosticMessage, LanguageVariant, NodeFlags, ScriptTarget, SyntaxKind, TokenFlags,
+};
+use bitflags::bitflags;
+use std::collections::HashSet;
+
+/// The result of `create_source_file`: the arena holding every node of the file, the
+/// `SourceFile` node at its root, and the syntax errors found while parsing.
+pub struct ParsedSourceFile {
+    pub arena: NodeArena,
+    pub source_file: NodeId,
+    pub parse_diagnostics: Vec<Diagnostic>,
+}
+
+/// Parses `source_text` as a TypeScript file. Parsing always succeeds; syntax errors are
+/// reported in `parse_diagnostics`, and the tree contains missing nodes where they occurred.
+pub fn create_source_file(
+    file_name: &str,
+    source_text: &str,
+    language_version: ScriptTarget,
+) -> ParsedSourceFile {
+    Parser::new(file_name, source_text, language_version).parse_source_file()
+}
+
+bitflags! {
+    struct SignatureFlags: u32 {
+        const NONE = 0;
+        const YIELD = 1 << 0;
+        const AWAIT = 1 << 1;
+        const TYPE = 1 << 2;
+        const IGNORE_MISSING_OPEN_BRACE = 1 << 4;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tristate {
+    False,
+    True,
+    Unknown,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ParsingContext {
+    SourceElements,           // Elements in source file
+    BlockStatements,          // Statements in block
+    SwitchClauses,            // Clauses in switch statement
+    SwitchClauseStatements,   // Statements in switch clause
+    TypeMembers,              // Members in interface or type literal
+    ClassMembers,             // Members in class declaration
+    EnumMembers,              // Members in enum declaration
+    HeritageClauseElement,    // Elements in a heritage clause
+    VariableDeclarations,     // Variable declarations in variable statement
+    ObjectBindingElements,    // Binding elements in object binding list
+    ArrayBindingElements,     // Binding elements in array binding list
+    ArgumentExpressions,      // Expressions in argument list
+    ObjectLiteralMembers,     // Members in object literal
+    ArrayLiteralMembers,      // Members in array literal
+    Parameters,               // Parameters in parameter list
+    TypeParameters,           // Type parameters in type parameter list
+    TypeArguments,            // Type arguments in type argument list
+    TupleElementTypes,        // Element types in tuple element type list
+    HeritageClauses,          // Heritage clauses for a class or interface declaration.
+    ImportOrExportSpecifiers, // Named import clause's import specifier list
+}
+
+impl ParsingContext {
+    const ALL: [ParsingContext; 20] = [
+        ParsingContext::SourceElements,
+        ParsingContext::BlockStatements,
+        ParsingContext::SwitchClauses,
+        ParsingContext::SwitchClauseStatements,
+        ParsingContext::TypeMembers,
+        ParsingContext::ClassMembers,
+        ParsingContext::EnumMembers,
+        ParsingContext::HeritageClauseElement,
+        ParsingContext::VariableDeclarations,
+        ParsingContext::ObjectBindingElements,
+        ParsingContext::ArrayBindingElements,
+        ParsingContext::ArgumentExpressions,
+        ParsingContext::ObjectLiteralMembers,
+        ParsingContext::ArrayLiteralMembers,
+        ParsingContext::Parameters,
+        ParsingContext::TypeParameters,
+        ParsingContext::TypeArguments,
+        ParsingContext::TupleElementTypes,
+        ParsingContext::HeritageClauses,
+        ParsingContext::ImportOrExportSpecifiers,
+    ];
+
+    fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+// Everything a speculative parse can change, so that it can be rolled back.
+struct ParserState {
+    scanner: ScannerState,
+    diagnostic_count: usize,
+    scanner_error_count: usize,
+    context_flags: NodeFlags,
+    parse_error_before_next_finished_node: bool,
+    node_count: usize,
+}
+
+/// What `try_parse` treats as success, like the truthiness check of TypeScript's `tryParse`.
+trait Speculation {
+    fn succeeded(&self) -> bool;
+}
+
+impl<T> Speculation for Option<T> {
+    fn succeeded(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl Speculation for bool {
+    fn succeeded(&self) -> bool {
+        *self
+    }
+}
+
+// The parts of a signature declaration, parsed before the node that owns them.
+struct Signature {
+    type_parameters: Option<NodeList>,
+    parameters: NodeList,
+    ty: Option<NodeId>,
+    // False if the parameter list was incomplete or the return type cannot belong to an arrow
+    // function, which rules out a speculative arrow function.
+    parsed_successfully: bool,
+}
+
+// The decorators and modifiers of a declaration, which are parsed before its kind is known.
+struct DeclarationStart {
+    pos: usize,
+    decorators: Option<NodeList>,
+    modifiers: Option<NodeList>,
+}
+
+struct Parser {
+    scanner: Scanner,
+    arena: NodeArena,
+    file_name: String,
+    language_version: ScriptTarget,
+    parse_diagnostics: Vec<Diagnostic>,
+    // How many of the scanner's errors have been copied to `parse_diagnostics`.
+    scanner_error_count: usize,
+    context_flags: NodeFlags,
+    // A bit set of the `ParsingContext`s of the lists being parsed.
+    parsing_context: u32,
+    // Whether an error was reported since the last node was finished. The next node to be
+    // finished is then marked with `THIS_NODE_HAS_ERROR`.
+    parse_error_before_next_finished_node: bool,
+    // The token positions at which a parenthesized arrow function has already been ruled out.
+    not_parenthesized_arrow: HashSet<usize>,
+}
+
+impl Parser {
+    fn new(file_name: &str, source_text: &str, language_version: ScriptTarget) -> Parser {
+        let mut scanner = Scanner::new(true);
+        scanner.set_script_target(language_version);
+        scanner.set_language_variant(LanguageVariant::Standard);
+        scanner.set_text(source_text, None, None);
+        Parser {
+            scanner,
+            arena: NodeArena::new(),
+            file_name: String::from(file_name),
+            language_version,
+            parse_diagnostics: Vec::new(),
+            scanner_error_count: 0,
+            context_flags: NodeFlags::NONE,
+            parsing_context: 0,
+            parse_error_before_next_finished_node: false,
+            not_parenthesized_arrow: HashSet::new(),
+        }
+    }
+
+    fn parse_source_file(mut self) -> ParsedSourceFile {
+        // Prime the scanner.
+        self.next_token();
+        let statements = self.parse_list(ParsingContext::SourceElements, Parser::parse_statement);
+        debug_assert_eq!(self.token(), SyntaxKind::EndOfFileToken);
+        let end_of_file_token = self.parse_token_node();
+
+        let mut node = Node::new(
+            SyntaxKind::SourceFile,
+            0,
+            self.scanner.source_text().len(),
+            NodeData::SourceFile {
+                statements,
+                end_of_file_token,
+                is_declaration_file: self.file_name.ends_with(".d.ts"),
+                file_name: self.file_name,
+                language_version: self.language_version,
+                language_variant: LanguageVariant::Standard,
+            },
+        );
+        node.flags = self.context_flags;
+        let source_file = self.arena.alloc(node);
+        ParsedSourceFile {
+            arena: self.arena,
+            source_file,
+            parse_diagnostics: self.parse_diagnostics,
+        }
+    }
+
+    // Context flags
+
+    fn set_context_flag(&mut self, val: bool, flag: NodeFlags) {
+        if val {
+            self.context_flags |= flag;
+        } else {
+            self.context_flags &= !flag;
+        }
+    }
+
+    fn in_context(&self, flags: NodeFlags) -> bool {
+        self.context_flags.intersects(flags)
+    }
+
+    fn in_yield_context(&self) -> bool {
+        self.in_context(NodeFlags::YIELD_CONTEXT)
+    }
+
+    fn in_disallow_in_context(&self) -> bool {
+        self.in_context(NodeFlags::DISALLOW_IN_CONTEXT)
+    }
+
+    fn in_decorator_context(&self) -> bool {
+        self.in_context(NodeFlags::DECORATOR_CONTEXT)
+    }
+
+    fn in_await_context(&self) -> bool {
+        self.in_context(NodeFlags::AWAIT_CONTEXT)
+    }
+
+    fn do_outside_of_context<T>(
+        &mut self,
+        context: NodeFlags,
+        func: impl FnOnce(&mut Parser) -> T,
+    ) -> T {
+        // contextFlagsToClear will contain only the context flags that are
+        // currently set that we need to temporarily clear
+        // We don't just blindly reset to the previous flags to ensure
+        // that we do not mutate cached flags for the incremental
+        // parser (ThisNodeHasError, ThisNodeOrAnySubNodesHasError, and
+        // HasAggregatedChildData).
+        let context_flags_to_clear = context & self.context_flags;
+        if !context_flags_to_clear.is_empty() {
+            // clear the requested context flags
+            self.set_context_flag(false, context_flags_to_clear);
+            let result = func(self);
+            // restore the context flags we just cleared
+            self.set_context_flag(true, context_flags_to_clear);
+            return result;
+        }
+        // no need to do anything special as we are not in any of the requested contexts
+        func(self)
+    }
+
+    fn do_inside_of_context<T>(
+        &mut self,
+        context: NodeFlags,
+        func: impl FnOnce(&mut Parser) -> T,
+    ) -> T {
+        // contextFlagsToSet will contain only the context flags that
+        // are not currently set that we need to temporarily enable.
+        let context_flags_to_set = context & !self.context_flags;
+        if !context_flags_to_set.is_empty() {
+            // set the requested context flags
+            self.set_context_flag(true, context_flags_to_set);
+            let result = func(self);
+            // reset the context flags we just set
+            self.set_context_flag(false, context_flags_to_set);
+            return result;
+        }
+        // no need to do anything special as we are already in all of the requested contexts
+        func(self)
+    }
+
+    fn allow_in_and<T>(&mut self, func: impl FnOnce(&mut Parser) -> T) -> T {
+        self.do_outside_of_context(NodeFlags::DISALLOW_IN_CONTEXT, func)
+    }
+
+    fn disallow_in_and<T>(&mut self, func: impl FnOnce(&mut Parser) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::DISALLOW_IN_CONTEXT, func)
+    }
+
+    fn do_in_yield_context<T>(&mut self, func: impl FnOnce(&mut Parser) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::YIELD_CONTEXT, func)
+    }
+
+    fn do_in_decorator_context<T>(&mut self, func: impl FnOnce(&mut Parser) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::DECORATOR_CONTEXT, func)
+    }
+
+    fn do_in_await_context<T>(&mut self, func: impl FnOnce(&mut Parser) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::AWAIT_CONTEXT, func)
+    }
+
+    fn do_outside_of_await_context<T>(&mut self, func: impl FnOnce(&mut Parser) -> T) -> T {
+        self.do_outside_of_context(NodeFlags::AWAIT_CONTEXT, func)
+    }
+
+    fn do_in_yield_and_await_context<T>(&mut self, func: impl FnOnce(&mut Parser) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::YIELD_CONTEXT | NodeFlags::AWAIT_CONTEXT, func)
+    }
+
+    // Errors
+
+    fn parse_error_at_current_token(&mut self, message: &DiagnosticMessage, args: &[&str]) {
+        let start = self.scanner.get_token_pos();
+        let length = self.scanner.get_text_pos() - start;
+        self.parse_error_at_position(start, length, message, args);
+    }
+
+    fn parse_error_at_position(
+        &mut self,
+        start: usize,
+        length: usize,
+        message: &DiagnosticMessage,
+        args: &[&str],
+    ) {
+        self.push_diagnostic(create_diagnostic(start, length, message, args));
+    }
+
+    fn parse_error_at(
+        &mut self,
+        start: usize,
+        end: usize,
+        message: &DiagnosticMessage,
+        args: &[&str],
+    ) {
+        self.parse_error_at_position(start, end - start, message, args);
+    }
+
+    fn push_diagnostic(&mut self, diagnostic: Diagnostic) {
+        // Don't report another error if it would just be at the same position as the last error.
+        let is_duplicate = self
+            .parse_diagnostics
+            .last()
+            .is_some_and(|last| last.start == diagnostic.start);
+        if !is_duplicate {
+            self.parse_diagnostics.push(diagnostic);
+        }
+        // Mark that we've encountered an error.  We'll set an appropriate bit on the next
+        // node we finish so that it can't be reused incrementally.
+        self.parse_error_before_next_finished_node = true;
+    }
+
+    // Reports the errors the scanner found in the tokens scanned since the last call.
+    fn take_scanner_errors(&mut self) {
+        let errors = self.scanner.get_errors();
+        if errors.len() > self.scanner_error_count {
+            let new_errors = errors[self.scanner_error_count..].to_vec();
+            self.scanner_error_count = errors.len();
+            for error in new_errors {
+                self.push_diagnostic(error);
+            }
+        }
+    }
+
+    // Tokens
+
+    fn node_pos(&self) -> usize {
+        self.scanner.get_start_pos()
+    }
+
+    fn token(&self) -> SyntaxKind {
+        self.scanner.get_token()
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.scanner.has_preceding_line_break()
+    }
+
+    fn next_token(&mut self) -> SyntaxKind {
+        let token = self.scanner.scan();
+        self.take_scanner_errors();
+        token
+    }
+
+    fn re_scan_greater_token(&mut self) -> SyntaxKind {
+        let token = self.scanner.re_scan_greater_token();
+        self.take_scanner_errors();
+        token
+    }
+
+    fn re_scan_slash_token(&mut self) -> SyntaxKind {
+        let token = self.scanner.re_scan_slash_token();
+        self.take_scanner_errors();
+        token
+    }
+
+    fn re_scan_template_token(&mut self, is_tagged_template: bool) -> SyntaxKind {
+        let token = self.scanner.re_scan_template_token(is_tagged_template);
+        self.take_scanner_errors();
+        token
+    }
+
+    fn re_scan_template_head_or_no_substitution_template(&mut self) -> SyntaxKind {
+        let token = self
+            .scanner
+            .re_scan_template_head_or_no_substitution_template();
+        self.take_scanner_errors();
+        token
+    }
+
+    fn re_scan_less_than_token(&mut self) -> SyntaxKind {
+        let token = self.scanner.re_scan_less_than_token();
+        self.take_scanner_errors();
+        token
+    }
+
+    // Speculation
+
+    fn save_state(&self) -> ParserState {
+        ParserState {
+            scanner: self.scanner.save_state(),
+            diagnostic_count: self.parse_diagnostics.len(),
+            scanner_error_count: self.scanner_error_count,
+            context_flags: self.context_flags,
+            parse_error_before_next_finished_node: self.parse_error_before_next_finished_node,
+            node_count: self.arena.len(),
+        }
+    }
+
+    fn restore_state(&mut self, state: ParserState) {
+        self.scanner.restore_state(state.scanner);
+        self.parse_diagnostics.truncate(state.diagnostic_count);
+        self.scanner_error_count = state.scanner_error_count;
+        self.context_flags = state.context_flags;
+        self.parse_error_before_next_finished_node = state.parse_error_before_next_finished_node;
+        self.arena.truncate(state.node_count);
+    }
+
+    /// Invokes the provided callback then unconditionally restores the parser to the state it
+    /// was in immediately prior to invoking the callback.  The result of invoking the callback
+    /// is returned from this function.
+    fn look_ahead<T>(&mut self, callback: impl FnOnce(&mut Parser) -> T) -> T {
+        let state = self.save_state();
+        let result = callback(self);
+        self.restore_state(state);
+        result
+    }
+
+    /// Invokes the provided callback.  If the callback does not succeed, the parser is restored
+    /// to the state it was in immediately prior to invoking the callback.  Otherwise the parser
+    /// stays where the callback left it.
+    fn try_parse<T: Speculation>(&mut self, callback: impl FnOnce(&mut Parser) -> T) -> T {
+        let state = self.save_state();
+        let result = callback(self);
+        if !result.succeeded() {
+            self.restore_state(state);
+        }
+        result
+    }
+
+    // Nodes
+
+    // Sets the end of `node` to the end of the last token consumed, and allocates it.
+    fn finish(&mut self, mut node: Node) -> NodeId {
+        node.end = self.scanner.get_start_pos();
+        node.flags |= self.context_flags;
+        // Keep track on the node if we encountered an error while parsing it.  If we did, then
+        // we cannot reuse the node incrementally.  Once we've marked this node, clear out the
+        // flag so that we don't mark any subsequent nodes.
+        if self.parse_error_before_next_finished_node {
+            self.parse_error_before_next_finished_node = false;
+            node.flags |= NodeFlags::THIS_NODE_HAS_ERROR;
+        }
+        self.arena.alloc(node)
+    }
+
+    fn finish_node(&mut self, kind: SyntaxKind, pos: usize, data: NodeData) -> NodeId {
+        self.finish(Node::new(kind, pos, pos, data))
+    }
+
+    fn finish_declaration(
+        &mut self,
+        start: DeclarationStart,
+        kind: SyntaxKind,
+        data: NodeData,
+    ) -> NodeId {
+        let mut node = Node::new(kind, start.pos, start.pos, data);
+        node.decorators = start.decorators;
+        node.modifiers = start.modifiers;
+        self.finish(node)
+    }
+
+    fn make_binary_expression(
+        &mut self,
+        left: NodeId,
+        operator_token: NodeId,
+        right: NodeId,
+    ) -> NodeId {
+        let pos = self.arena[left].pos;
+        self.finish_node(
+            SyntaxKind::BinaryExpression,
+            pos,
+            NodeData::BinaryExpression {
+                left,
+                operator_token,
+                right,
+            },
+        )
+    }
+
+    fn node_is_missing(&self, id: NodeId) -> bool {
+        let node = &self.arena[id];
+        node.pos == node.end && node.kind != SyntaxKind::EndOfFileToken
+    }
+
+    fn create_missing_node(
+        &mut self,
+        kind: SyntaxKind,
+        report_at_current_position: bool,
+        message: &DiagnosticMessage,
+        args: &[&str],
+    ) -> NodeId {
+        if report_at_current_position {
+            let pos = self.scanner.get_start_pos();
+            self.parse_error_at_position(pos, 0, message, args);
+        } else {
+            self.parse_error_at_current_token(message, args);
+        }
+        let data = if kind == SyntaxKind::Identifier {
+            NodeData::Identifier {
+                escaped_text: String::new(),
+                original_keyword_kind: None,
+            }
+        } else if kind.is_literal_kind()
+            || (SyntaxKind::FirstTemplateToken <= kind && kind <= SyntaxKind::LastTemplateToken)
+        {
+            NodeData::Literal {
+                text: String::new(),
+                token_flags: TokenFlags::NONE,
+            }
+        } else {
+            NodeData::Token {}
+        };
+        let pos = self.node_pos();
+        self.finish_node(kind, pos, data)
+    }
+
+    fn create_missing_list(&self) -> NodeList {
+        let pos = self.node_pos();
+        NodeList::new(pos, pos, Vec::new())
+    }
+
+    fn parse_expected(&mut self, kind: SyntaxKind, message: Option<&DiagnosticMessage>) -> bool {
+        if self.token() == kind {
+            self.next_token();
+            return true;
+        }
+        // Report specific message if provided with one.  Otherwise, report generic fallback message.
+        match message {
+            Some(message) => self.parse_error_at_current_token(message, &[]),
+            None => self.parse_error_at_current_token(
+                &diagnostics::_0_EXPECTED,
+                &[&token_to_string(kind as u32).unwrap_or_default()],
+            ),
+        }
+        false
+    }
+
+    fn parse_optional(&mut self, kind: SyntaxKind) -> bool {
+        if self.token() == kind {
+            self.next_token();
+            return true;
+        }
+        false
+    }
+
+    fn parse_optional_token(&mut self, kind: SyntaxKind) -> Option<NodeId> {
+        if self.token() == kind {
+            return Some(self.parse_token_node());
+        }
+        None
+    }
+
+    fn parse_expected_token(&mut self, kind: SyntaxKind) -> NodeId {
+        if let Some(token) = self.parse_optional_token(kind) {
+            return token;
+        }
+        let text = token_to_string(kind as u32).unwrap_or_default();
+        self.create_missing_node(kind, false, &diagnostics::_0_EXPECTED, &[&text])
+    }
+
+    fn parse_token_node(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let kind = self.token();
+        self.next_token();
+        self.finish_node(kind, pos, NodeData::Token {})
+    }
+
+    fn can_parse_semicolon(&self) -> bool {
+        // If there's a real semicolon, then we can always parse it out.
+        if self.token() == SyntaxKind::SemicolonToken {
+            return true;
+        }
+        // We can parse out an optional semicolon in ASI cases in the following cases.
+        self.token() == SyntaxKind::CloseBraceToken
+            || self.token() == SyntaxKind::EndOfFileToken
+            || self.has_preceding_line_break()
+    }
+
+    fn parse_semicolon(&mut self) -> bool {
+        if self.can_parse_semicolon() {
+            if self.token() == SyntaxKind::SemicolonToken {
+                // consume the semicolon if it was explicitly provided.
+                self.next_token();
+            }
+            return true;
+        }
+        self.parse_expected(SyntaxKind::SemicolonToken, None)
+    }
+
+    // Identifiers and names
+
+    fn is_identifier(&self) -> bool {
+        let token = self.token();
+        if token == SyntaxKind::Identifier {
+            return true;
+        }
+        // If we have a 'yield' keyword, and we're in the [yield] context, then 'yield' is
+        // considered a keyword and is not an identifier.
+        if token == SyntaxKind::YieldKeyword && self.in_yield_context() {
+            return false;
+        }
+        // If we have a 'await' keyword, and we're in the [Await] context, then 'await' is
+        // considered a keyword and is not an identifier.
+        if token == SyntaxKind::AwaitKeyword && self.in_await_context() {
+            return false;
+        }
+        token > SyntaxKind::LastReservedWord
+    }
+
+    // An identifier that starts with two underscores has an extra underscore prepended to it to
+    // avoid issues with magic property names like '__proto__'.
+    fn create_identifier(
+        &mut self,
+        is_identifier: bool,
+        message: Option<&DiagnosticMessage>,
+    ) -> NodeId {
+        if is_identifier {
+            let pos = self.node_pos();
+            let token = self.token();
+            // Store original token kind if it is not just an Identifier so we can report
+            // appropriate error later in type checker
+            let original_keyword_kind = if token != SyntaxKind::Identifier {
+                Some(token)
+            } else {
+                None
+            };
+            let escaped_text = escape_leading_underscores(&self.scanner.get_token_value());
+            self.next_token();
+            return self.finish_node(
+                SyntaxKind::Identifier,
+                pos,
+                NodeData::Identifier {
+                    escaped_text,
+                    original_keyword_kind,
+                },
+            );
+        }
+        // Only for end of file because the error gets reported incorrectly on embedded script tags.
+        let report_at_current_position = self.token() == SyntaxKind::EndOfFileToken;
+        self.create_missing_node(
+            SyntaxKind::Identifier,
+            report_at_current_position,
+            message.unwrap_or(&diagnostics::IDENTIFIER_EXPECTED),
+            &[],
+        )
+    }
+
+    fn parse_identifier(&mut self, message: Option<&DiagnosticMessage>) -> NodeId {
+        let is_identifier = self.is_identifier();
+        self.create_identifier(is_identifier, message)
+    }
+
+    fn parse_identifier_name(&mut self, message: Option<&DiagnosticMessage>) -> NodeId {
+        let is_identifier = self.token().token_is_identifier_or_keyword();
+        self.create_identifier(is_identifier, message)
+    }
+
+    fn parse_optional_identifier(&mut self) -> Option<NodeId> {
+        if self.is_identifier() {
+            Some(self.parse_identifier(None))
+        } else {
+            None
+        }
+    }
+
+    fn is_literal_property_name(&self) -> bool {
+        self.token().token_is_identifier_or_keyword()
+            || self.token() == SyntaxKind::StringLiteral
+            || self.token() == SyntaxKind::NumericLiteral
+    }
+
+    fn parse_property_name_worker(&mut self, allow_computed_property_names: bool) -> NodeId {
+        if self.token() == SyntaxKind::StringLiteral || self.token() == SyntaxKind::NumericLiteral {
+            return self.parse_literal_node();
+        }
+        if allow_computed_property_names && self.token() == SyntaxKind::OpenBracketToken {
+            return self.parse_computed_property_name();
+        }
+        self.parse_identifier_name(None)
+    }
+
+    fn parse_property_name(&mut self) -> NodeId {
+        self.parse_property_name_worker(true)
+    }
+
+    fn parse_computed_property_name(&mut self) -> NodeId {
+        // PropertyName [Yield]:
+        //      LiteralPropertyName
+        //      ComputedPropertyName[?Yield]
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenBracketToken, None);
+        // We parse any expression (including a comma expression). But the grammar
+        // says that only an assignment expression is allowed, so the grammar checker
+        // will error if it sees a comma expression.
+        let expression = self.allow_in_and(Parser::parse_expression);
+        self.parse_expected(SyntaxKind::CloseBracketToken, None);
+        self.finish_node(
+            SyntaxKind::ComputedPropertyName,
+            pos,
+            NodeData::ComputedPropertyName { expression },
+        )
+    }
+
+    fn parse_entity_name(
+        &mut self,
+        allow_reserved_words: bool,
+        message: Option<&DiagnosticMessage>,
+    ) -> NodeId {
+        let mut entity = if allow_reserved_words {
+            self.parse_identifier_name(message)
+        } else {
+            self.parse_identifier(message)
+        };
+        while self.parse_optional(SyntaxKind::DotToken) {
+            if self.token() == SyntaxKind::LessThanToken {
+                // the entity is part of a JSDoc-style generic, so record the trailing dot for
+                // later error reporting
+                break;
+            }
+            let pos = self.arena[entity].pos;
+            let right = self.parse_right_side_of_dot(allow_reserved_words);
+            entity = self.finish_node(
+                SyntaxKind::QualifiedName,
+                pos,
+                NodeData::QualifiedName {
+                    left: entity,
+                    right,
+                },
+            );
+        }
+        entity
+    }
+
+    fn parse_right_side_of_dot(&mut self, allow_identifier_names: bool) -> NodeId {
+        // Technically a keyword is valid here as all identifiers and keywords are identifier
+        // names. However, often we'll encounter this in error situations when the identifier
+        // or keyword is actually starting another valid construct.
+        //
+        // So, we check for the following specific case:
+        //
+        //      name.
+        //      identifierOrKeyword identifierNameOrKeyword
+        //
+        // Note: the newlines are important here.  For example, if that above code
+        // were rewritten into:
+        //
+        //      name.identifierOrKeyword
+        //      identifierNameOrKeyword
+        //
+        // Then we would consider it valid.  That's because ASI would take effect and
+        // the code would be implicitly: "name.identifierOrKeyword; identifierNameOrKeyword".
+        // In the first case though, ASI will not take effect because there is not a
+        // line terminator after the identifier or keyword.
+        if self.has_preceding_line_break() && self.token().token_is_identifier_or_keyword() {
+            let matches_pattern =
+                self.look_ahead(Parser::next_token_is_identifier_or_keyword_on_same_line);
+            if matches_pattern {
+                // Report that we need an identifier.  However, report it right after the dot,
+                // and not on the next token.  This is because the next token might actually
+                // be an identifier and the error would be quite confusing.
+                return self.create_missing_node(
+                    SyntaxKind::Identifier,
+                    true,
+                    &diagnostics::IDENTIFIER_EXPECTED,
+                    &[],
+                );
+            }
+        }
+        if allow_identifier_names {
+            self.parse_identifier_name(None)
+        } else {
+            self.parse_identifier(None)
+        }
+    }
+
+    // Literals
+
+    fn parse_literal_node(&mut self) -> NodeId {
+        let kind = self.token();
+        self.parse_literal_like_node(kind)
+    }
+
+    fn parse_literal_like_node(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.node_pos();
+        let text = self.scanner.get_token_value();
+        // Only keep the flags that describe the literal itself, not the trivia before it.
+        let token_flags = self.scanner.get_token_flags()
+            & !(TokenFlags::PRECEDING_LINE_BREAK | TokenFlags::PRECEDING_JSDOC_COMMENT);
+        self.next_token();
+        self.finish_node(kind, pos, NodeData::Literal { text, token_flags })
+    }
+
+    fn parse_template_expression(&mut self, is_tagged_template: bool) -> NodeId {
+        let pos = self.node_pos();
+        let head = self.parse_template_head(is_tagged_template);
+        debug_assert_eq!(
+            self.arena[head].kind,
+            SyntaxKind::TemplateHead,
+            "Template head has wrong token kind"
+        );
+
+        let list_pos = self.node_pos();
+        let mut spans = Vec::new();
+        loop {
+            let span = self.parse_template_span(is_tagged_template);
+            spans.push(span);
+            let literal = match self.arena[span].data {
+                NodeData::TemplateSpan { literal, .. } => literal,
+                _ => unreachable!(),
+            };
+            if self.arena[literal].kind != SyntaxKind::TemplateMiddle {
+                break;
+            }
+        }
+        let template_spans = NodeList::new(list_pos, self.node_pos(), spans);
+        self.finish_node(
+            SyntaxKind::TemplateExpression,
+            pos,
+            NodeData::TemplateExpression {
+                head,
+                template_spans,
+            },
+        )
+    }
+
+    fn parse_template_span(&mut self, is_tagged_template: bool) -> NodeId {
+        let pos = self.node_pos();
+        let expression = self.allow_in_and(Parser::parse_expression);
+        let literal = if self.token() == SyntaxKind::CloseBraceToken {
+            self.re_scan_template_token(is_tagged_template);
+            self.parse_template_middle_or_template_tail()
+        } else {
+            let text = token_to_string(SyntaxKind::CloseBraceToken as u32).unwrap_or_default();
+            self.parse_optional_token(SyntaxKind::TemplateTail)
+                .unwrap_or_else(|| {
+                    self.create_missing_node(
+                        SyntaxKind::TemplateTail,
+                        false,
+                        &diagnostics::_0_EXPECTED,
+                        &[&text],
+                    )
+                })
+        };
+        self.finish_node(
+            SyntaxKind::TemplateSpan,
+            pos,
+            NodeData::TemplateSpan {
+                expression,
+                literal,
+            },
+        )
+    }
+
+    fn parse_template_head(&mut self, is_tagged_template: bool) -> NodeId {
+        if is_tagged_template {
+            self.re_scan_template_head_or_no_substitution_template();
+        }
+        let kind = self.token();
+        self.parse_literal_like_node(kind)
+    }
+
+    fn parse_template_middle_or_template_tail(&mut self) -> NodeId {
+        let kind = self.token();
+        let fragment = self.parse_literal_like_node(kind);
+        debug_assert!(
+            kind == SyntaxKind::TemplateMiddle || kind == SyntaxKind::TemplateTail,
+            "Template fragment has wrong token kind"
+        );
+        fragment
+    }
+
+    // Lists
+
+    fn is_list_element(
+        &mut self,
+        parsing_context: ParsingContext,
+        in_error_recovery: bool,
+    ) -> bool {
+        match parsing_context {
+            ParsingContext::SourceElements
+            | ParsingContext::BlockStatements
+            | ParsingContext::SwitchClauseStatements => {
+                // If we're in error recovery, then we don't want to treat ';' as an empty
+                // statement. The problem is that ';' can show up in far too many contexts, and
+                // if we see one and assume it's a statement, then we may bail out inappropriately
+                // from whatever we're parsing.  For example, if we have a semicolon in the middle
+                // of a class, then we really don't want to assume the class is over and we're on
+                // a statement in the outer module.  We just want to consume and move on.
+                !(self.token() == SyntaxKind::SemicolonToken && in_error_recovery)
+                    && self.is_start_of_statement()
+            }
+            ParsingContext::SwitchClauses => {
+                self.token() == SyntaxKind::CaseKeyword
+                    || self.token() == SyntaxKind::DefaultKeyword
+            }
+            ParsingContext::TypeMembers => self.look_ahead(Parser::is_type_member_start),
+            ParsingContext::ClassMembers => {
+                // We allow semicolons as class elements (as specified by ES6) as long as we're
+                // not in error recovery.  If we're in error recovery, we don't want an errant
+                // semicolon to be treated as a class member (since they're almost always used
+                // for statements.
+                self.look_ahead(Parser::is_class_member_start)
+                    || (self.token() == SyntaxKind::SemicolonToken && !in_error_recovery)
+            }
+            ParsingContext::EnumMembers => {
+                // Include open bracket computed properties. This technically also lets in
+                // indexers, which would be a pretty odd thing to have.
+                self.token() == SyntaxKind::OpenBracketToken || self.is_literal_property_name()
+            }
+            ParsingContext::HeritageClauseElement => {
+                // If we see `{ ... }` then only consume it as an expression if it is followed by
+                // `,` or `{`. That way we won't consume the body of a class in its heritage clause.
+                if self.token() == SyntaxKind::OpenBraceToken {
+                    return self.look_ahead(Parser::is_valid_heritage_clause_object_literal);
+                }
+                if !in_error_recovery {
+                    self.is_start_of_left_hand_side_expression()
+                        && !self.is_heritage_clause_extends_or_implements_keyword()
+                } else {
+                    // If we're in error recovery we tighten up what we're willing to match.
+                    // That way we don't treat something like "this" as a valid heritage clause
+                    // element during recovery.
+                    self.is_identifier() && !self.is_heritage_clause_extends_or_implements_keyword()
+                }
+            }
+            ParsingContext::VariableDeclarations => self.is_identifier_or_pattern(),
+            ParsingContext::ObjectBindingElements => {
+                self.token() == SyntaxKind::OpenBracketToken
+                    || self.token() == SyntaxKind::DotDotDotToken
+                    || self.is_literal_property_name()
+            }
+            ParsingContext::ArrayBindingElements => {
+                self.token() == SyntaxKind::CommaToken
+                    || self.token() == SyntaxKind::DotDotDotToken
+                    || self.is_identifier_or_pattern()
+            }
+            ParsingContext::ArgumentExpressions => {
+                self.token() == SyntaxKind::DotDotDotToken || self.is_start_of_expression()
+            }
+            ParsingContext::ObjectLiteralMembers => {
+                self.token() == SyntaxKind::OpenBracketToken
+                    || self.token() == SyntaxKind::AsteriskToken
+                    || self.token() == SyntaxKind::DotDotDotToken
+                    // Not valid, but should allow the parser to recover
+                    || self.token() == SyntaxKind::DotToken
+                    || self.is_literal_property_name()
+            }
+            ParsingContext::ArrayLiteralMembers => {
+                self.token() == SyntaxKind::CommaToken
+                    // Not valid, but should allow the parser to recover
+                    || self.token() == SyntaxKind::DotToken
+                    || self.token() == SyntaxKind::DotDotDotToken
+                    || self.is_start_of_expression()
+            }
+            ParsingContext::Parameters => self.is_start_of_parameter(),
+            ParsingContext::TypeParameters => self.is_identifier(),
+            ParsingContext::TypeArguments | ParsingContext::TupleElementTypes => {
+                self.token() == SyntaxKind::CommaToken || self.is_start_of_type(false)
+            }
+            ParsingContext::HeritageClauses => self.is_heritage_clause(),
+            ParsingContext::ImportOrExportSpecifiers => {
+                self.token().token_is_identifier_or_keyword()
+            }
+        }
+    }
+
+    fn is_valid_heritage_clause_object_literal(&mut self) -> bool {
+        debug_assert_eq!(self.token(), SyntaxKind::OpenBraceToken);
+        if self.next_token() == SyntaxKind::CloseBraceToken {
+            // if we see "extends {}" then only treat the {} as what we're extending (and not
+            // the class body) if we have:
+            //
+            //      extends {} {
+            //      extends {},
+            //      extends {} extends
+            //      extends {} implements
+            let next = self.next_token();
+            return next == SyntaxKind::CommaToken
+                || next == SyntaxKind::OpenBraceToken
+                || next == SyntaxKind::ExtendsKeyword
+                || next == SyntaxKind::ImplementsKeyword;
+        }
+        true
+    }
+
+    fn is_heritage_clause_extends_or_implements_keyword(&mut self) -> bool {
+        if self.token() == SyntaxKind::ImplementsKeyword
+            || self.token() == SyntaxKind::ExtendsKeyword
+        {
+            return self.look_ahead(Parser::next_token_is_start_of_expression);
+        }
+        false
+    }
+
+    // True if positioned at a list terminator
+    fn is_list_terminator(&self, kind: ParsingContext) -> bool {
+        if self.token() == SyntaxKind::EndOfFileToken {
+            // Being at the end of the file ends all lists.
+            return true;
+        }
+        match kind {
+            ParsingContext::BlockStatements
+            | ParsingContext::SwitchClauses
+            | ParsingContext::TypeMembers
+            | ParsingContext::ClassMembers
+            | ParsingContext::EnumMembers
+            | ParsingContext::ObjectLiteralMembers
+            | ParsingContext::ObjectBindingElements
+            | ParsingContext::ImportOrExportSpecifiers => {
+                self.token() == SyntaxKind::CloseBraceToken
+            }
+            ParsingContext::SwitchClauseStatements => {
+                self.token() == SyntaxKind::CloseBraceToken
+                    || self.token() == SyntaxKind::CaseKeyword
+                    || self.token() == SyntaxKind::DefaultKeyword
+            }
+            ParsingContext::HeritageClauseElement => {
+                self.token() == SyntaxKind::OpenBraceToken
+                    || self.token() == SyntaxKind::ExtendsKeyword
+                    || self.token() == SyntaxKind::ImplementsKeyword
+            }
+            ParsingContext::VariableDeclarations => self.is_variable_declarator_list_terminator(),
+            ParsingContext::TypeParameters => {
+                // Tokens other than '>' are here for better error recovery
+                self.token() == SyntaxKind::GreaterThanToken
+                    || self.token() == SyntaxKind::OpenParenToken
+                    || self.token() == SyntaxKind::OpenBraceToken
+                    || self.token() == SyntaxKind::ExtendsKeyword
+                    || self.token() == SyntaxKind::ImplementsKeyword
+            }
+            ParsingContext::ArgumentExpressions => {
+                // Tokens other than ')' are here for better error recovery
+                self.token() == SyntaxKind::CloseParenToken
+                    || self.token() == SyntaxKind::SemicolonToken
+            }
+            ParsingContext::ArrayLiteralMembers
+            | ParsingContext::TupleElementTypes
+            | ParsingContext::ArrayBindingElements => self.token() == SyntaxKind::CloseBracketToken,
+            ParsingContext::Parameters => {
+                // Tokens other than ')' and ']' (the latter for index signatures) are here for
+                // better error recovery
+                self.token() == SyntaxKind::CloseParenToken
+                    || self.token() == SyntaxKind::CloseBracketToken
+            }
+            ParsingContext::TypeArguments => {
+                // All other tokens should cause the type-argument to terminate except comma token
+                self.token() != SyntaxKind::CommaToken
+            }
+            ParsingContext::HeritageClauses => {
+                self.token() == SyntaxKind::OpenBraceToken
+                    || self.token() == SyntaxKind::CloseBraceToken
+            }
+            ParsingContext::SourceElements => false,
+        }
+    }
+
+    fn is_variable_declarator_list_terminator(&self) -> bool {
+        // If we can consume a semicolon (either explicitly, or with ASI), then consider us done
+        // with parsing the list of variable declarators.
+        if self.can_parse_semicolon() {
+            return true;
+        }
+
+        // in the case where we're parsing the variable declarator of a 'for-in' statement, we
+        // are done if we see an 'in' keyword in front of us. Same with for-of
+        if is_in_or_of_keyword(self.token()) {
+            return true;
+        }
+
+        // ERROR RECOVERY TWEAK:
+        // For better error recovery, if we see an '=>' then we just stop immediately.  We've got
+        // an arrow function here and it's going to be very unlikely that we'll resynchronize and
+        // get another variable declaration.
+        if self.token() == SyntaxKind::EqualsGreaterThanToken {
+            return true;
+        }
+
+        // Keep trying to parse out variable declarators.
+        false
+    }
+
+    // True if positioned at element or terminator of the current list or any enclosing list
+    fn is_in_some_parsing_context(&mut self) -> bool {
+        for kind in ParsingContext::ALL.iter().cloned() {
+            if self.parsing_context & kind.bit() != 0
+                && (self.is_list_element(kind, /*in_error_recovery*/ true)
+                    || self.is_list_terminator(kind))
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Parses a list of elements
+    fn parse_list(
+        &mut self,
+        kind: ParsingContext,
+        parse_element: fn(&mut Parser) -> NodeId,
+    ) -> NodeList {
+        let save_parsing_context = self.parsing_context;
+        self.parsing_context |= kind.bit();
+        let list_pos = self.node_pos();
+        let mut list = Vec::new();
+
+        while !self.is_list_terminator(kind) {
+            if self.is_list_element(kind, /*in_error_recovery*/ false) {
+                list.push(parse_element(self));
+                continue;
+            }
+            if self.abort_parsing_list_or_move_to_next_token(kind) {
+                break;
+            }
+        }
+
+        self.parsing_context = save_parsing_context;
+        NodeList::new(list_pos, self.node_pos(), list)
+    }
+
+    // Returns true if we should abort parsing.
+    fn abort_parsing_list_or_move_to_next_token(&mut self, kind: ParsingContext) -> bool {
+        let (message, args) = parsing_context_errors(kind);
+        self.parse_error_at_current_token(message, args);
+        if self.is_in_some_parsing_context() {
+            return true;
+        }
+        self.next_token();
+        false
+    }
+
+    // Parses a comma-delimited list of elements
+    fn parse_delimited_list(
+        &mut self,
+        kind: ParsingContext,
+        parse_element: fn(&mut Parser) -> NodeId,
+        consider_semicolon_as_delimiter: bool,
+    ) -> NodeList {
+        let save_parsing_context = self.parsing_context;
+        self.parsing_context |= kind.bit();
+        let list_pos = self.node_pos();
+        let mut list = Vec::new();
+        let mut has_trailing_comma = false;
+
+        loop {
+            if self.is_list_element(kind, /*in_error_recovery*/ false) {
+                let start_pos = self.scanner.get_start_pos();
+                list.push(parse_element(self));
+                if self.parse_optional(SyntaxKind::CommaToken) {
+                    // No need to check for a zero length node since we know we parsed a comma
+                    has_trailing_comma = true;
+                    continue;
+                }
+                has_trailing_comma = false;
+                if self.is_list_terminator(kind) {
+                    break;
+                }
+                // We didn't get a comma, and the list wasn't terminated, explicitly parse
+                // out a comma so we give a good error message.
+                self.parse_expected(SyntaxKind::CommaToken, None);
+
+                // If the token was a semicolon, and the caller allows that, then skip it and
+                // continue.  This ensures we get back on track and don't result in tons of
+                // parse errors.  For example, this can happen when people do things like use
+                // a semicolon to delimit object literal members.   Note: we'll have already
+                // reported an error when we called parseExpected above.
+                if consider_semicolon_as_delimiter
+                    && self.token() == SyntaxKind::SemicolonToken
+                    && !self.has_preceding_line_break()
+                {
+                    self.next_token();
+                }
+                if start_pos == self.scanner.get_start_pos() {
+                    // What we're parsing isn't actually remotely recognizable as a element and
+                    // we've consumed no tokens.  Consume a token to advance the parser in some
+                    // way and avoid an infinite loop.  This can happen when we're speculatively
+                    // parsing parenthesized expressions which can't be arrow functions.
+                    self.next_token();
+                }
+                continue;
+            }
+
+            if self.is_list_terminator(kind) {
+                break;
+            }
+            if self.abort_parsing_list_or_move_to_next_token(kind) {
+                break;
+            }
+        }
+
+        self.parsing_context = save_parsing_context;
+        let mut result = NodeList::new(list_pos, self.node_pos(), list);
+        // Recording the trailing comma is deliberately done after the previous
+        // loop, and not just if we see a list terminator. This is because the list
+        // may have ended incorrectly, but it is still important to know if there
+        // was a trailing comma.
+        // Check if the last token was a comma.
+        result.has_trailing_comma = has_trailing_comma;
+        result
+    }
+
+    fn parse_bracketed_list(
+        &mut self,
+        kind: ParsingContext,
+        parse_element: fn(&mut Parser) -> NodeId,
+        open: SyntaxKind,
+        close: SyntaxKind,
+    ) -> NodeList {
+        if self.parse_expected(open, None) {
+            let result = self.parse_delimited_list(kind, parse_element, false);
+            self.parse_expected(close, None);
+            return result;
+        }
+        self.create_missing_list()
+    }
+
+    // Types
+
+    fn parse_type_reference(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let type_name = self.parse_entity_name_of_type_reference();
+        let type_arguments = self.try_parse_type_arguments_of_type_reference();
+        self.finish_node(
+            SyntaxKind::TypeReference,
+            pos,
+            NodeData::TypeReference {
+                type_name,
+                type_arguments,
+            },
+        )
+    }
+
+    fn parse_entity_name_of_type_reference(&mut self) -> NodeId {
+        self.parse_entity_name(true, Some(&diagnostics::TYPE_EXPECTED))
+    }
+
+    fn try_parse_type_arguments_of_type_reference(&mut self) -> Option<NodeList> {
+        if !self.has_preceding_line_break()
+            && self.re_scan_less_than_token() == SyntaxKind::LessThanToken
+        {
+            return Some(self.parse_bracketed_list(
+                ParsingContext::TypeArguments,
+                Parser::parse_type,
+                SyntaxKind::LessThanToken,
+                SyntaxKind::GreaterThanToken,
+            ));
+        }
+        None
+    }
+
+    fn parse_this_type_predicate(&mut self, lhs: NodeId) -> NodeId {
+        self.next_token();
+        let pos = self.arena[lhs].pos;
+        let ty = self.parse_type();
+        self.finish_node(
+            SyntaxKind::TypePredicate,
+            pos,
+            NodeData::TypePredicate {
+                parameter_name: lhs,
+                ty,
+            },
+        )
+    }
+
+    fn parse_this_type_node(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.next_token();
+        self.finish_node(SyntaxKind::ThisType, pos, NodeData::Empty {})
+    }
+
+    fn parse_type_query(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::TypeOfKeyword, None);
+        let expr_name = self.parse_entity_name(true, None);
+        self.finish_node(
+            SyntaxKind::TypeQuery,
+            pos,
+            NodeData::TypeQuery { expr_name },
+        )
+    }
+
+    fn parse_type_parameter(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let name = self.parse_identifier(None);
+        let mut constraint = None;
+        let mut expression = None;
+        if self.parse_optional(SyntaxKind::ExtendsKeyword) {
+            // It's not uncommon for people to write improper constraints to a generic.  If the
+            // user writes a constraint that is an expression and not an actual type, then parse
+            // it out as an expression (so we can recover well), but report that a type is needed
+            // instead.
+            if self.is_start_of_type(false) || !self.is_start_of_expression() {
+                constraint = Some(self.parse_type());
+            } else {
+                // It was not a type, and it looked like an expression.  Parse out an expression
+                // here so we recover well.  Note: it is important that we call parseUnaryExpression
+                // and not parseExpression here.  If the user has:
+                //
+                //      <T extends "">
+                //
+                // We do *not* want to consume the `>` as we're consuming the expression for "".
+                expression = Some(self.parse_unary_expression_or_higher());
+            }
+        }
+        let default = if self.parse_optional(SyntaxKind::EqualsToken) {
+            Some(self.parse_type())
+        } else {
+            None
+        };
+        self.finish_node(
+            SyntaxKind::TypeParameter,
+            pos,
+            NodeData::TypeParameter {
+                name,
+                constraint,
+                default,
+                expression,
+            },
+        )
+    }
+
+    fn parse_type_parameters(&mut self) -> Option<NodeList> {
+        if self.token() == SyntaxKind::LessThanToken {
+            return Some(self.parse_bracketed_list(
+                ParsingContext::TypeParameters,
+                Parser::parse_type_parameter,
+                SyntaxKind::LessThanToken,
+                SyntaxKind::GreaterThanToken,
+            ));
+        }
+        None
+    }
+
+    fn is_start_of_parameter(&mut self) -> bool {
+        self.token() == SyntaxKind::DotDotDotToken
+            || self.is_identifier_or_pattern()
+            || is_modifier_kind(self.token())
+            || self.token() == SyntaxKind::AtToken
+            || self.is_start_of_type(/*in_start_of_parameter*/ true)
+    }
+
+    fn parse_parameter(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        if self.token() == SyntaxKind::ThisKeyword {
+            let name = self.create_identifier(true, None);
+            let ty = self.parse_type_annotation();
+            return self.finish_node(
+                SyntaxKind::Parameter,
+                pos,
+                NodeData::Parameter {
+                    dot_dot_dot_token: None,
+                    name,
+                    question_token: None,
+                    ty,
+                    initializer: None,
+                },
+            );
+        }
+
+        let decorators = self.parse_decorators();
+        let modifiers = self.parse_modifiers(false);
+        let dot_dot_dot_token = self.parse_optional_token(SyntaxKind::DotDotDotToken);
+
+        // FormalParameter [Yield,Await]:
+        //      BindingElement[?Yield,?Await]
+        let name = self.parse_identifier_or_pattern();
+        if self.node_is_missing(name) && modifiers.is_none() && is_modifier_kind(self.token()) {
+            // in cases like
+            // 'use strict'
+            // function foo(static)
+            // isParameter('static') === true, because of isModifier('static')
+            // however 'static' is not a legal identifier in a strict mode.
+            // so result of this function will be ParameterDeclaration (flags = 0, name = missing, type = undefined, initializer = undefined)
+            // and current token will not change => parsing of the enclosing parameter list will last till the end of time (or OOM)
+            // to avoid this we'll advance cursor to the next token.
+            self.next_token();
+        }
+
+        let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+        let ty = self.parse_type_annotation();
+        let initializer = self.parse_initializer();
+        self.finish_declaration(
+            DeclarationStart {
+                pos,
+                decorators,
+                modifiers,
+            },
+            SyntaxKind::Parameter,
+            NodeData::Parameter {
+                dot_dot_dot_token,
+                name,
+                question_token,
+                ty,
+                initializer,
+            },
+        )
+    }
+
+    fn fill_signature(&mut self, return_token: SyntaxKind, flags: SignatureFlags) -> Signature {
+        let type_parameters = self.parse_type_parameters();
+        let (parameters, parameters_parsed_successfully) = self.parse_parameter_list(flags);
+        let mut ty = None;
+        let mut parsed_successfully = parameters_parsed_successfully;
+        if self.should_parse_return_type(return_token, flags.contains(SignatureFlags::TYPE)) {
+            let return_type = self.parse_type_or_type_predicate();
+            if self.type_has_arrow_function_blocking_parse_error(return_type) {
+                parsed_successfully = false;
+            }
+            ty = Some(return_type);
+        }
+        Signature {
+            type_parameters,
+            parameters,
+            ty,
+            parsed_successfully,
+        }
+    }
+
+    fn type_has_arrow_function_blocking_parse_error(&self, node: NodeId) -> bool {
+        match &self.arena[node].data {
+            NodeData::TypeReference { type_name, .. } => self.node_is_missing(*type_name),
+            NodeData::Signature { ty: Some(ty), .. } => {
+                self.type_has_arrow_function_blocking_parse_error(*ty)
+            }
+            NodeData::WrappedType { ty }
+                if self.arena[node].kind == SyntaxKind::ParenthesizedType =>
+            {
+                self.type_has_arrow_function_blocking_parse_error(*ty)
+            }
+            _ => false,
+        }
+    }
+
+    fn should_parse_return_type(&mut self, return_token: SyntaxKind, is_type: bool) -> bool {
+        if return_token == SyntaxKind::EqualsGreaterThanToken {
+            self.parse_expected(return_token, None);
+            return true;
+        } else if self.parse_optional(SyntaxKind::ColonToken) {
+            return true;
+        } else if is_type && self.token() == SyntaxKind::EqualsGreaterThanToken {
+            // This is easy to get backward, especially in type contexts, so parse the type anyway
+            self.parse_error_at_current_token(&diagnostics::_0_EXPECTED, &[":"]);
+            self.next_token();
+            return true;
+        }
+        false
+    }
+
+    // Returns the parameters, and whether the list was complete.
+    fn parse_parameter_list(&mut self, flags: SignatureFlags) -> (NodeList, bool) {
+        // FormalParameters [Yield,Await]: (modified)
+        //      [empty]
+        //      FormalParameterList[?Yield,Await]
+        //
+        // FormalParameter[Yield,Await]: (modified)
+        //      BindingElement[?Yield,Await]
+        //
+        // BindingElement [Yield,Await]: (modified)
+        //      SingleNameBinding[?Yield,?Await]
+        //      BindingPattern[?Yield,?Await]Initializer [In, ?Yield,?Await] opt
+        //
+        // SingleNameBinding [Yield,Await]:
+        //      BindingIdentifier[?Yield,?Await]Initializer [In, ?Yield,?Await] opt
+        if !self.parse_expected(SyntaxKind::OpenParenToken, None) {
+            return (self.create_missing_list(), false);
+        }
+
+        let saved_yield_context = self.in_yield_context();
+        let saved_await_context = self.in_await_context();
+        self.set_context_flag(
+            flags.contains(SignatureFlags::YIELD),
+            NodeFlags::YIELD_CONTEXT,
+        );
+        self.set_context_flag(
+            flags.contains(SignatureFlags::AWAIT),
+            NodeFlags::AWAIT_CONTEXT,
+        );
+
+        let parameters =
+            self.parse_delimited_list(ParsingContext::Parameters, Parser::parse_parameter, false);
+
+        self.set_context_flag(saved_yield_context, NodeFlags::YIELD_CONTEXT);
+        self.set_context_flag(saved_await_context, NodeFlags::AWAIT_CONTEXT);
+
+        let parsed_successfully = self.parse_expected(SyntaxKind::CloseParenToken, None);
+        (parameters, parsed_successfully)
+    }
+
+    fn parse_type_member_semicolon(&mut self) {
+        // We allow type members to be separated by commas or (possibly ASI) semicolons.
+        // First check if it was a comma.  If so, we're done with the member.
+        if self.parse_optional(SyntaxKind::CommaToken) {
+            return;
+        }
+        // Didn't have a comma.  We must have a (possible ASI) semicolon.
+        self.parse_semicolon();
+    }
+
+    fn parse_signature_member(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.node_pos();
+        if kind == SyntaxKind::ConstructSignature {
+            self.parse_expected(SyntaxKind::NewKeyword, None);
+        }
+        let signature = self.fill_signature(SyntaxKind::ColonToken, SignatureFlags::TYPE);
+        self.parse_type_member_semicolon();
+        self.finish_node(
+            kind,
+            pos,
+            NodeData::Signature {
+                name: None,
+                question_token: None,
+                type_parameters: signature.type_parameters,
+                parameters: signature.parameters,
+                ty: signature.ty,
+            },
+        )
+    }
+
+    fn is_index_signature(&mut self) -> bool {
+        self.token() == SyntaxKind::OpenBracketToken
+            && self.look_ahead(Parser::is_unambiguously_index_signature)
+    }
+
+    fn is_unambiguously_index_signature(&mut self) -> bool {
+        // The only allowed sequence is:
+        //
+        //   [id:
+        //
+        // However, for error recovery, we also check the following cases:
+        //
+        //   [...
+        //   [id,
+        //   [id?,
+        //   [id?:
+        //   [id?]
+        //   [public id
+        //   [private id
+        //   [protected id
+        //   []
+        //
+        self.next_token();
+        if self.token() == SyntaxKind::DotDotDotToken
+            || self.token() == SyntaxKind::CloseBracketToken
+        {
+            return true;
+        }
+
+        if is_modifier_kind(self.token()) {
+            self.next_token();
+            if self.is_identifier() {
+                return true;
+            }
+        } else if !self.is_identifier() {
+            return false;
+        } else {
+            // Skip the identifier
+            self.next_token();
+        }
+
+        // A colon signifies a well formed indexer
+        // A comma should be a badly formed indexer because comma expressions are not allowed
+        // in computed properties.
+        if self.token() == SyntaxKind::ColonToken || self.token() == SyntaxKind::CommaToken {
+            return true;
+        }
+
+        // Question mark could be an indexer with an optional property,
+        // or it could be a conditional expression in a computed property.
+        if self.token() != SyntaxKind::QuestionToken {
+            return false;
+        }
+
+        // If any of the following tokens are after the question mark, it cannot
+        // be a conditional expression, so treat it as an indexer.
+        self.next_token();
+        self.token() == SyntaxKind::ColonToken
+            || self.token() == SyntaxKind::CommaToken
+            || self.token() == SyntaxKind::CloseBracketToken
+    }
+
+    fn parse_index_signature_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        let parameters = self.parse_bracketed_list(
+            ParsingContext::Parameters,
+            Parser::parse_parameter,
+            SyntaxKind::OpenBracketToken,
+            SyntaxKind::CloseBracketToken,
+        );
+        let ty = self.parse_type_annotation();
+        self.parse_type_member_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::IndexSignature,
+            NodeData::Signature {
+                name: None,
+                question_token: None,
+                type_parameters: None,
+                parameters,
+                ty,
+            },
+        )
+    }
+
+    fn parse_property_or_method_signature(&mut self, start: DeclarationStart) -> NodeId {
+        let name = self.parse_property_name();
+        let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+        if self.token() == SyntaxKind::OpenParenToken || self.token() == SyntaxKind::LessThanToken {
+            // Method signatures don't exist in expression contexts.  So they have neither
+            // [Yield] nor [Await]
+            let signature = self.fill_signature(SyntaxKind::ColonToken, SignatureFlags::TYPE);
+            self.parse_type_member_semicolon();
+            return self.finish_declaration(
+                start,
+                SyntaxKind::MethodSignature,
+                NodeData::Signature {
+                    name: Some(name),
+                    question_token,
+                    type_parameters: signature.type_parameters,
+                    parameters: signature.parameters,
+                    ty: signature.ty,
+                },
+            );
+        }
+
+        let ty = self.parse_type_annotation();
+        // Although type literal properties cannot not have initializers, we attempt
+        // to parse an initializer so we can report in the checker that an interface
+        // property or type literal property cannot have an initializer.
+        let initializer = if self.token() == SyntaxKind::EqualsToken {
+            self.parse_initializer()
+        } else {
+            None
+        };
+        self.parse_type_member_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::PropertySignature,
+            NodeData::PropertySignature {
+                name,
+                question_token,
+                ty,
+                initializer,
+            },
+        )
+    }
+
+    fn is_type_member_start(&mut self) -> bool {
+        // Return true if we have the start of a signature member
+        if self.token() == SyntaxKind::OpenParenToken || self.token() == SyntaxKind::LessThanToken {
+            return true;
+        }
+        let mut id_token = false;
+        // Eat up all modifiers, but hold on to the last one in case it is actually an identifier
+        while is_modifier_kind(self.token()) {
+            id_token = true;
+            self.next_token();
+        }
+        // Index signatures and computed property names are type members
+        if self.token() == SyntaxKind::OpenBracketToken {
+            return true;
+        }
+        // Try to get the first property-like token following all modifiers
+        if self.is_literal_property_name() {
+            id_token = true;
+            self.next_token();
+        }
+        // If we were able to get any potential identifier, check that it is
+        // the start of a member declaration
+        if id_token {
+            return self.token() == SyntaxKind::OpenParenToken
+                || self.token() == SyntaxKind::LessThanToken
+                || self.token() == SyntaxKind::QuestionToken
+                || self.token() == SyntaxKind::ColonToken
+                || self.token() == SyntaxKind::CommaToken
+                || self.can_parse_semicolon();
+        }
+        false
+    }
+
+    fn parse_type_member(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::OpenParenToken || self.token() == SyntaxKind::LessThanToken {
+            return self.parse_signature_member(SyntaxKind::CallSignature);
+        }
+        if self.token() == SyntaxKind::NewKeyword
+            && self.look_ahead(Parser::next_token_is_open_paren_or_less_than)
+        {
+            return self.parse_signature_member(SyntaxKind::ConstructSignature);
+        }
+        let pos = self.node_pos();
+        let modifiers = self.parse_modifiers(false);
+        let start = DeclarationStart {
+            pos,
+            decorators: None,
+            modifiers,
+        };
+        if self.is_index_signature() {
+            return self.parse_index_signature_declaration(start);
+        }
+        self.parse_property_or_method_signature(start)
+    }
+
+    fn parse_type_literal(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let members = self.parse_object_type_members();
+        self.finish_node(
+            SyntaxKind::TypeLiteral,
+            pos,
+            NodeData::TypeLiteral { members },
+        )
+    }
+
+    fn parse_object_type_members(&mut self) -> NodeList {
+        if self.parse_expected(SyntaxKind::OpenBraceToken, None) {
+            let members = self.parse_list(ParsingContext::TypeMembers, Parser::parse_type_member);
+            self.parse_expected(SyntaxKind::CloseBraceToken, None);
+            return members;
+        }
+        self.create_missing_list()
+    }
+
+    fn is_start_of_mapped_type(&mut self) -> bool {
+        self.next_token();
+        if self.token() == SyntaxKind::PlusToken || self.token() == SyntaxKind::MinusToken {
+            return self.next_token() == SyntaxKind::ReadonlyKeyword;
+        }
+        if self.token() == SyntaxKind::ReadonlyKeyword {
+            self.next_token();
+        }
+        self.token() == SyntaxKind::OpenBracketToken
+            && self.next_token_is_identifier()
+            && self.next_token() == SyntaxKind::InKeyword
+    }
+
+    fn parse_mapped_type_parameter(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let name = self.parse_identifier(None);
+        self.parse_expected(SyntaxKind::InKeyword, None);
+        let constraint = self.parse_type();
+        self.finish_node(
+            SyntaxKind::TypeParameter,
+            pos,
+            NodeData::TypeParameter {
+                name,
+                constraint: Some(constraint),
+                default: None,
+                expression: None,
+            },
+        )
+    }
+
+    fn parse_mapped_type(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken, None);
+        let mut readonly_token = None;
+        if self.token() == SyntaxKind::ReadonlyKeyword
+            || self.token() == SyntaxKind::PlusToken
+            || self.token() == SyntaxKind::MinusToken
+        {
+            let token = self.parse_token_node();
+            if self.arena[token].kind != SyntaxKind::ReadonlyKeyword {
+                self.parse_expected(SyntaxKind::ReadonlyKeyword, None);
+            }
+            readonly_token = Some(token);
+        }
+        self.parse_expected(SyntaxKind::OpenBracketToken, None);
+        let type_parameter = self.parse_mapped_type_parameter();
+        self.parse_expected(SyntaxKind::CloseBracketToken, None);
+        let mut question_token = None;
+        if self.token() == SyntaxKind::QuestionToken
+            || self.token() == SyntaxKind::PlusToken
+            || self.token() == SyntaxKind::MinusToken
+        {
+            let token = self.parse_token_node();
+            if self.arena[token].kind != SyntaxKind::QuestionToken {
+                self.parse_expected(SyntaxKind::QuestionToken, None);
+            }
+            question_token = Some(token);
+        }
+        let ty = self.parse_type_annotation();
+        self.parse_semicolon();
+        self.parse_expected(SyntaxKind::CloseBraceToken, None);
+        self.finish_node(
+            SyntaxKind::MappedType,
+            pos,
+            NodeData::MappedType {
+                readonly_token,
+                type_parameter,
+                question_token,
+                ty,
+            },
+        )
+    }
+
+    fn parse_tuple_element_type(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        if self.parse_optional(SyntaxKind::DotDotDotToken) {
+            let ty = self.parse_type();
+            return self.finish_node(SyntaxKind::RestType, pos, NodeData::WrappedType { ty });
+        }
+        let ty = self.parse_type();
+        // `T?` is only an optional element at the top level of a tuple element; anywhere else
+        // the postfix `?` is left for the enclosing construct to report.
+        if self.token() == SyntaxKind::QuestionToken {
+            self.next_token();
+            return self.finish_node(SyntaxKind::OptionalType, pos, NodeData::WrappedType { ty });
+        }
+        ty
+    }
+
+    fn parse_tuple_type(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let element_types = self.parse_bracketed_list(
+            ParsingContext::TupleElementTypes,
+            Parser::parse_tuple_element_type,
+            SyntaxKind::OpenBracketToken,
+            SyntaxKind::CloseBracketToken,
+        );
+        self.finish_node(
+            SyntaxKind::TupleType,
+            pos,
+            NodeData::TupleType { element_types },
+        )
+    }
+
+    fn parse_parenthesized_type(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let ty = self.parse_type();
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        self.finish_node(
+            SyntaxKind::ParenthesizedType,
+            pos,
+            NodeData::WrappedType { ty },
+        )
+    }
+
+    fn parse_function_or_constructor_type(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let kind = if self.parse_optional(SyntaxKind::NewKeyword) {
+            SyntaxKind::ConstructorType
+        } else {
+            SyntaxKind::FunctionType
+        };
+        let signature =
+            self.fill_signature(SyntaxKind::EqualsGreaterThanToken, SignatureFlags::TYPE);
+        self.finish_node(
+            kind,
+            pos,
+            NodeData::Signature {
+                name: None,
+                question_token: None,
+                type_parameters: signature.type_parameters,
+                parameters: signature.parameters,
+                ty: signature.ty,
+            },
+        )
+    }
+
+    fn parse_keyword_and_no_dot(&mut self) -> Option<NodeId> {
+        let node = self.parse_token_node();
+        if self.token() == SyntaxKind::DotToken {
+            None
+        } else {
+            Some(node)
+        }
+    }
+
+    fn parse_literal_type_node(&mut self, negative: bool) -> NodeId {
+        let pos = self.node_pos();
+        let unary_minus_pos = self.node_pos();
+        if negative {
+            self.next_token();
+        }
+        let mut expression = if self.token() == SyntaxKind::TrueKeyword
+            || self.token() == SyntaxKind::FalseKeyword
+        {
+            self.parse_token_node()
+        } else {
+            let kind = self.token();
+            self.parse_literal_like_node(kind)
+        };
+        if negative {
+            expression = self.finish_node(
+                SyntaxKind::PrefixUnaryExpression,
+                unary_minus_pos,
+                NodeData::PrefixUnaryExpression {
+                    operator: SyntaxKind::MinusToken,
+                    operand: expression,
+                },
+            );
+        }
+        self.finish_node(
+            SyntaxKind::LiteralType,
+            pos,
+            NodeData::LiteralType {
+                literal: expression,
+            },
+        )
+    }
+
+    fn is_start_of_type_of_import_type(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::ImportKeyword
+    }
+
+    fn parse_import_type(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let is_type_of = self.parse_optional(SyntaxKind::TypeOfKeyword);
+        self.parse_expected(SyntaxKind::ImportKeyword, None);
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let argument = self.parse_type();
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        let qualifier = if self.parse_optional(SyntaxKind::DotToken) {
+            Some(self.parse_entity_name_of_type_reference())
+        } else {
+            None
+        };
+        let type_arguments = self.try_parse_type_arguments_of_type_reference();
+        self.finish_node(
+            SyntaxKind::ImportType,
+            pos,
+            NodeData::ImportType {
+                is_type_of,
+                argument,
+                qualifier,
+                type_arguments,
+            },
+        )
+    }
+
+    fn next_token_is_numeric_or_big_int_literal(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::NumericLiteral || self.token() == SyntaxKind::BigIntLiteral
+    }
+
+    fn parse_non_array_type(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::AnyKeyword
+            | SyntaxKind::UnknownKeyword
+            | SyntaxKind::StringKeyword
+            | SyntaxKind::NumberKeyword
+            | SyntaxKind::BigIntKeyword
+            | SyntaxKind::SymbolKeyword
+            | SyntaxKind::BooleanKeyword
+            | SyntaxKind::UndefinedKeyword
+            | SyntaxKind::NeverKeyword
+            | SyntaxKind::ObjectKeyword => {
+                // If these are followed by a dot, then parse these out as a dotted type reference
+                // instead.
+                match self.try_parse(Parser::parse_keyword_and_no_dot) {
+                    Some(node) => node,
+                    None => self.parse_type_reference(),
+                }
+            }
+            SyntaxKind::StringLiteral
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword => self.parse_literal_type_node(false),
+            SyntaxKind::MinusToken => {
+                if self.look_ahead(Parser::next_token_is_numeric_or_big_int_literal) {
+                    self.parse_literal_type_node(true)
+                } else {
+                    self.parse_type_reference()
+                }
+            }
+            SyntaxKind::VoidKeyword | SyntaxKind::NullKeyword => self.parse_token_node(),
+            SyntaxKind::ThisKeyword => {
+                let this_keyword = self.parse_this_type_node();
+                if self.token() == SyntaxKind::IsKeyword && !self.has_preceding_line_break() {
+                    self.parse_this_type_predicate(this_keyword)
+                } else {
+                    this_keyword
+                }
+            }
+            SyntaxKind::TypeOfKeyword => {
+                if self.look_ahead(Parser::is_start_of_type_of_import_type) {
+                    self.parse_import_type()
+                } else {
+                    self.parse_type_query()
+                }
+            }
+            SyntaxKind::OpenBraceToken => {
+                if self.look_ahead(Parser::is_start_of_mapped_type) {
+                    self.parse_mapped_type()
+                } else {
+                    self.parse_type_literal()
+                }
+            }
+            SyntaxKind::OpenBracketToken => self.parse_tuple_type(),
+            SyntaxKind::OpenParenToken => self.parse_parenthesized_type(),
+            SyntaxKind::ImportKeyword => self.parse_import_type(),
+            _ => self.parse_type_reference(),
+        }
+    }
+
+    fn is_start_of_type(&mut self, in_start_of_parameter: bool) -> bool {
+        match self.token() {
+            SyntaxKind::AnyKeyword
+            | SyntaxKind::UnknownKeyword
+            | SyntaxKind::StringKeyword
+            | SyntaxKind::NumberKeyword
+            | SyntaxKind::BigIntKeyword
+            | SyntaxKind::BooleanKeyword
+            | SyntaxKind::ReadonlyKeyword
+            | SyntaxKind::SymbolKeyword
+            | SyntaxKind::UniqueKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::UndefinedKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::ThisKeyword
+            | SyntaxKind::TypeOfKeyword
+            | SyntaxKind::NeverKeyword
+            | SyntaxKind::OpenBraceToken
+            | SyntaxKind::OpenBracketToken
+            | SyntaxKind::LessThanToken
+            | SyntaxKind::BarToken
+            | SyntaxKind::AmpersandToken
+            | SyntaxKind::NewKeyword
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::ObjectKeyword
+            | SyntaxKind::AsteriskToken
+            | SyntaxKind::QuestionToken
+            | SyntaxKind::ExclamationToken
+            | SyntaxKind::DotDotDotToken
+            | SyntaxKind::InferKeyword
+            | SyntaxKind::ImportKeyword
+            | SyntaxKind::KeyOfKeyword => true,
+            SyntaxKind::FunctionKeyword => !in_start_of_parameter,
+            SyntaxKind::MinusToken => {
+                !in_start_of_parameter
+                    && self.look_ahead(Parser::next_token_is_numeric_or_big_int_literal)
+            }
+            SyntaxKind::OpenParenToken => {
+                // Only consider '(' the start of a type if followed by ')', '...', an identifier, a modifier,
+                // or something that starts a type. We don't want to consider things like '(1)' a type.
+                !in_start_of_parameter
+                    && self.look_ahead(Parser::is_start_of_parenthesized_or_function_type)
+            }
+            _ => self.is_identifier(),
+        }
+    }
+
+    fn is_start_of_parenthesized_or_function_type(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::CloseParenToken
+            || self.is_start_of_parameter()
+            || self.is_start_of_type(false)
+    }
+
+    fn parse_postfix_type_or_higher(&mut self) -> NodeId {
+        let mut ty = self.parse_non_array_type();
+        while !self.has_preceding_line_break() && self.token() == SyntaxKind::OpenBracketToken {
+            let pos = self.arena[ty].pos;
+            self.parse_expected(SyntaxKind::OpenBracketToken, None);
+            if self.is_start_of_type(false) {
+                let index_type = self.parse_type();
+                self.parse_expected(SyntaxKind::CloseBracketToken, None);
+                ty = self.finish_node(
+                    SyntaxKind::IndexedAccessType,
+                    pos,
+                    NodeData::IndexedAccessType {
+                        object_type: ty,
+                        index_type,
+                    },
+                );
+            } else {
+                self.parse_expected(SyntaxKind::CloseBracketToken, None);
+                ty = self.finish_node(
+                    SyntaxKind::ArrayType,
+                    pos,
+                    NodeData::ArrayType { element_type: ty },
+                );
+            }
+        }
+        ty
+    }
+
+    fn parse_type_operator(&mut self, operator: SyntaxKind) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(operator, None);
+        let ty = self.parse_type_operator_or_higher();
+        self.finish_node(
+            SyntaxKind::TypeOperator,
+            pos,
+            NodeData::TypeOperator { operator, ty },
+        )
+    }
+
+    fn parse_infer_type(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::InferKeyword, None);
+        let type_parameter_pos = self.node_pos();
+        let name = self.parse_identifier(None);
+        let type_parameter = self.finish_node(
+            SyntaxKind::TypeParameter,
+            type_parameter_pos,
+            NodeData::TypeParameter {
+                name,
+                constraint: None,
+                default: None,
+                expression: None,
+            },
+        );
+        self.finish_node(
+            SyntaxKind::InferType,
+            pos,
+            NodeData::InferType { type_parameter },
+        )
+    }
+
+    fn parse_type_operator_or_higher(&mut self) -> NodeId {
+        let operator = self.token();
+        match operator {
+            SyntaxKind::KeyOfKeyword | SyntaxKind::UniqueKeyword | SyntaxKind::ReadonlyKeyword => {
+                self.parse_type_operator(operator)
+            }
+            SyntaxKind::InferKeyword => self.parse_infer_type(),
+            _ => self.parse_postfix_type_or_higher(),
+        }
+    }
+
+    fn parse_union_or_intersection_type(
+        &mut self,
+        kind: SyntaxKind,
+        parse_constituent_type: fn(&mut Parser) -> NodeId,
+        operator: SyntaxKind,
+    ) -> NodeId {
+        let start = self.scanner.get_start_pos();
+        let has_leading_operator = self.parse_optional(operator);
+        let mut ty = parse_constituent_type(self);
+        if self.token() == operator || has_leading_operator {
+            let mut types = vec![ty];
+            while self.parse_optional(operator) {
+                types.push(parse_constituent_type(self));
+            }
+            let types = NodeList::new(start, self.node_pos(), types);
+            ty = self.finish_node(kind, start, NodeData::UnionOrIntersectionType { types });
+        }
+        ty
+    }
+
+    fn parse_intersection_type_or_higher(&mut self) -> NodeId {
+        self.parse_union_or_intersection_type(
+            SyntaxKind::IntersectionType,
+            Parser::parse_type_operator_or_higher,
+            SyntaxKind::AmpersandToken,
+        )
+    }
+
+    fn parse_union_type_or_higher(&mut self) -> NodeId {
+        self.parse_union_or_intersection_type(
+            SyntaxKind::UnionType,
+            Parser::parse_intersection_type_or_higher,
+            SyntaxKind::BarToken,
+        )
+    }
+
+    fn is_start_of_function_type(&mut self) -> bool {
+        if self.token() == SyntaxKind::LessThanToken {
+            return true;
+        }
+        self.token() == SyntaxKind::OpenParenToken
+            && self.look_ahead(Parser::is_unambiguously_start_of_function_type)
+    }
+
+    fn skip_parameter_start(&mut self) -> bool {
+        if is_modifier_kind(self.token()) {
+            // Skip modifiers
+            self.parse_modifiers(false);
+        }
+        if self.is_identifier() || self.token() == SyntaxKind::ThisKeyword {
+            self.next_token();
+            return true;
+        }
+        if self.token() == SyntaxKind::OpenBracketToken
+            || self.token() == SyntaxKind::OpenBraceToken
+        {
+            // Return true if we can parse an array or object binding pattern with no errors
+            let previous_error_count = self.parse_diagnostics.len();
+            self.parse_identifier_or_pattern();
+            return previous_error_count == self.parse_diagnostics.len();
+        }
+        false
+    }
+
+    fn is_unambiguously_start_of_function_type(&mut self) -> bool {
+        self.next_token();
+        if self.token() == SyntaxKind::CloseParenToken || self.token() == SyntaxKind::DotDotDotToken
+        {
+            // ( )
+            // ( ...
+            return true;
+        }
+        if self.skip_parameter_start() {
+            // We successfully skipped modifiers (if any) and an identifier or binding pattern,
+            // now see if we have something that indicates a parameter declaration
+            if self.token() == SyntaxKind::ColonToken
+                || self.token() == SyntaxKind::CommaToken
+                || self.token() == SyntaxKind::QuestionToken
+                || self.token() == SyntaxKind::EqualsToken
+            {
+                // ( xxx :
+                // ( xxx ,
+                // ( xxx ?
+                // ( xxx =
+                return true;
+            }
+            if self.token() == SyntaxKind::CloseParenToken {
+                self.next_token();
+                if self.token() == SyntaxKind::EqualsGreaterThanToken {
+                    // ( xxx ) =>
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn parse_type_or_type_predicate(&mut self) -> NodeId {
+        let type_predicate_variable = if self.is_identifier() {
+            self.try_parse(Parser::parse_type_predicate_prefix)
+        } else {
+            None
+        };
+        let ty = self.parse_type();
+        match type_predicate_variable {
+            Some(parameter_name) => {
+                let pos = self.arena[parameter_name].pos;
+                self.finish_node(
+                    SyntaxKind::TypePredicate,
+                    pos,
+                    NodeData::TypePredicate { parameter_name, ty },
+                )
+            }
+            None => ty,
+        }
+    }
+
+    fn parse_type_predicate_prefix(&mut self) -> Option<NodeId> {
+        let id = self.parse_identifier(None);
+        if self.token() == SyntaxKind::IsKeyword && !self.has_preceding_line_break() {
+            self.next_token();
+            return Some(id);
+        }
+        None
+    }
+
+    fn parse_type(&mut self) -> NodeId {
+        // The rules about 'yield' only apply to actual code/expression contexts.  They don't
+        // apply to 'type' contexts.  So we disable these parameters here before moving on.
+        self.do_outside_of_context(NodeFlags::TYPE_EXCLUDES_FLAGS, |parser| {
+            parser.parse_type_worker(false)
+        })
+    }
+
+    fn parse_type_worker(&mut self, no_conditional_types: bool) -> NodeId {
+        if self.is_start_of_function_type() || self.token() == SyntaxKind::NewKeyword {
+            return self.parse_function_or_constructor_type();
+        }
+        let ty = self.parse_union_type_or_higher();
+        if !no_conditional_types
+            && !self.has_preceding_line_break()
+            && self.parse_optional(SyntaxKind::ExtendsKeyword)
+        {
+            let pos = self.arena[ty].pos;
+            // The type following 'extends' is not permitted to be another conditional type
+            let extends_type = self.parse_type_worker(true);
+            self.parse_expected(SyntaxKind::QuestionToken, None);
+            let true_type = self.parse_type_worker(false);
+            self.parse_expected(SyntaxKind::ColonToken, None);
+            let false_type = self.parse_type_worker(false);
+            return self.finish_node(
+                SyntaxKind::ConditionalType,
+                pos,
+                NodeData::ConditionalType {
+                    check_type: ty,
+                    extends_type,
+                    true_type,
+                    false_type,
+                },
+            );
+        }
+        ty
+    }
+
+    fn parse_type_annotation(&mut self) -> Option<NodeId> {
+        if self.parse_optional(SyntaxKind::ColonToken) {
+            Some(self.parse_type())
+        } else {
+            None
+        }
+    }
+
+    // Expressions
+
+    fn is_start_of_left_hand_side_expression(&mut self) -> bool {
+        match self.token() {
+            SyntaxKind::ThisKeyword
+            | SyntaxKind::SuperKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NoSubstitutionTemplateLiteral
+            | SyntaxKind::TemplateHead
+            | SyntaxKind::OpenParenToken
+            | SyntaxKind::OpenBracketToken
+            | SyntaxKind::OpenBraceToken
+            | SyntaxKind::FunctionKeyword
+            | SyntaxKind::ClassKeyword
+            | SyntaxKind::NewKeyword
+            | SyntaxKind::SlashToken
+            | SyntaxKind::SlashEqualsToken
+            | SyntaxKind::Identifier => true,
+            SyntaxKind::ImportKeyword => {
+                self.look_ahead(Parser::next_token_is_open_paren_or_less_than_or_dot)
+            }
+            _ => self.is_identifier(),
+        }
+    }
+
+    fn is_start_of_expression(&mut self) -> bool {
+        if self.is_start_of_left_hand_side_expression() {
+            return true;
+        }
+        match self.token() {
+            SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::TildeToken
+            | SyntaxKind::ExclamationToken
+            | SyntaxKind::DeleteKeyword
+            | SyntaxKind::TypeOfKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::PlusPlusToken
+            | SyntaxKind::MinusMinusToken
+            | SyntaxKind::LessThanToken
+            | SyntaxKind::AwaitKeyword
+            | SyntaxKind::YieldKeyword => {
+                // Yes, we include '<' here; it starts a type assertion.
+                true
+            }
+            _ => {
+                // Error tolerance.  If we see the start of some binary operator, we consider
+                // that the start of an expression.  That way we'll parse out a missing identifier,
+                // give a good message about an identifier being missing, and then consume the
+                // rest of the binary expression.
+                if self.is_binary_operator() {
+                    return true;
+                }
+                self.is_identifier()
+            }
+        }
+    }
+
+    fn is_start_of_expression_statement(&mut self) -> bool {
+        // As per the grammar, none of '{' or 'function' or 'class' can start an expression statement.
+        self.token() != SyntaxKind::OpenBraceToken
+            && self.token() != SyntaxKind::FunctionKeyword
+            && self.token() != SyntaxKind::ClassKeyword
+            && self.token() != SyntaxKind::AtToken
+            && self.is_start_of_expression()
+    }
+
+    fn parse_expression(&mut self) -> NodeId {
+        // Expression[in]:
+        //      AssignmentExpression[in]
+        //      Expression[in] , AssignmentExpression[in]
+
+        // clear the decorator context when parsing Expression, as it should be unambiguous when
+        // parsing a decorator
+        let save_decorator_context = self.in_decorator_context();
+        if save_decorator_context {
+            self.set_context_flag(false, NodeFlags::DECORATOR_CONTEXT);
+        }
+
+        let mut expr = self.parse_assignment_expression_or_higher();
+        while let Some(operator_token) = self.parse_optional_token(SyntaxKind::CommaToken) {
+            let right = self.parse_assignment_expression_or_higher();
+            expr = self.make_binary_expression(expr, operator_token, right);
+        }
+
+        if save_decorator_context {
+            self.set_context_flag(true, NodeFlags::DECORATOR_CONTEXT);
+        }
+        expr
+    }
+
+    fn parse_initializer(&mut self) -> Option<NodeId> {
+        if self.parse_optional(SyntaxKind::EqualsToken) {
+            Some(self.parse_assignment_expression_or_higher())
+        } else {
+            None
+        }
+    }
+
+    fn parse_assignment_expression_or_higher(&mut self) -> NodeId {
+        //  AssignmentExpression[in,yield]:
+        //      1) ConditionalExpression[?in,?yield]
+        //      2) LeftHandSideExpression = AssignmentExpression[?in,?yield]
+        //      3) LeftHandSideExpression AssignmentOperator AssignmentExpression[?in,?yield]
+        //      4) ArrowFunctionExpression[?in,?yield]
+        //      5) AsyncArrowFunctionExpression[in,yield,await]
+        //      6) [+Yield] YieldExpression[?In]
+        //
+        // Note: for ease of implementation we treat productions '2' and '3' as the same thing.
+        // (i.e. they're both BinaryExpressions with an assignment operator in it).
+
+        // First, do the simple check if we have a YieldExpression (production '6').
+        if self.is_yield_expression() {
+            return self.parse_yield_expression();
+        }
+
+        // Then, check if we have an arrow function (production '4' and '5') that starts with a
+        // parenthesized parameter list or is an async arrow function.
+        // AsyncArrowFunctionExpression:
+        //      1) async[no LineTerminator here]AsyncArrowBindingIdentifier[?Yield][no LineTerminator here]=>AsyncConciseBody[?In]
+        //      2) CoverCallExpressionAndAsyncArrowHead[?Yield, ?Await][no LineTerminator here]=>AsyncConciseBody[?In]
+        // Production (1) of AsyncArrowFunctionExpression is parsed in
+        // "try_parse_async_simple_arrow_function_expression". And production (2) is parsed in
+        // "try_parse_parenthesized_arrow_function_expression".
+        //
+        // If we do successfully parse arrow-function, we must *not* recurse for productions 1, 2
+        // or 3. An ArrowFunction is not a LeftHandSideExpression, nor does it start a
+        // ConditionalExpression.  So we are done with AssignmentExpression if we see one.
+        if let Some(arrow_expression) = self.try_parse_parenthesized_arrow_function_expression() {
+            return arrow_expression;
+        }
+        if let Some(arrow_expression) = self.try_parse_async_simple_arrow_function_expression() {
+            return arrow_expression;
+        }
+
+        // Now try to see if we're in production '1', '2' or '3'.  A conditional expression can
+        // start with a LogicalOrExpression, while the assignment productions can only start with
+        // LeftHandSideExpressions.
+        //
+        // So, first, we try to just parse out a BinaryExpression.  If we get something that is a
+        // LeftHandSide or higher, then we can try to parse out the assignment expression part.
+        // Otherwise, we try to parse out the conditional expression bit.  We want to allow any
+        // binary expression here, so we pass in the 'lowest' precedence here so that it matches
+        // and consumes anything.
+        let expr = self.parse_binary_expression_or_higher(0);
+
+        // To avoid a look-ahead, we did not handle the case of an arrow function with a single
+        // un-parenthesized parameter ('x => ...') above. We handle it here by checking if the
+        // parsed expression was a single identifier and the current token is an arrow.
+        if self.arena[expr].kind == SyntaxKind::Identifier
+            && self.token() == SyntaxKind::EqualsGreaterThanToken
+        {
+            return self.parse_simple_arrow_function_expression(expr, None);
+        }
+
+        // Now see if we might be in cases '2' or '3'.
+        // If the expression was a LHS expression, and we have an assignment operator, then
+        // we're in '2' or '3'. Consume the assignment and return.
+        //
+        // Note: we call re_scan_greater_token so that we get an appropriately merged token
+        // for cases like `> > =` becoming `>>=`
+        if is_left_hand_side_expression_kind(self.arena[expr].kind)
+            && self.re_scan_greater_token().is_assignment_operator()
+        {
+            let operator_token = self.parse_token_node();
+            let right = self.parse_assignment_expression_or_higher();
+            return self.make_binary_expression(expr, operator_token, right);
+        }
+
+        // It wasn't an assignment or a lambda.  This is a conditional expression:
+        self.parse_conditional_expression_rest(expr)
+    }
+
+    fn is_yield_expression(&mut self) -> bool {
+        if self.token() == SyntaxKind::YieldKeyword {
+            // If we have a 'yield' keyword, and this is a context where yield expressions are
+            // allowed, then definitely parse out a yield expression.
+            if self.in_yield_context() {
+                return true;
+            }
+
+            // We're in a context where 'yield expr' is not allowed.  However, if we can
+            // definitely tell that the user was trying to parse a 'yield expr' and not
+            // just a normal expr that start with a 'yield' identifier, then parse out
+            // a 'yield expr'.  We can then report an error later that they are only
+            // allowed in generator expressions.
+            //
+            // for example, if we see 'yield(foo)', then we'll have to treat that as an
+            // invocation expression of something called 'yield'.  However, if we have
+            // 'yield foo' then that is not legal as a normal expression, so we can
+            // definitely recognize this as a yield expression.
+            //
+            // for now we just check if the next token is an identifier.  More heuristics
+            // can be added here later as necessary.  We just need to make sure that we
+            // don't accidentally consume something legal.
+            return self
+                .look_ahead(Parser::next_token_is_identifier_or_keyword_or_literal_on_same_line);
+        }
+        false
+    }
+
+    fn parse_yield_expression(&mut self) -> NodeId {
+        let pos = self.node_pos();
+
+        // YieldExpression[In] :
+        //      yield
+        //      yield [no LineTerminator here] [Lexical goal InputElementRegExp]AssignmentExpression[?In, Yield]
+        //      yield [no LineTerminator here] * [Lexical goal InputElementRegExp]AssignmentExpression[?In, Yield]
+        self.next_token();
+
+        let mut asterisk_token = None;
+        let mut expression = None;
+        if !self.has_preceding_line_break()
+            && (self.token() == SyntaxKind::AsteriskToken || self.is_start_of_expression())
+        {
+            asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+            expression = Some(self.parse_assignment_expression_or_higher());
+        }
+        // Otherwise the next token is not on the same line as yield, or we don't have an '*' or
+        // the start of an expression, and this is just a simple "yield" expression.
+        self.finish_node(
+            SyntaxKind::YieldExpression,
+            pos,
+            NodeData::YieldExpression {
+                asterisk_token,
+                expression,
+            },
+        )
+    }
+
+    fn parse_simple_arrow_function_expression(
+        &mut self,
+        identifier: NodeId,
+        async_modifier: Option<NodeList>,
+    ) -> NodeId {
+        debug_assert_eq!(
+            self.token(),
+            SyntaxKind::EqualsGreaterThanToken,
+            "parse_simple_arrow_function_expression should only have been called if we had a =>"
+        );
+
+        let identifier_pos = self.arena[identifier].pos;
+        let pos = async_modifier
+            .as_ref()
+            .map_or(identifier_pos, |modifiers| modifiers.pos);
+        let parameter = self.finish_node(
+            SyntaxKind::Parameter,
+            identifier_pos,
+            NodeData::Parameter {
+                dot_dot_dot_token: None,
+                name: identifier,
+                question_token: None,
+                ty: None,
+                initializer: None,
+            },
+        );
+        let parameters = NodeList::new(
+            self.arena[parameter].pos,
+            self.arena[parameter].end,
+            vec![parameter],
+        );
+
+        let is_async = async_modifier.is_some();
+        let equals_greater_than_token =
+            self.parse_expected_token(SyntaxKind::EqualsGreaterThanToken);
+        let body = self.parse_arrow_function_expression_body(is_async);
+        self.finish_declaration(
+            DeclarationStart {
+                pos,
+                decorators: None,
+                modifiers: async_modifier,
+            },
+            SyntaxKind::ArrowFunction,
+            NodeData::FunctionLike {
+                asterisk_token: None,
+                name: None,
+                question_token: None,
+                exclamation_token: None,
+                type_parameters: None,
+                parameters,
+                ty: None,
+                equals_greater_than_token: Some(equals_greater_than_token),
+                body: Some(body),
+            },
+        )
+    }
+
+    fn try_parse_parenthesized_arrow_function_expression(&mut self) -> Option<NodeId> {
+        let head = match self.is_parenthesized_arrow_function_expression() {
+            // It's definitely not a parenthesized arrow function expression.
+            Tristate::False => return None,
+            // If we definitely have an arrow function, then we can just parse one, not requiring a
+            // following => or { token. Otherwise, we *might* have an arrow function.  Try to parse
+            // it out, but don't allow any ambiguity, and return None if this could be an
+            // expression instead.
+            Tristate::True => self.parse_parenthesized_arrow_function_expression_head(true),
+            Tristate::Unknown => {
+                self.try_parse(Parser::parse_possible_parenthesized_arrow_function_expression_head)
+            }
+        };
+        // Didn't appear to actually be a parenthesized arrow function.  Just bail out.
+        let (start, signature) = head?;
+
+        let is_async = self.has_modifier_of_kind(&start.modifiers, SyntaxKind::AsyncKeyword);
+
+        // If we have an arrow, then try to parse the body. Even if not, try to parse if we have an
+        // opening brace, just in case we're in an error state.
+        let last_token = self.token();
+        let equals_greater_than_token =
+            self.parse_expected_token(SyntaxKind::EqualsGreaterThanToken);
+        let body = if last_token == SyntaxKind::EqualsGreaterThanToken
+            || last_token == SyntaxKind::OpenBraceToken
+        {
+            self.parse_arrow_function_expression_body(is_async)
+        } else {
+            self.parse_identifier(None)
+        };
+
+        Some(self.finish_declaration(
+            start,
+            SyntaxKind::ArrowFunction,
+            NodeData::FunctionLike {
+                asterisk_token: None,
+                name: None,
+                question_token: None,
+                exclamation_token: None,
+                type_parameters: signature.type_parameters,
+                parameters: signature.parameters,
+                ty: signature.ty,
+                equals_greater_than_token: Some(equals_greater_than_token),
+                body: Some(body),
+            },
+        ))
+    }
+
+    //  True        -> We definitely expect a parenthesized arrow function here.
+    //  False       -> There *cannot* be a parenthesized arrow function here.
+    //  Unknown     -> There *might* be a parenthesized arrow function here.
+    //                 Speculatively look ahead to be sure, and rollback if not.
+    fn is_parenthesized_arrow_function_expression(&mut self) -> Tristate {
+        if self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::LessThanToken
+            || self.token() == SyntaxKind::AsyncKeyword
+        {
+            return self.look_ahead(Parser::is_parenthesized_arrow_function_expression_worker);
+        }
+
+        if self.token() == SyntaxKind::EqualsGreaterThanToken {
+            // ERROR RECOVERY TWEAK:
+            // If we see a standalone => try to parse it as an arrow function expression as that's
+            // likely what the user intended to write.
+            return Tristate::True;
+        }
+        // Definitely not a parenthesized arrow function.
+        Tristate::False
+    }
+
+    fn is_parenthesized_arrow_function_expression_worker(&mut self) -> Tristate {
+        if self.token() == SyntaxKind::AsyncKeyword {
+            self.next_token();
+            if self.has_preceding_line_break() {
+                return Tristate::False;
+            }
+            if self.token() != SyntaxKind::OpenParenToken
+                && self.token() != SyntaxKind::LessThanToken
+            {
+                return Tristate::False;
+            }
+        }
+
+        let first = self.token();
+        let second = self.next_token();
+
+        if first == SyntaxKind::OpenParenToken {
+            if second == SyntaxKind::CloseParenToken {
+                // Simple cases: "() =>", "(): ", and "() {".
+                // This is an arrow function with no parameters.
+                // The last one is not actually an arrow function,
+                // but this is probably what the user intended.
+                let third = self.next_token();
+                return match third {
+                    SyntaxKind::EqualsGreaterThanToken
+                    | SyntaxKind::ColonToken
+                    | SyntaxKind::OpenBraceToken => Tristate::True,
+                    _ => Tristate::False,
+                };
+            }
+
+            // If encounter "([" or "({", this could be the start of a binding pattern.
+            // Examples:
+            //      ([ x ]) => { }
+            //      ({ x }) => { }
+            //      ([ x ])
+            //      ({ x })
+            if second == SyntaxKind::OpenBracketToken || second == SyntaxKind::OpenBraceToken {
+                return Tristate::Unknown;
+            }
+
+            // Simple case: "(..."
+            // This is an arrow function with a rest parameter.
+            if second == SyntaxKind::DotDotDotToken {
+                return Tristate::True;
+            }
+
+            // Check for "(xxx yyy", where xxx is a modifier and yyy is an identifier. This
+            // isn't actually allowed, but we want to treat it as a lambda so we can provide
+            // a good error message.
+            if is_modifier_kind(second)
+                && second != SyntaxKind::AsyncKeyword
+                && self.look_ahead(Parser::next_token_is_identifier)
+            {
+                return Tristate::True;
+            }
+
+            // If we had "(" followed by something that's not an identifier,
+            // then this definitely doesn't look like a lambda.  "this" is not
+            // valid, but we want to parse it and then give a semantic error.
+            if !self.is_identifier() && second != SyntaxKind::ThisKeyword {
+                return Tristate::False;
+            }
+
+            match self.next_token() {
+                SyntaxKind::ColonToken => {
+                    // If we have something like "(a:", then we must have a
+                    // type-annotated parameter in an arrow function expression.
+                    Tristate::True
+                }
+                SyntaxKind::QuestionToken => {
+                    self.next_token();
+                    // If we have "(a?:" or "(a?," or "(a?=" or "(a?)" then it is definitely a lambda.
+                    match self.token() {
+                        SyntaxKind::ColonToken
+                        | SyntaxKind::CommaToken
+                        | SyntaxKind::EqualsToken
+                        | SyntaxKind::CloseParenToken => Tristate::True,
+                        // Otherwise it is definitely not a lambda.
+                        _ => Tristate::False,
+                    }
+                }
+                SyntaxKind::CommaToken | SyntaxKind::EqualsToken | SyntaxKind::CloseParenToken => {
+                    // If we have "(a," or "(a=" or "(a)" this *could* be an arrow function
+                    Tristate::Unknown
+                }
+                // It is definitely not an arrow function
+                _ => Tristate::False,
+            }
+        } else {
+            debug_assert_eq!(first, SyntaxKind::LessThanToken);
+
+            // If we have "<" not followed by an identifier,
+            // then this definitely is not an arrow function.
+            if !self.is_identifier() {
+                return Tristate::False;
+            }
+
+            // This *could* be a parenthesized arrow function.
+            Tristate::Unknown
+        }
+    }
+
+    fn parse_possible_parenthesized_arrow_function_expression_head(
+        &mut self,
+    ) -> Option<(DeclarationStart, Signature)> {
+        let token_pos = self.scanner.get_token_pos();
+        if self.not_parenthesized_arrow.contains(&token_pos) {
+            return None;
+        }
+        let result = self.parse_parenthesized_arrow_function_expression_head(false);
+        if result.is_none() {
+            self.not_parenthesized_arrow.insert(token_pos);
+        }
+        result
+    }
+
+    fn try_parse_async_simple_arrow_function_expression(&mut self) -> Option<NodeId> {
+        // We do a check here so that we won't be doing unnecessarily call to "look_ahead"
+        if self.token() == SyntaxKind::AsyncKeyword
+            && self.look_ahead(Parser::is_un_parenthesized_async_arrow_function_worker)
+        {
+            let async_modifier = self.parse_modifiers_for_arrow_function();
+            let expr = self.parse_binary_expression_or_higher(0);
+            return Some(self.parse_simple_arrow_function_expression(expr, async_modifier));
+        }
+        None
+    }
+
+    fn is_un_parenthesized_async_arrow_function_worker(&mut self) -> bool {
+        // AsyncArrowFunctionExpression:
+        //      1) async[no LineTerminator here]AsyncArrowBindingIdentifier[?Yield][no LineTerminator here]=>AsyncConciseBody[?In]
+        //      2) CoverCallExpressionAndAsyncArrowHead[?Yield, ?Await][no LineTerminator here]=>AsyncConciseBody[?In]
+        if self.token() == SyntaxKind::AsyncKeyword {
+            self.next_token();
+            // If the "async" is followed by "=>" token then it is not a beginning of an async
+            // arrow-function but instead a simple arrow-function which will be parsed inside
+            // "parse_assignment_expression_or_higher"
+            if self.has_preceding_line_break() || self.token() == SyntaxKind::EqualsGreaterThanToken
+            {
+                return false;
+            }
+            // Check for un-parenthesized AsyncArrowFunction
+            let expr = self.parse_binary_expression_or_higher(0);
+            if !self.has_preceding_line_break()
+                && self.arena[expr].kind == SyntaxKind::Identifier
+                && self.token() == SyntaxKind::EqualsGreaterThanToken
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn parse_parenthesized_arrow_function_expression_head(
+        &mut self,
+        allow_ambiguity: bool,
+    ) -> Option<(DeclarationStart, Signature)> {
+        let pos = self.node_pos();
+        let modifiers = self.parse_modifiers_for_arrow_function();
+        let is_async = if self.has_modifier_of_kind(&modifiers, SyntaxKind::AsyncKeyword) {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+
+        // Arrow functions are never generators.
+        //
+        // If we're speculatively parsing a signature for a parenthesized arrow function, then
+        // we have to have a complete parameter list.  Otherwise we might see something like
+        // a => (b => c)
+        // And think that "(b =>" was actually a parenthesized arrow function with a missing
+        // close paren.
+        let signature = self.fill_signature(SyntaxKind::ColonToken, is_async);
+        if !signature.parsed_successfully && !allow_ambiguity {
+            return None;
+        }
+
+        // Parsing a signature isn't enough.
+        // Parenthesized arrow signatures often look like other valid expressions.
+        // For instance:
+        //  - "(x = 10)" is an assignment expression parsed as a signature with a default
+        //    parameter value.
+        //  - "(x,y)" is a comma expression parsed as a signature with two parameters.
+        //  - "a ? (b): c" will have "(b):" parsed as a signature with a return type annotation.
+        //
+        // So we need just a bit of lookahead to ensure that it can only be a signature.
+        if !allow_ambiguity
+            && self.token() != SyntaxKind::EqualsGreaterThanToken
+            && self.token() != SyntaxKind::OpenBraceToken
+        {
+            // Returning None here will cause our caller to rewind to where we started from.
+            return None;
+        }
+
+        Some((
+            DeclarationStart {
+                pos,
+                decorators: None,
+                modifiers,
+            },
+            signature,
+        ))
+    }
+
+    fn parse_arrow_function_expression_body(&mut self, is_async: bool) -> NodeId {
+        let async_flags = if is_async {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        if self.token() == SyntaxKind::OpenBraceToken {
+            return self.parse_function_block(async_flags, None);
+        }
+
+        if self.token() != SyntaxKind::SemicolonToken
+            && self.token() != SyntaxKind::FunctionKeyword
+            && self.token() != SyntaxKind::ClassKeyword
+            && self.is_start_of_statement()
+            && !self.is_start_of_expression_statement()
+        {
+            // Check if we got a plain statement (i.e. no expression-statements, no
+            // function/class expressions/declarations)
+            //
+            // Here we try to recover from a potential error situation in the case where the
+            // user meant to supply a block. For example, if the user wrote:
+            //
+            //  a =>
+            //      let v = 0;
+            //  }
+            //
+            // they may be missing an open brace.  Check to see if that's the case so we can
+            // try to recover better.  If we don't do this, then the next close curly we see may
+            // end up preemptively closing the containing construct.
+            //
+            // Note: even when 'IGNORE_MISSING_OPEN_BRACE' is passed, parse_body will still error.
+            return self.parse_function_block(
+                SignatureFlags::IGNORE_MISSING_OPEN_BRACE | async_flags,
+                None,
+            );
+        }
+
+        if is_async {
+            self.do_in_await_context(Parser::parse_assignment_expression_or_higher)
+        } else {
+            self.do_outside_of_await_context(Parser::parse_assignment_expression_or_higher)
+        }
+    }
+
+    fn parse_conditional_expression_rest(&mut self, left_operand: NodeId) -> NodeId {
+        // Note: we are passed in an expression which was produced from
+        // parse_binary_expression_or_higher.
+        let question_token = match self.parse_optional_token(SyntaxKind::QuestionToken) {
+            Some(question_token) => question_token,
+            None => return left_operand,
+        };
+
+        // Note: we explicitly 'allowIn' in the whenTrue part of the condition expression, and
+        // we do not that for the 'whenFalse' part.
+        let pos = self.arena[left_operand].pos;
+        let when_true = self.do_outside_of_context(
+            NodeFlags::DISALLOW_IN_CONTEXT,
+            Parser::parse_assignment_expression_or_higher,
+        );
+        let colon_token = self.parse_expected_token(SyntaxKind::ColonToken);
+        let when_false = if self.node_is_missing(colon_token) {
+            self.create_missing_node(
+                SyntaxKind::Identifier,
+                false,
+                &diagnostics::_0_EXPECTED,
+                &[":"],
+            )
+        } else {
+            self.parse_assignment_expression_or_higher()
+        };
+        self.finish_node(
+            SyntaxKind::ConditionalExpression,
+            pos,
+            NodeData::ConditionalExpression {
+                condition: left_operand,
+                question_token,
+                when_true,
+                colon_token,
+                when_false,
+            },
+        )
+    }
+
+    fn parse_binary_expression_or_higher(&mut self, precedence: i32) -> NodeId {
+        let left_operand = self.parse_unary_expression_or_higher();
+        self.parse_binary_expression_rest(precedence, left_operand)
+    }
+
+    fn parse_binary_expression_rest(
+        &mut self,
+        precedence: i32,
+        mut left_operand: NodeId,
+    ) -> NodeId {
+        loop {
+            // We either have a binary operator here, or we're finished.  We call
+            // re_scan_greater_token so that we merge token sequences like > and = into >=
+            self.re_scan_greater_token();
+            let new_precedence = get_binary_operator_precedence(self.token());
+
+            // Check the precedence to see if we should "take" this operator
+            // - For left associative operator (all operator but **), consume the operator,
+            //   recursively call the function below, and parse binaryExpression as a rightOperand
+            //   of the caller if the new precedence of the operator is greater then or equal to
+            //   the current precedence.
+            //   For example:
+            //      a - b - c;
+            //            ^token; leftOperand = b. Return b to the caller as a rightOperand
+            //      a * b - c
+            //            ^token; leftOperand = b. Return b to the caller as a rightOperand
+            //      a - b * c;
+            //            ^token; leftOperand = b. Return b * c to the caller as a rightOperand
+            // - For right associative operator (**), consume the operator, recursively call the
+            //   function and parse binaryExpression as a rightOperand of the caller if the new
+            //   precedence of the operator is strictly grater than the current precedence
+            //   For example:
+            //      a ** b ** c;
+            //             ^^token; leftOperand = b. Return b ** c to the caller as a rightOperand
+            //      a - b ** c;
+            //            ^^token; leftOperand = b. Return b ** c to the caller as a rightOperand
+            //      a ** b - c
+            //             ^token; leftOperand = b. Return b to the caller as a rightOperand
+            let consume_current_operator = if self.token() == SyntaxKind::AsteriskAsteriskToken {
+                new_precedence >= precedence
+            } else {
+                new_precedence > precedence
+            };
+            if !consume_current_operator {
+                break;
+            }
+
+            if self.token() == SyntaxKind::InKeyword && self.in_disallow_in_context() {
+                break;
+            }
+
+            if self.token() == SyntaxKind::AsKeyword {
+                // Make sure we *do* perform ASI for constructs like this:
+                //    var x = foo
+                //    as (Bar)
+                // This should be parsed as an initialized variable, followed
+                // by a function call to 'as' with the argument 'Bar'
+                if self.has_preceding_line_break() {
+                    break;
+                }
+                self.next_token();
+                let pos = self.arena[left_operand].pos;
+                let ty = self.parse_type();
+                left_operand = self.finish_node(
+                    SyntaxKind::AsExpression,
+                    pos,
+                    NodeData::AsExpression {
+                        expression: left_operand,
+                        ty,
+                    },
+                );
+            } else {
+                let operator_token = self.parse_token_node();
+                let right = self.parse_binary_expression_or_higher(new_precedence);
+                left_operand = self.make_binary_expression(left_operand, operator_token, right);
+            }
+        }
+        left_operand
+    }
+
+    fn is_binary_operator(&self) -> bool {
+        if self.in_disallow_in_context() && self.token() == SyntaxKind::InKeyword {
+            return false;
+        }
+        get_binary_operator_precedence(self.token()) > 0
+    }
+
+    fn parse_prefix_unary_expression(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let operator = self.token();
+        self.next_token();
+        let operand = self.parse_simple_unary_expression();
+        self.finish_node(
+            SyntaxKind::PrefixUnaryExpression,
+            pos,
+            NodeData::PrefixUnaryExpression { operator, operand },
+        )
+    }
+
+    // Parses `delete`, `typeof`, `void` and `await` expressions, whose operand is the next
+    // unary expression.
+    fn parse_keyword_unary_expression(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.node_pos();
+        self.next_token();
+        let expression = self.parse_simple_unary_expression();
+        self.finish_node(kind, pos, NodeData::WrappedExpression { expression })
+    }
+
+    fn is_await_expression(&mut self) -> bool {
+        if self.token() == SyntaxKind::AwaitKeyword {
+            if self.in_await_context() {
+                return true;
+            }
+            // here we are using similar heuristics as 'is_yield_expression'
+            return self
+                .look_ahead(Parser::next_token_is_identifier_or_keyword_or_literal_on_same_line);
+        }
+        false
+    }
+
+    // Parse ES7 exponential expression and await expression
+    //
+    // ES7 ExponentiationExpression:
+    //      1) UnaryExpression[?Yield]
+    //      2) UpdateExpression[?Yield] ** ExponentiationExpression[?Yield]
+    fn parse_unary_expression_or_higher(&mut self) -> NodeId {
+        // ES7 UpdateExpression:
+        //      1) LeftHandSideExpression[?Yield]
+        //      2) LeftHandSideExpression[?Yield][no LineTerminator here]++
+        //      3) LeftHandSideExpression[?Yield][no LineTerminator here]--
+        //      4) ++UnaryExpression[?Yield]
+        //      5) --UnaryExpression[?Yield]
+        if self.is_update_expression() {
+            let update_expression = self.parse_update_expression();
+            return if self.token() == SyntaxKind::AsteriskAsteriskToken {
+                let precedence = get_binary_operator_precedence(self.token());
+                self.parse_binary_expression_rest(precedence, update_expression)
+            } else {
+                update_expression
+            };
+        }
+
+        // ES7 UnaryExpression:
+        //      1) UpdateExpression[?yield]
+        //      2) delete UpdateExpression[?yield]
+        //      3) void UpdateExpression[?yield]
+        //      4) typeof UpdateExpression[?yield]
+        //      5) + UpdateExpression[?yield]
+        //      6) - UpdateExpression[?yield]
+        //      7) ~ UpdateExpression[?yield]
+        //      8) ! UpdateExpression[?yield]
+        let unary_operator = self.token();
+        let simple_unary_expression = self.parse_simple_unary_expression();
+        if self.token() == SyntaxKind::AsteriskAsteriskToken {
+            let node = &self.arena[simple_unary_expression];
+            let pos = skip_trivia_in(self.scanner.source_text(), node.pos, false, false);
+            let end = node.end;
+            if node.kind == SyntaxKind::TypeAssertionExpression {
+                self.parse_error_at(
+                    pos,
+                    end,
+                    &diagnostics::A_TYPE_ASSERTION_EXPRESSION_IS_NOT_ALLOWED_IN_THE_LEFT_HAND_SIDE_OF_AN_EXPONENTIATION_EXPRESSION_CONSIDER_ENCLOSING_THE_EXPRESSION_IN_PARENTHESES,
+                    &[],
+                );
+            } else {
+                let operator = token_to_string(unary_operator as u32).unwrap_or_default();
+                self.parse_error_at(
+                    pos,
+                    end,
+                    &diagnostics::AN_UNARY_EXPRESSION_WITH_THE_0_OPERATOR_IS_NOT_ALLOWED_IN_THE_LEFT_HAND_SIDE_OF_AN_EXPONENTIATION_EXPRESSION_CONSIDER_ENCLOSING_THE_EXPRESSION_IN_PARENTHESES,
+                    &[&operator],
+                );
+            }
+        }
+        simple_unary_expression
+    }
+
+    // Parse ES7 simple-unary expression or higher:
+    //
+    // ES7 UnaryExpression:
+    //      1) UpdateExpression[?yield]
+    //      2) delete UnaryExpression[?yield]
+    //      3) void UnaryExpression[?yield]
+    //      4) typeof UnaryExpression[?yield]
+    //      5) + UnaryExpression[?yield]
+    //      6) - UnaryExpression[?yield]
+    //      7) ~ UnaryExpression[?yield]
+    //      8) ! UnaryExpression[?yield]
+    //      9) [+Await] await UnaryExpression[?yield]
+    fn parse_simple_unary_expression(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::TildeToken
+            | SyntaxKind::ExclamationToken => self.parse_prefix_unary_expression(),
+            SyntaxKind::DeleteKeyword => {
+                self.parse_keyword_unary_expression(SyntaxKind::DeleteExpression)
+            }
+            SyntaxKind::TypeOfKeyword => {
+                self.parse_keyword_unary_expression(SyntaxKind::TypeOfExpression)
+            }
+            SyntaxKind::VoidKeyword => {
+                self.parse_keyword_unary_expression(SyntaxKind::VoidExpression)
+            }
+            // This is modified UnaryExpression grammar in TypeScript
+            //  UnaryExpression (modified):
+            //      < type > UnaryExpression
+            SyntaxKind::LessThanToken => self.parse_type_assertion(),
+            SyntaxKind::AwaitKeyword if self.is_await_expression() => {
+                self.parse_keyword_unary_expression(SyntaxKind::AwaitExpression)
+            }
+            _ => self.parse_update_expression(),
+        }
+    }
+
+    // Check if the current token can possibly be an ES7 increment expression.
+    //
+    // ES7 UpdateExpression:
+    //      LeftHandSideExpression[?Yield]
+    //      LeftHandSideExpression[?Yield][no LineTerminator here]++
+    //      LeftHandSideExpression[?Yield][no LineTerminator here]--
+    //      ++LeftHandSideExpression[?Yield]
+    //      --LeftHandSideExpression[?Yield]
+    fn is_update_expression(&self) -> bool {
+        // This function is called inside parse_unary_expression_or_higher to decide
+        // whether to call parse_simple_unary_expression or call parse_update_expression directly
+        match self.token() {
+            SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::TildeToken
+            | SyntaxKind::ExclamationToken
+            | SyntaxKind::DeleteKeyword
+            | SyntaxKind::TypeOfKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::AwaitKeyword => false,
+            // Without JSX, '<' starts a type assertion, which is a UnaryExpression
+            SyntaxKind::LessThanToken => false,
+            _ => true,
+        }
+    }
+
+    // Parse ES7 UpdateExpression. UpdateExpression is used instead of ES6's PostFixExpression.
+    //
+    // ES7 UpdateExpression[yield]:
+    //      1) LeftHandSideExpression[?yield]
+    //      2) LeftHandSideExpression[?yield] [[no LineTerminator here]]++
+    //      3) LeftHandSideExpression[?yield] [[no LineTerminator here]]--
+    //      4) ++LeftHandSideExpression[?yield]
+    //      5) --LeftHandSideExpression[?yield]
+    // In TypeScript (2), (3) are parsed as PostfixUnaryExpression. (4), (5) are parsed as
+    // PrefixUnaryExpression
+    fn parse_update_expression(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::PlusPlusToken || self.token() == SyntaxKind::MinusMinusToken
+        {
+            let pos = self.node_pos();
+            let operator = self.token();
+            self.next_token();
+            let operand = self.parse_left_hand_side_expression_or_higher();
+            return self.finish_node(
+                SyntaxKind::PrefixUnaryExpression,
+                pos,
+                NodeData::PrefixUnaryExpression { operator, operand },
+            );
+        }
+
+        let expression = self.parse_left_hand_side_expression_or_higher();
+        debug_assert!(is_left_hand_side_expression_kind(
+            self.arena[expression].kind
+        ));
+        if (self.token() == SyntaxKind::PlusPlusToken
+            || self.token() == SyntaxKind::MinusMinusToken)
+            && !self.has_preceding_line_break()
+        {
+            let pos = self.arena[expression].pos;
+            let operator = self.token();
+            self.next_token();
+            return self.finish_node(
+                SyntaxKind::PostfixUnaryExpression,
+                pos,
+                NodeData::PostfixUnaryExpression {
+                    operand: expression,
+                    operator,
+                },
+            );
+        }
+        expression
+    }
+
+    fn parse_left_hand_side_expression_or_higher(&mut self) -> NodeId {
+        // Original Ecma:
+        // LeftHandSideExpression: See 11.2
+        //      NewExpression
+        //      CallExpression
+        //
+        // Our simplification:
+        //
+        // LeftHandSideExpression: See 11.2
+        //      MemberExpression
+        //      CallExpression
+        //
+        // See comment in parse_member_expression_or_higher on how we replaced NewExpression with
+        // MemberExpression to make our lives easier.
+        //
+        // to best understand the below code, it's important to see how CallExpression expands
+        // out into its own productions:
+        //
+        // CallExpression:
+        //      MemberExpression Arguments
+        //      CallExpression Arguments
+        //      CallExpression[Expression]
+        //      CallExpression.IdentifierName
+        //      import (AssignmentExpression)
+        //      super Arguments
+        //      super.IdentifierName
+        //
+        // Because of the recursion in these calls, we need to bottom out first. There are three
+        // bottom out states we can run into: 1) We see 'super' which must start either of
+        // the last two CallExpression productions. 2) We see 'import' which must start import
+        // call. 3) we have a MemberExpression which either completes the LeftHandSideExpression,
+        // or starts the beginning of the first four CallExpression productions.
+        let expression = if self.token() == SyntaxKind::ImportKeyword {
+            if self.look_ahead(Parser::next_token_is_open_paren_or_less_than) {
+                // We don't want to eagerly consume all import keyword as import call expression
+                // so we look ahead to find "("
+                // For example:
+                //      var foo3 = require("subfolder
+                //      import * as foo1 from "module-foo"; // Error: Unterminated string literal
+                //      import * as foo2 from "module-foo"; // Error: Unterminated string literal
+                // In above example, the error message report at "import" instead of missing
+                // string literal.
+                self.parse_token_node()
+            } else if self.look_ahead(Parser::next_token_is_dot) {
+                // This is an 'import.*' metaproperty (i.e. 'import.meta')
+                let full_start = self.scanner.get_start_pos();
+                self.next_token(); // advance past the 'import'
+                self.next_token(); // advance past the dot
+                let name = self.parse_identifier_name(None);
+                self.finish_node(
+                    SyntaxKind::MetaProperty,
+                    full_start,
+                    NodeData::MetaProperty {
+                        keyword_token: SyntaxKind::ImportKeyword,
+                        name,
+                    },
+                )
+            } else {
+                self.parse_member_expression_or_higher()
+            }
+        } else if self.token() == SyntaxKind::SuperKeyword {
+            self.parse_super_expression()
+        } else {
+            self.parse_member_expression_or_higher()
+        };
+
+        // Now, we *may* be complete.  However, we might have consumed the start of a
+        // CallExpression.  As such, we need to consume the rest of it here to be complete.
+        self.parse_call_expression_rest(expression)
+    }
+
+    fn parse_member_expression_or_higher(&mut self) -> NodeId {
+        // Note: to make our lives simpler, we decompose the NewExpression productions and
+        // place ObjectCreationExpression and FunctionExpression into PrimaryExpression.
+        // like so:
+        //
+        //   PrimaryExpression : See 11.1
+        //      this
+        //      Identifier
+        //      Literal
+        //      ArrayLiteral
+        //      ObjectLiteral
+        //      (Expression)
+        //      FunctionExpression
+        //      new MemberExpression Arguments?
+        //
+        //   MemberExpression : See 11.2
+        //      PrimaryExpression
+        //      MemberExpression[Expression]
+        //      MemberExpression.IdentifierName
+        //
+        //   CallExpression : See 11.2
+        //      MemberExpression
+        //      CallExpression Arguments
+        //      CallExpression[Expression]
+        //      CallExpression.IdentifierName
+        //
+        // Technically this is ambiguous.  i.e. CallExpression defines:
+        //
+        //   CallExpression:
+        //      CallExpression Arguments
+        //
+        // If you see: "new Foo()"
+        //
+        // Then that could be treated as a single ObjectCreationExpression, or it could be
+        // treated as the invocation of "new Foo".  We disambiguate that in code (to match
+        // the original grammar) by making sure that if we see an ObjectCreationExpression
+        // we always consume arguments if they are there. So we treat "new Foo()" as an
+        // object creation only, and not at all as an invocation.  Another way to think
+        // about this is that for every "new" that we see, we will consume an argument list if
+        // it is there as part of the *associated* object creation node.  Any additional
+        // argument lists we see, will become invocation expressions.
+        //
+        // Because there are no other places in the grammar now that refer to FunctionExpression
+        // or ObjectCreationExpression, it is safe to push down into the PrimaryExpression
+        // production.
+        //
+        // Because CallExpression and MemberExpression are left recursive, we need to bottom out
+        // of the recursion immediately.  So we parse out a primary expression to start with.
+        let expression = self.parse_primary_expression();
+        self.parse_member_expression_rest(expression)
+    }
+
+    fn parse_super_expression(&mut self) -> NodeId {
+        let expression = self.parse_token_node();
+        if self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::DotToken
+            || self.token() == SyntaxKind::OpenBracketToken
+        {
+            return expression;
+        }
+
+        // If we have seen "super" it must be followed by '(' or '.'.
+        // If it wasn't then just try to parse out a '.' and report an error.
+        let pos = self.arena[expression].pos;
+        self.parse_expected(
+            SyntaxKind::DotToken,
+            Some(&diagnostics::SUPER_MUST_BE_FOLLOWED_BY_AN_ARGUMENT_LIST_OR_MEMBER_ACCESS),
+        );
+        let name = self.parse_right_side_of_dot(true);
+        self.finish_node(
+            SyntaxKind::PropertyAccessExpression,
+            pos,
+            NodeData::PropertyAccessExpression { expression, name },
+        )
+    }
+
+    fn parse_member_expression_rest(&mut self, mut expression: NodeId) -> NodeId {
+        loop {
+            let pos = self.arena[expression].pos;
+            if self.parse_optional(SyntaxKind::DotToken) {
+                let name = self.parse_right_side_of_dot(true);
+                expression = self.finish_node(
+                    SyntaxKind::PropertyAccessExpression,
+                    pos,
+                    NodeData::PropertyAccessExpression { expression, name },
+                );
+                continue;
+            }
+
+            if self.token() == SyntaxKind::ExclamationToken && !self.has_preceding_line_break() {
+                self.next_token();
+                expression = self.finish_node(
+                    SyntaxKind::NonNullExpression,
+                    pos,
+                    NodeData::WrappedExpression { expression },
+                );
+                continue;
+            }
+
+            // when in the [Decorator] context, we do not parse ElementAccess as it could be part
+            // of a ComputedPropertyName
+            if !self.in_decorator_context() && self.parse_optional(SyntaxKind::OpenBracketToken) {
+                // It's not uncommon for a user to write: "new Type[]".
+                // Check for that common pattern and report a better error message.
+                let argument_expression = if self.token() == SyntaxKind::CloseBracketToken {
+                    self.create_missing_node(
+                        SyntaxKind::Identifier,
+                        true,
+                        &diagnostics::AN_ELEMENT_ACCESS_EXPRESSION_SHOULD_TAKE_AN_ARGUMENT,
+                        &[],
+                    )
+                } else {
+                    self.allow_in_and(Parser::parse_expression)
+                };
+                self.parse_expected(SyntaxKind::CloseBracketToken, None);
+                expression = self.finish_node(
+                    SyntaxKind::ElementAccessExpression,
+                    pos,
+                    NodeData::ElementAccessExpression {
+                        expression,
+                        argument_expression,
+                    },
+                );
+                continue;
+            }
+
+            if self.is_template_start_of_tagged_template() {
+                expression = self.parse_tagged_template_rest(expression, None);
+                continue;
+            }
+
+            return expression;
+        }
+    }
+
+    fn is_template_start_of_tagged_template(&self) -> bool {
+        self.token() == SyntaxKind::NoSubstitutionTemplateLiteral
+            || self.token() == SyntaxKind::TemplateHead
+    }
+
+    fn parse_tagged_template_rest(
+        &mut self,
+        tag: NodeId,
+        type_arguments: Option<NodeList>,
+    ) -> NodeId {
+        let pos = self.arena[tag].pos;
+        let template = if self.token() == SyntaxKind::NoSubstitutionTemplateLiteral {
+            self.re_scan_template_head_or_no_substitution_template();
+            self.parse_literal_node()
+        } else {
+            self.parse_template_expression(true)
+        };
+        self.finish_node(
+            SyntaxKind::TaggedTemplateExpression,
+            pos,
+            NodeData::TaggedTemplateExpression {
+                tag,
+                type_arguments,
+                template,
+            },
+        )
+    }
+
+    fn parse_call_expression_rest(&mut self, mut expression: NodeId) -> NodeId {
+        loop {
+            expression = self.parse_member_expression_rest(expression);
+            let pos = self.arena[expression].pos;
+            if self.token() == SyntaxKind::LessThanToken {
+                // See if this is the start of a generic invocation.  If so, consume it and
+                // keep checking for postfix expressions.  Otherwise, it's just a '<' that's
+                // part of an arithmetic expression.  Break out so we consume it higher in the
+                // stack.
+                let type_arguments = self.try_parse(Parser::parse_type_arguments_in_expression);
+                if type_arguments.is_none() {
+                    return expression;
+                }
+
+                if self.is_template_start_of_tagged_template() {
+                    expression = self.parse_tagged_template_rest(expression, type_arguments);
+                    continue;
+                }
+
+                let arguments = self.parse_argument_list();
+                expression = self.finish_node(
+                    SyntaxKind::CallExpression,
+                    pos,
+                    NodeData::CallOrNewExpression {
+                        expression,
+                        type_arguments,
+                        arguments: Some(arguments),
+                    },
+                );
+                continue;
+            } else if self.token() == SyntaxKind::OpenParenToken {
+                let arguments = self.parse_argument_list();
+                expression = self.finish_node(
+                    SyntaxKind::CallExpression,
+                    pos,
+                    NodeData::CallOrNewExpression {
+                        expression,
+                        type_arguments: None,
+                        arguments: Some(arguments),
+                    },
+                );
+                continue;
+            }
+
+            return expression;
+        }
+    }
+
+    fn parse_argument_list(&mut self) -> NodeList {
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let result = self.parse_delimited_list(
+            ParsingContext::ArgumentExpressions,
+            Parser::parse_argument_expression,
+            false,
+        );
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        result
+    }
+
+    fn parse_type_arguments_in_expression(&mut self) -> Option<NodeList> {
+        if self.re_scan_less_than_token() != SyntaxKind::LessThanToken {
+            return None;
+        }
+        self.next_token();
+
+        let type_arguments =
+            self.parse_delimited_list(ParsingContext::TypeArguments, Parser::parse_type, false);
+        if !self.parse_expected(SyntaxKind::GreaterThanToken, None) {
+            // If it doesn't have the closing `>` then it's definitely not an type argument list.
+            return None;
+        }
+
+        // If we have a '<', then only parse this as a argument list if the type arguments
+        // are complete and we have an open paren.  if we don't, rewind and return nothing.
+        if self.can_follow_type_arguments_in_expression() {
+            Some(type_arguments)
+        } else {
+            None
+        }
+    }
+
+    fn can_follow_type_arguments_in_expression(&self) -> bool {
+        match self.token() {
+            SyntaxKind::OpenParenToken                    // foo<x>(
+            | SyntaxKind::NoSubstitutionTemplateLiteral   // foo<T> `...`
+            | SyntaxKind::TemplateHead                    // foo<T> `...${100}...`
+            // these are the only tokens can legally follow a type argument
+            // list. So we definitely want to treat them as type arg lists.
+            | SyntaxKind::DotToken                        // foo<x>.
+            | SyntaxKind::CloseParenToken                 // foo<x>)
+            | SyntaxKind::CloseBracketToken               // foo<x>]
+            | SyntaxKind::ColonToken                      // foo<x>:
+            | SyntaxKind::SemicolonToken                  // foo<x>;
+            | SyntaxKind::QuestionToken                   // foo<x>?
+            | SyntaxKind::EqualsEqualsToken               // foo<x> ==
+            | SyntaxKind::EqualsEqualsEqualsToken         // foo<x> ===
+            | SyntaxKind::ExclamationEqualsToken          // foo<x> !=
+            | SyntaxKind::ExclamationEqualsEqualsToken    // foo<x> !==
+            | SyntaxKind::AmpersandAmpersandToken         // foo<x> &&
+            | SyntaxKind::BarBarToken                     // foo<x> ||
+            | SyntaxKind::CaretToken                      // foo<x> ^
+            | SyntaxKind::AmpersandToken                  // foo<x> &
+            | SyntaxKind::BarToken                        // foo<x> |
+            | SyntaxKind::CloseBraceToken                 // foo<x> }
+            | SyntaxKind::EndOfFileToken => {             // foo<x>
+                // these cases can't legally follow a type arg list.  However, they're not legal
+                // expressions either.  The user is probably in the middle of a generic type. So
+                // treat it as such.
+                true
+            }
+            // foo<x>, and foo<x> { are not treated as type arguments.  Otherwise we'll parse
+            // this as an invocation expression.  Instead, we want to parse out the expression
+            // in isolation from the type arguments.  Anything else is treated as an expression
+            // too.
+            _ => false,
+        }
+    }
+
+    fn parse_primary_expression(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NoSubstitutionTemplateLiteral => return self.parse_literal_node(),
+            SyntaxKind::ThisKeyword
+            | SyntaxKind::SuperKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword => return self.parse_token_node(),
+            SyntaxKind::OpenParenToken => return self.parse_parenthesized_expression(),
+            SyntaxKind::OpenBracketToken => return self.parse_array_literal_expression(),
+            SyntaxKind::OpenBraceToken => return self.parse_object_literal_expression(),
+            // Async arrow functions are parsed earlier in parse_assignment_expression_or_higher.
+            // If we encounter `async [no LineTerminator here] function` then this is an async
+            // function; otherwise, its an identifier.
+            SyntaxKind::AsyncKeyword
+                if self.look_ahead(Parser::next_token_is_function_keyword_on_same_line) =>
+            {
+                return self.parse_function_expression()
+            }
+            SyntaxKind::ClassKeyword => return self.parse_class_expression(),
+            SyntaxKind::FunctionKeyword => return self.parse_function_expression(),
+            SyntaxKind::NewKeyword => return self.parse_new_expression_or_new_dot_target(),
+            SyntaxKind::SlashToken | SyntaxKind::SlashEqualsToken
+                if self.re_scan_slash_token() == SyntaxKind::RegularExpressionLiteral =>
+            {
+                return self.parse_literal_node()
+            }
+            SyntaxKind::TemplateHead => return self.parse_template_expression(false),
+            _ => {}
+        }
+        self.parse_identifier(Some(&diagnostics::EXPRESSION_EXPECTED))
+    }
+
+    fn parse_parenthesized_expression(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let expression = self.allow_in_and(Parser::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        self.finish_node(
+            SyntaxKind::ParenthesizedExpression,
+            pos,
+            NodeData::WrappedExpression { expression },
+        )
+    }
+
+    fn parse_spread_element(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::DotDotDotToken, None);
+        let expression = self.parse_assignment_expression_or_higher();
+        self.finish_node(
+            SyntaxKind::SpreadElement,
+            pos,
+            NodeData::WrappedExpression { expression },
+        )
+    }
+
+    fn parse_omitted_expression(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.finish_node(SyntaxKind::OmittedExpression, pos, NodeData::Empty {})
+    }
+
+    fn parse_argument_or_array_literal_element(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::DotDotDotToken => self.parse_spread_element(),
+            SyntaxKind::CommaToken => self.parse_omitted_expression(),
+            _ => self.parse_assignment_expression_or_higher(),
+        }
+    }
+
+    fn parse_argument_expression(&mut self) -> NodeId {
+        self.do_outside_of_context(
+            NodeFlags::DISALLOW_IN_CONTEXT | NodeFlags::DECORATOR_CONTEXT,
+            Parser::parse_argument_or_array_literal_element,
+        )
+    }
+
+    fn parse_array_literal_expression(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenBracketToken, None);
+        let multi_line = self.has_preceding_line_break();
+        let elements = self.parse_delimited_list(
+            ParsingContext::ArrayLiteralMembers,
+            Parser::parse_argument_or_array_literal_element,
+            false,
+        );
+        self.parse_expected(SyntaxKind::CloseBracketToken, None);
+        self.finish_node(
+            SyntaxKind::ArrayLiteralExpression,
+            pos,
+            NodeData::ArrayLiteralExpression {
+                elements,
+                multi_line,
+            },
+        )
+    }
+
+    fn parse_object_literal_element(&mut self) -> NodeId {
+        let pos = self.node_pos();
+
+        if self.parse_optional(SyntaxKind::DotDotDotToken) {
+            let expression = self.parse_assignment_expression_or_higher();
+            return self.finish_node(
+                SyntaxKind::SpreadAssignment,
+                pos,
+                NodeData::SpreadAssignment { expression },
+            );
+        }
+
+        let decorators = self.parse_decorators();
+        let modifiers = self.parse_modifiers(false);
+        let start = DeclarationStart {
+            pos,
+            decorators,
+            modifiers,
+        };
+
+        if self.parse_contextual_modifier(SyntaxKind::GetKeyword) {
+            return self.parse_accessor_declaration(start, SyntaxKind::GetAccessor);
+        }
+        if self.parse_contextual_modifier(SyntaxKind::SetKeyword) {
+            return self.parse_accessor_declaration(start, SyntaxKind::SetAccessor);
+        }
+
+        let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+        let token_is_identifier = self.is_identifier();
+        let name = self.parse_property_name();
+
+        // Disallowing of optional property assignments and definite assignment assertion
+        // happens in the grammar checker.
+        let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+        let exclamation_token = self.parse_optional_token(SyntaxKind::ExclamationToken);
+
+        if asterisk_token.is_some()
+            || self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::LessThanToken
+        {
+            return self.parse_method_declaration(
+                start,
+                asterisk_token,
+                name,
+                question_token,
+                exclamation_token,
+                None,
+            );
+        }
+
+        // check if it is short-hand property assignment or normal property assignment
+        // NOTE: if token is EqualsToken it is interpreted as short-hand property assignment with
+        // initializer
+        // CoverInitializedName[Yield] :
+        //     IdentifierReference[?Yield] Initializer[In, ?Yield]
+        // this is necessary because ObjectLiteral productions are also used to cover grammar for
+        // ObjectAssignmentPattern
+        let is_shorthand_property_assignment =
+            token_is_identifier && self.token() != SyntaxKind::ColonToken;
+        if is_shorthand_property_assignment {
+            let equals_token = self.parse_optional_token(SyntaxKind::EqualsToken);
+            let object_assignment_initializer = if equals_token.is_some() {
+                Some(self.allow_in_and(Parser::parse_assignment_expression_or_higher))
+            } else {
+                None
+            };
+            return self.finish_declaration(
+                start,
+                SyntaxKind::ShorthandPropertyAssignment,
+                NodeData::ShorthandPropertyAssignment {
+                    name,
+                    question_token,
+                    exclamation_token,
+                    equals_token,
+                    object_assignment_initializer,
+                },
+            );
+        }
+
+        self.parse_expected(SyntaxKind::ColonToken, None);
+        let initializer = self.allow_in_and(Parser::parse_assignment_expression_or_higher);
+        self.finish_declaration(
+            start,
+            SyntaxKind::PropertyAssignment,
+            NodeData::PropertyAssignment {
+                name,
+                question_token,
+                initializer,
+            },
+        )
+    }
+
+    fn parse_object_literal_expression(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken, None);
+        let multi_line = self.has_preceding_line_break();
+        let properties = self.parse_delimited_list(
+            ParsingContext::ObjectLiteralMembers,
+            Parser::parse_object_literal_element,
+            /*consider_semicolon_as_delimiter*/ true,
+        );
+        self.parse_expected(SyntaxKind::CloseBraceToken, None);
+        self.finish_node(
+            SyntaxKind::ObjectLiteralExpression,
+            pos,
+            NodeData::ObjectLiteralExpression {
+                properties,
+                multi_line,
+            },
+        )
+    }
+
+    fn parse_function_expression(&mut self) -> NodeId {
+        // GeneratorExpression:
+        //      function* BindingIdentifier [Yield][opt](FormalParameters[Yield]){ GeneratorBody }
+        //
+        // FunctionExpression:
+        //      function BindingIdentifier[opt](FormalParameters){ FunctionBody }
+        let save_decorator_context = self.in_decorator_context();
+        if save_decorator_context {
+            self.set_context_flag(false, NodeFlags::DECORATOR_CONTEXT);
+        }
+
+        let pos = self.node_pos();
+        let modifiers = self.parse_modifiers(false);
+        self.parse_expected(SyntaxKind::FunctionKeyword, None);
+        let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+
+        let is_generator = if asterisk_token.is_some() {
+            SignatureFlags::YIELD
+        } else {
+            SignatureFlags::NONE
+        };
+        let is_async = if self.has_modifier_of_kind(&modifiers, SyntaxKind::AsyncKeyword) {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        let name = if !is_generator.is_empty() && !is_async.is_empty() {
+            self.do_in_yield_and_await_context(Parser::parse_optional_identifier)
+        } else if !is_generator.is_empty() {
+            self.do_in_yield_context(Parser::parse_optional_identifier)
+        } else if !is_async.is_empty() {
+            self.do_in_await_context(Parser::parse_optional_identifier)
+        } else {
+            self.parse_optional_identifier()
+        };
+
+        let flags = is_generator | is_async;
+        let signature = self.fill_signature(SyntaxKind::ColonToken, flags);
+        let body = self.parse_function_block(flags, None);
+
+        if save_decorator_context {
+            self.set_context_flag(true, NodeFlags::DECORATOR_CONTEXT);
+        }
+
+        self.finish_declaration(
+            DeclarationStart {
+                pos,
+                decorators: None,
+                modifiers,
+            },
+            SyntaxKind::FunctionExpression,
+            NodeData::FunctionLike {
+                asterisk_token,
+                name,
+                question_token: None,
+                exclamation_token: None,
+                type_parameters: signature.type_parameters,
+                parameters: signature.parameters,
+                ty: signature.ty,
+                equals_greater_than_token: None,
+                body: Some(body),
+            },
+        )
+    }
+
+    fn parse_new_expression_or_new_dot_target(&mut self) -> NodeId {
+        let full_start = self.scanner.get_start_pos();
+        self.parse_expected(SyntaxKind::NewKeyword, None);
+        if self.parse_optional(SyntaxKind::DotToken) {
+            let name = self.parse_identifier_name(None);
+            return self.finish_node(
+                SyntaxKind::MetaProperty,
+                full_start,
+                NodeData::MetaProperty {
+                    keyword_token: SyntaxKind::NewKeyword,
+                    name,
+                },
+            );
+        }
+
+        let mut expression = self.parse_primary_expression();
+        expression = self.parse_member_expression_rest(expression);
+        let mut type_arguments = self.try_parse(Parser::parse_type_arguments_in_expression);
+        if self.is_template_start_of_tagged_template() {
+            debug_assert!(
+                type_arguments.is_some(),
+                "Expected a type argument list; all plain tagged template starts should be consumed in 'parse_member_expression_rest'"
+            );
+            expression = self.parse_tagged_template_rest(expression, type_arguments.take());
+        }
+
+        let arguments = if type_arguments.is_some() || self.token() == SyntaxKind::OpenParenToken {
+            Some(self.parse_argument_list())
+        } else {
+            None
+        };
+        self.finish_node(
+            SyntaxKind::NewExpression,
+            full_start,
+            NodeData::CallOrNewExpression {
+                expression,
+                type_arguments,
+                arguments,
+            },
+        )
+    }
+
+    fn parse_type_assertion(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::LessThanToken, None);
+        let ty = self.parse_type();
+        self.parse_expected(SyntaxKind::GreaterThanToken, None);
+        let expression = self.parse_simple_unary_expression();
+        self.finish_node(
+            SyntaxKind::TypeAssertionExpression,
+            pos,
+            NodeData::TypeAssertion { ty, expression },
+        )
+    }
+
+    // Function bodies
+
+    fn parse_function_block(
+        &mut self,
+        flags: SignatureFlags,
+        diagnostic_message: Option<&DiagnosticMessage>,
+    ) -> NodeId {
+        let saved_yield_context = self.in_yield_context();
+        self.set_context_flag(
+            flags.contains(SignatureFlags::YIELD),
+            NodeFlags::YIELD_CONTEXT,
+        );
+
+        let saved_await_context = self.in_await_context();
+        self.set_context_flag(
+            flags.contains(SignatureFlags::AWAIT),
+            NodeFlags::AWAIT_CONTEXT,
+        );
+
+        // We may be in a [Decorator] context when parsing a function expression or
+        // arrow function. The body of the function is not in [Decorator] context.
+        let save_decorator_context = self.in_decorator_context();
+        if save_decorator_context {
+            self.set_context_flag(false, NodeFlags::DECORATOR_CONTEXT);
+        }
+
+        let block = self.parse_block(
+            flags.contains(SignatureFlags::IGNORE_MISSING_OPEN_BRACE),
+            diagnostic_message,
+        );
+
+        if save_decorator_context {
+            self.set_context_flag(true, NodeFlags::DECORATOR_CONTEXT);
+        }
+
+        self.set_context_flag(saved_yield_context, NodeFlags::YIELD_CONTEXT);
+        self.set_context_flag(saved_await_context, NodeFlags::AWAIT_CONTEXT);
+
+        block
+    }
+
+    fn parse_function_block_or_semicolon(
+        &mut self,
+        flags: SignatureFlags,
+        diagnostic_message: Option<&DiagnosticMessage>,
+    ) -> Option<NodeId> {
+        if self.token() != SyntaxKind::OpenBraceToken && self.can_parse_semicolon() {
+            self.parse_semicolon();
+            return None;
+        }
+        Some(self.parse_function_block(flags, diagnostic_message))
+    }
+
+    fn parse_method_declaration(
+        &mut self,
+        start: DeclarationStart,
+        asterisk_token: Option<NodeId>,
+        name: NodeId,
+        question_token: Option<NodeId>,
+        exclamation_token: Option<NodeId>,
+        diagnostic_message: Option<&DiagnosticMessage>,
+    ) -> NodeId {
+        let is_generator = if asterisk_token.is_some() {
+            SignatureFlags::YIELD
+        } else {
+            SignatureFlags::NONE
+        };
+        let is_async = if self.has_modifier_of_kind(&start.modifiers, SyntaxKind::AsyncKeyword) {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        let signature = self.fill_signature(SyntaxKind::ColonToken, is_generator | is_async);
+        let body =
+            self.parse_function_block_or_semicolon(is_generator | is_async, diagnostic_message);
+        self.finish_declaration(
+            start,
+            SyntaxKind::MethodDeclaration,
+            NodeData::FunctionLike {
+                asterisk_token,
+                name: Some(name),
+                question_token,
+                exclamation_token,
+                type_parameters: signature.type_parameters,
+                parameters: signature.parameters,
+                ty: signature.ty,
+                equals_greater_than_token: None,
+                body,
+            },
+        )
+    }
+
+    fn parse_accessor_declaration(&mut self, start: DeclarationStart, kind: SyntaxKind) -> NodeId {
+        let name = self.parse_property_name();
+        let signature = self.fill_signature(SyntaxKind::ColonToken, SignatureFlags::NONE);
+        let body = self.parse_function_block_or_semicolon(SignatureFlags::NONE, None);
+        self.finish_declaration(
+            start,
+            kind,
+            NodeData::FunctionLike {
+                asterisk_token: None,
+                name: Some(name),
+                question_token: None,
+                exclamation_token: None,
+                type_parameters: signature.type_parameters,
+                parameters: signature.parameters,
+                ty: signature.ty,
+                equals_greater_than_token: None,
+                body,
+            },
+        )
+    }
+
+    // Classes
+
+    fn parse_class_expression(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_class_declaration_or_expression(
+            DeclarationStart {
+                pos,
+                decorators: None,
+                modifiers: None,
+            },
+            SyntaxKind::ClassExpression,
+        )
+    }
+
+    fn parse_class_declaration_or_expression(
+        &mut self,
+        start: DeclarationStart,
+        kind: SyntaxKind,
+    ) -> NodeId {
+        self.parse_expected(SyntaxKind::ClassKeyword, None);
+        let name = self.parse_name_of_class_declaration_or_expression();
+        let type_parameters = self.parse_type_parameters();
+        let heritage_clauses = self.parse_heritage_clauses();
+
+        let members = if self.parse_expected(SyntaxKind::OpenBraceToken, None) {
+            // ClassTail[Yield,Await] : (Modified) See 14.5
+            //      ClassHeritage[?Yield,?Await]opt { ClassBody[?Yield,?Await]opt }
+            let members = self.parse_class_members();
+            self.parse_expected(SyntaxKind::CloseBraceToken, None);
+            members
+        } else {
+            self.create_missing_list()
+        };
+
+        self.finish_declaration(
+            start,
+            kind,
+            NodeData::ClassLike {
+                name,
+                type_parameters,
+                heritage_clauses,
+                members,
+            },
+        )
+    }
+
+    fn parse_name_of_class_declaration_or_expression(&mut self) -> Option<NodeId> {
+        // implements is a future reserved word so
+        // 'class implements' might mean either
+        // - class expression with omitted name, 'implements' starts heritage clause
+        // - class with name 'implements'
+        // 'is_implements_clause' helps to disambiguate between these two cases
+        if self.is_identifier() && !self.is_implements_clause() {
+            Some(self.parse_identifier(None))
+        } else {
+            None
+        }
+    }
+
+    fn is_implements_clause(&mut self) -> bool {
+        self.token() == SyntaxKind::ImplementsKeyword
+            && self.look_ahead(Parser::next_token_is_identifier_or_keyword)
+    }
+
+    fn parse_heritage_clauses(&mut self) -> Option<NodeList> {
+        // ClassTail[Yield,Await] : (Modified) See 14.5
+        //      ClassHeritage[?Yield,?Await]opt { ClassBody[?Yield,?Await]opt }
+        if self.is_heritage_clause() {
+            return Some(self.parse_list(
+                ParsingContext::HeritageClauses,
+                Parser::parse_heritage_clause,
+            ));
+        }
+        None
+    }
+
+    fn parse_heritage_clause(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let token = self.token();
+        debug_assert!(
+            token == SyntaxKind::ExtendsKeyword || token == SyntaxKind::ImplementsKeyword,
+            "Expected an extends or implements keyword"
+        );
+        self.next_token();
+        let types = self.parse_delimited_list(
+            ParsingContext::HeritageClauseElement,
+            Parser::parse_expression_with_type_arguments,
+            false,
+        );
+        self.finish_node(
+            SyntaxKind::HeritageClause,
+            pos,
+            NodeData::HeritageClause { token, types },
+        )
+    }
+
+    fn parse_expression_with_type_arguments(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let expression = self.parse_left_hand_side_expression_or_higher();
+        let type_arguments = self.try_parse_type_arguments();
+        self.finish_node(
+            SyntaxKind::ExpressionWithTypeArguments,
+            pos,
+            NodeData::ExpressionWithTypeArguments {
+                expression,
+                type_arguments,
+            },
+        )
+    }
+
+    fn try_parse_type_arguments(&mut self) -> Option<NodeList> {
+        if self.token() == SyntaxKind::LessThanToken {
+            return Some(self.parse_bracketed_list(
+                ParsingContext::TypeArguments,
+                Parser::parse_type,
+                SyntaxKind::LessThanToken,
+                SyntaxKind::GreaterThanToken,
+            ));
+        }
+        None
+    }
+
+    fn is_heritage_clause(&self) -> bool {
+        self.token() == SyntaxKind::ExtendsKeyword || self.token() == SyntaxKind::ImplementsKeyword
+    }
+
+    fn parse_class_members(&mut self) -> NodeList {
+        self.parse_list(ParsingContext::ClassMembers, Parser::parse_class_element)
+    }
+
+    fn parse_class_element(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::SemicolonToken {
+            let pos = self.node_pos();
+            self.next_token();
+            return self.finish_node(SyntaxKind::SemicolonClassElement, pos, NodeData::Empty {});
+        }
+
+        let pos = self.node_pos();
+        let decorators = self.parse_decorators();
+        let modifiers = self.parse_modifiers(/*permit_invalid_const_as_modifier*/ true);
+        let start = DeclarationStart {
+            pos,
+            decorators,
+            modifiers,
+        };
+
+        if self.parse_contextual_modifier(SyntaxKind::GetKeyword) {
+            return self.parse_accessor_declaration(start, SyntaxKind::GetAccessor);
+        }
+
+        if self.parse_contextual_modifier(SyntaxKind::SetKeyword) {
+            return self.parse_accessor_declaration(start, SyntaxKind::SetAccessor);
+        }
+
+        if self.token() == SyntaxKind::ConstructorKeyword {
+            return self.parse_constructor_declaration(start);
+        }
+
+        if self.is_index_signature() {
+            return self.parse_index_signature_declaration(start);
+        }
+
+        // It is very important that we check this *after* checking indexers because
+        // the [ token can start an index signature or a computed property name
+        if self.token().token_is_identifier_or_keyword()
+            || self.token() == SyntaxKind::StringLiteral
+            || self.token() == SyntaxKind::NumericLiteral
+            || self.token() == SyntaxKind::AsteriskToken
+            || self.token() == SyntaxKind::OpenBracketToken
+        {
+            return self.parse_property_or_method_declaration(start);
+        }
+
+        debug_assert!(
+            start.decorators.is_some() || start.modifiers.is_some(),
+            "Should not have attempted to parse class member declaration."
+        );
+        // treat this as a property declaration with a missing name.
+        let name = self.create_missing_node(
+            SyntaxKind::Identifier,
+            true,
+            &diagnostics::DECLARATION_EXPECTED,
+            &[],
+        );
+        self.parse_property_declaration(start, name, None)
+    }
+
+    fn parse_constructor_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        self.parse_expected(SyntaxKind::ConstructorKeyword, None);
+        let signature = self.fill_signature(SyntaxKind::ColonToken, SignatureFlags::NONE);
+        let body = self.parse_function_block_or_semicolon(
+            SignatureFlags::NONE,
+            Some(&diagnostics::OR_EXPECTED),
+        );
+        self.finish_declaration(
+            start,
+            SyntaxKind::Constructor,
+            NodeData::FunctionLike {
+                asterisk_token: None,
+                name: None,
+                question_token: None,
+                exclamation_token: None,
+                type_parameters: signature.type_parameters,
+                parameters: signature.parameters,
+                ty: signature.ty,
+                equals_greater_than_token: None,
+                body,
+            },
+        )
+    }
+
+    fn parse_property_or_method_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+        let name = self.parse_property_name();
+        // Note: this is not legal as per the grammar.  But we allow it in the parser and
+        // report an error in the grammar checker.
+        let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+        if asterisk_token.is_some()
+            || self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::LessThanToken
+        {
+            return self.parse_method_declaration(
+                start,
+                asterisk_token,
+                name,
+                question_token,
+                None,
+                Some(&diagnostics::OR_EXPECTED),
+            );
+        }
+        self.parse_property_declaration(start, name, question_token)
+    }
+
+    fn parse_property_declaration(
+        &mut self,
+        start: DeclarationStart,
+        name: NodeId,
+        question_token: Option<NodeId>,
+    ) -> NodeId {
+        let exclamation_token = if question_token.is_none() && !self.has_preceding_line_break() {
+            self.parse_optional_token(SyntaxKind::ExclamationToken)
+        } else {
+            None
+        };
+        let ty = self.parse_type_annotation();
+
+        // For instance properties specifically, since they are evaluated inside the constructor,
+        // we do *not * want to parse yield expressions, so we specifically turn the yield context
+        // off. The grammar would look something like this:
+        //
+        //    MemberVariableDeclaration[Yield]:
+        //        AccessibilityModifier_opt   PropertyName   TypeAnnotation_opt   Initializer_opt[In];
+        //        AccessibilityModifier_opt  static_opt  PropertyName   TypeAnnotation_opt   Initializer_opt[In, ?Yield];
+        //
+        // The checker may still error in the static case to explicitly disallow the yield
+        // expression.
+        let initializer = self.do_outside_of_context(
+            NodeFlags::YIELD_CONTEXT | NodeFlags::AWAIT_CONTEXT | NodeFlags::DISALLOW_IN_CONTEXT,
+            Parser::parse_initializer,
+        );
+
+        self.parse_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::PropertyDeclaration,
+            NodeData::PropertyDeclaration {
+                name,
+                question_token,
+                exclamation_token,
+                ty,
+                initializer,
+            },
+        )
+    }
+
+    fn is_class_member_start(&mut self) -> bool {
+        let mut id_token = None;
+
+        if self.token() == SyntaxKind::AtToken {
+            return true;
+        }
+
+        // Eat up all modifiers, but hold on to the last one in case it is actually an identifier.
+        while is_modifier_kind(self.token()) {
+            id_token = Some(self.token());
+            // If the id_token is a class modifier (protected, private, public, and static), it is
+            // certain that we are starting to parse class member. This allows better error
+            // recovery.
+            // Example:
+            //      public foo() ...     // true
+            //      public @dec blah ... // true; we will then report an error later
+            //      export public ...    // true; we will then report an error later
+            if is_class_member_modifier(self.token()) {
+                return true;
+            }
+
+            self.next_token();
+        }
+
+        if self.token() == SyntaxKind::AsteriskToken {
+            return true;
+        }
+
+        // Try to get the first property-like token following all modifiers.
+        // This can either be an identifier or the 'get' or 'set' keywords.
+        if self.is_literal_property_name() {
+            id_token = Some(self.token());
+            self.next_token();
+        }
+
+        // Index signatures and computed properties are class members; we can parse.
+        if self.token() == SyntaxKind::OpenBracketToken {
+            return true;
+        }
+
+        // If we were able to get any potential identifier...
+        if let Some(id_token) = id_token {
+            // If we have a non-keyword identifier, or if we have an accessor, then it's safe to
+            // parse.
+            if !id_token.is_keyword()
+                || id_token == SyntaxKind::SetKeyword
+                || id_token == SyntaxKind::GetKeyword
+            {
+                return true;
+            }
+
+            // If it *is* a keyword, but not an accessor, check a little farther along
+            // to see if it should actually be parsed as a class member.
+            return match self.token() {
+                SyntaxKind::OpenParenToken      // Method declaration
+                | SyntaxKind::LessThanToken     // Generic Method declaration
+                | SyntaxKind::ExclamationToken  // Non-null assertion on property name
+                | SyntaxKind::ColonToken        // Type Annotation for declaration
+                | SyntaxKind::EqualsToken       // Initializer for declaration
+                | SyntaxKind::QuestionToken => true, // Not valid, but permitted so that it gets caught later on.
+                // Covers
+                //  - Semicolons     (declaration termination)
+                //  - Closing braces (end-of-class, must be declaration)
+                //  - End-of-files   (not valid, but permitted so that it gets caught later on)
+                //  - Line-breaks    (enabling *automatic semicolon insertion*)
+                _ => self.can_parse_semicolon(),
+            };
+        }
+
+        false
+    }
+
+    // Decorators and modifiers
+
+    fn parse_decorators(&mut self) -> Option<NodeList> {
+        let list_pos = self.node_pos();
+        let mut list = Vec::new();
+        loop {
+            let decorator_start = self.node_pos();
+            if !self.parse_optional(SyntaxKind::AtToken) {
+                break;
+            }
+            let expression =
+                self.do_in_decorator_context(Parser::parse_left_hand_side_expression_or_higher);
+            list.push(self.finish_node(
+                SyntaxKind::Decorator,
+                decorator_start,
+                NodeData::Decorator { expression },
+            ));
+        }
+        if list.is_empty() {
+            return None;
+        }
+        Some(NodeList::new(list_pos, self.node_pos(), list))
+    }
+
+    /// Parses modifiers, treating `const` as one only when `permit_invalid_const_as_modifier` is
+    /// set, as it is for class members where `const` is an error the checker reports.
+    fn parse_modifiers(&mut self, permit_invalid_const_as_modifier: bool) -> Option<NodeList> {
+        let list_pos = self.node_pos();
+        let mut list = Vec::new();
+        loop {
+            let modifier_start = self.scanner.get_start_pos();
+            let modifier_kind = self.token();
+
+            if self.token() == SyntaxKind::ConstKeyword && permit_invalid_const_as_modifier {
+                // We need to ensure that any subsequent modifiers appear on the same line
+                // so that when 'const' is a standalone declaration, we don't issue an error.
+                if !self.try_parse(Parser::next_token_is_on_same_line_and_can_follow_modifier) {
+                    break;
+                }
+            } else if !self.parse_any_contextual_modifier() {
+                break;
+            }
+
+            list.push(self.finish_node(modifier_kind, modifier_start, NodeData::Token {}));
+        }
+        if list.is_empty() {
+            return None;
+        }
+        Some(NodeList::new(list_pos, self.node_pos(), list))
+    }
+
+    fn parse_modifiers_for_arrow_function(&mut self) -> Option<NodeList> {
+        if self.token() == SyntaxKind::AsyncKeyword {
+            let modifier_start = self.scanner.get_start_pos();
+            let modifier_kind = self.token();
+            self.next_token();
+            let modifier = self.finish_node(modifier_kind, modifier_start, NodeData::Token {});
+            return Some(NodeList::new(
+                modifier_start,
+                self.node_pos(),
+                vec![modifier],
+            ));
+        }
+        None
+    }
+
+    fn has_modifier_of_kind(&self, modifiers: &Option<NodeList>, kind: SyntaxKind) -> bool {
+        modifiers
+            .as_ref()
+            .is_some_and(|modifiers| modifiers.iter().any(|id| self.arena[id].kind == kind))
+    }
+
+    fn parse_any_contextual_modifier(&mut self) -> bool {
+        is_modifier_kind(self.token()) && self.try_parse(Parser::next_token_can_follow_modifier)
+    }
+
+    fn parse_contextual_modifier(&mut self, kind: SyntaxKind) -> bool {
+        self.token() == kind && self.try_parse(Parser::next_token_can_follow_modifier)
+    }
+
+    fn next_token_is_on_same_line_and_can_follow_modifier(&mut self) -> bool {
+        self.next_token();
+        if self.has_preceding_line_break() {
+            return false;
+        }
+        self.can_follow_modifier()
+    }
+
+    fn next_token_can_follow_modifier(&mut self) -> bool {
+        match self.token() {
+            SyntaxKind::ConstKeyword => {
+                // 'const' is only a modifier if followed by 'enum'.
+                self.next_token() == SyntaxKind::EnumKeyword
+            }
+            SyntaxKind::ExportKeyword => {
+                self.next_token();
+                if self.token() == SyntaxKind::DefaultKeyword {
+                    return self.look_ahead(Parser::next_token_can_follow_default_keyword);
+                }
+                self.token() != SyntaxKind::AsteriskToken
+                    && self.token() != SyntaxKind::AsKeyword
+                    && self.token() != SyntaxKind::OpenBraceToken
+                    && self.can_follow_modifier()
+            }
+            SyntaxKind::DefaultKeyword => self.next_token_can_follow_default_keyword(),
+            SyntaxKind::StaticKeyword => {
+                self.next_token();
+                self.can_follow_modifier()
+            }
+            _ => self.next_token_is_on_same_line_and_can_follow_modifier(),
+        }
+    }
+
+    fn can_follow_modifier(&self) -> bool {
+        self.token() == SyntaxKind::OpenBracketToken
+            || self.token() == SyntaxKind::OpenBraceToken
+            || self.token() == SyntaxKind::AsteriskToken
+            || self.token() == SyntaxKind::DotDotDotToken
+            || self.is_literal_property_name()
+    }
+
+    fn next_token_can_follow_default_keyword(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::ClassKeyword
+            || self.token() == SyntaxKind::FunctionKeyword
+            || self.token() == SyntaxKind::InterfaceKeyword
+            || (self.token() == SyntaxKind::AbstractKeyword
+                && self.look_ahead(Parser::next_token_is_class_keyword_on_same_line))
+            || (self.token() == SyntaxKind::AsyncKeyword
+                && self.look_ahead(Parser::next_token_is_function_keyword_on_same_line))
+    }
+
+    // Binding patterns
+
+    fn is_identifier_or_pattern(&self) -> bool {
+        self.token() == SyntaxKind::OpenBraceToken
+            || self.token() == SyntaxKind::OpenBracketToken
+            || self.is_identifier()
+    }
+
+    fn parse_identifier_or_pattern(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::OpenBracketToken => self.parse_array_binding_pattern(),
+            SyntaxKind::OpenBraceToken => self.parse_object_binding_pattern(),
+            _ => self.parse_identifier(None),
+        }
+    }
+
+    fn parse_array_binding_element(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::CommaToken {
+            return self.parse_omitted_expression();
+        }
+        let pos = self.node_pos();
+        let dot_dot_dot_token = self.parse_optional_token(SyntaxKind::DotDotDotToken);
+        let name = self.parse_identifier_or_pattern();
+        let initializer = self.parse_initializer();
+        self.finish_node(
+            SyntaxKind::BindingElement,
+            pos,
+            NodeData::BindingElement {
+                dot_dot_dot_token,
+                property_name: None,
+                name,
+                initializer,
+            },
+        )
+    }
+
+    fn parse_object_binding_element(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let dot_dot_dot_token = self.parse_optional_token(SyntaxKind::DotDotDotToken);
+        let token_is_identifier = self.is_identifier();
+        let property_name = self.parse_property_name();
+        let (property_name, name) = if token_is_identifier && self.token() != SyntaxKind::ColonToken
+        {
+            (None, property_name)
+        } else {
+            self.parse_expected(SyntaxKind::ColonToken, None);
+            (Some(property_name), self.parse_identifier_or_pattern())
+        };
+        let initializer = self.parse_initializer();
+        self.finish_node(
+            SyntaxKind::BindingElement,
+            pos,
+            NodeData::BindingElement {
+                dot_dot_dot_token,
+                property_name,
+                name,
+                initializer,
+            },
+        )
+    }
+
+    fn parse_object_binding_pattern(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken, None);
+        let elements = self.parse_delimited_list(
+            ParsingContext::ObjectBindingElements,
+            Parser::parse_object_binding_element,
+            false,
+        );
+        self.parse_expected(SyntaxKind::CloseBraceToken, None);
+        self.finish_node(
+            SyntaxKind::ObjectBindingPattern,
+            pos,
+            NodeData::BindingPattern { elements },
+        )
+    }
+
+    fn parse_array_binding_pattern(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenBracketToken, None);
+        let elements = self.parse_delimited_list(
+            ParsingContext::ArrayBindingElements,
+            Parser::parse_array_binding_element,
+            false,
+        );
+        self.parse_expected(SyntaxKind::CloseBracketToken, None);
+        self.finish_node(
+            SyntaxKind::ArrayBindingPattern,
+            pos,
+            NodeData::BindingPattern { elements },
+        )
+    }
+
+    // Statements
+
+    fn is_start_of_statement(&mut self) -> bool {
+        match self.token() {
+            SyntaxKind::AtToken
+            | SyntaxKind::SemicolonToken
+            | SyntaxKind::OpenBraceToken
+            | SyntaxKind::VarKeyword
+            | SyntaxKind::LetKeyword
+            | SyntaxKind::FunctionKeyword
+            | SyntaxKind::ClassKeyword
+            | SyntaxKind::EnumKeyword
+            | SyntaxKind::IfKeyword
+            | SyntaxKind::DoKeyword
+            | SyntaxKind::WhileKeyword
+            | SyntaxKind::ForKeyword
+            | SyntaxKind::ContinueKeyword
+            | SyntaxKind::BreakKeyword
+            | SyntaxKind::ReturnKeyword
+            | SyntaxKind::WithKeyword
+            | SyntaxKind::SwitchKeyword
+            | SyntaxKind::ThrowKeyword
+            | SyntaxKind::TryKeyword
+            | SyntaxKind::DebuggerKeyword
+            // 'catch' and 'finally' do not actually indicate that the code is part of a
+            // statement, however, we say they are here so that we may gracefully parse them and
+            // error later.
+            | SyntaxKind::CatchKeyword
+            | SyntaxKind::FinallyKeyword => true,
+
+            SyntaxKind::ImportKeyword => {
+                self.is_start_of_declaration()
+                    || self.look_ahead(Parser::next_token_is_open_paren_or_less_than_or_dot)
+            }
+
+            SyntaxKind::ConstKeyword | SyntaxKind::ExportKeyword => self.is_start_of_declaration(),
+
+            // When these don't start a declaration, they're an identifier in an expression
+            // statement
+            SyntaxKind::AsyncKeyword
+            | SyntaxKind::DeclareKeyword
+            | SyntaxKind::InterfaceKeyword
+            | SyntaxKind::ModuleKeyword
+            | SyntaxKind::NamespaceKeyword
+            | SyntaxKind::TypeKeyword
+            | SyntaxKind::GlobalKeyword => true,
+
+            // When these don't start a declaration, they may be the start of a class member if
+            // an identifier immediately follows. Otherwise they're an identifier in an
+            // expression statement.
+            SyntaxKind::PublicKeyword
+            | SyntaxKind::PrivateKeyword
+            | SyntaxKind::ProtectedKeyword
+            | SyntaxKind::StaticKeyword
+            | SyntaxKind::ReadonlyKeyword => {
+                self.is_start_of_declaration()
+                    || !self.look_ahead(Parser::next_token_is_identifier_or_keyword_on_same_line)
+            }
+
+            _ => self.is_start_of_expression(),
+        }
+    }
+
+    fn is_start_of_declaration(&mut self) -> bool {
+        self.look_ahead(Parser::is_declaration)
+    }
+
+    fn is_declaration(&mut self) -> bool {
+        loop {
+            match self.token() {
+                SyntaxKind::VarKeyword
+                | SyntaxKind::LetKeyword
+                | SyntaxKind::ConstKeyword
+                | SyntaxKind::FunctionKeyword
+                | SyntaxKind::ClassKeyword
+                | SyntaxKind::EnumKeyword => return true,
+
+                // 'declare', 'module', 'namespace', 'interface'* and 'type' are all legal
+                // JavaScript identifiers; however, an identifier cannot be followed by another
+                // identifier on the same line. This is what we count on to parse out the
+                // respective declarations. For instance, we exploit this to say that
+                //
+                //    namespace n
+                //
+                // can be none other than the beginning of a namespace declaration, but need to
+                // respect that JavaScript sees
+                //
+                //    namespace
+                //    n
+                //
+                // as the identifier 'namespace' on one line followed by the identifier 'n' on
+                // another. We need to look one token ahead to see if it permissible to try
+                // parsing a declaration.
+                //
+                // *Note*: 'interface' is actually a strict mode reserved word. So while
+                //
+                //   "use strict"
+                //   interface
+                //   I {}
+                //
+                // could be legal, it would add complexity for very little gain.
+                SyntaxKind::InterfaceKeyword | SyntaxKind::TypeKeyword => {
+                    return self.next_token_is_identifier_on_same_line();
+                }
+                SyntaxKind::ModuleKeyword | SyntaxKind::NamespaceKeyword => {
+                    return self.next_token_is_identifier_or_string_literal_on_same_line();
+                }
+                SyntaxKind::AbstractKeyword
+                | SyntaxKind::AsyncKeyword
+                | SyntaxKind::DeclareKeyword
+                | SyntaxKind::PrivateKeyword
+                | SyntaxKind::ProtectedKeyword
+                | SyntaxKind::PublicKeyword
+                | SyntaxKind::ReadonlyKeyword => {
+                    self.next_token();
+                    // ASI takes effect for this modifier.
+                    if self.has_preceding_line_break() {
+                        return false;
+                    }
+                }
+                SyntaxKind::GlobalKeyword => {
+                    self.next_token();
+                    return self.token() == SyntaxKind::OpenBraceToken
+                        || self.token() == SyntaxKind::Identifier
+                        || self.token() == SyntaxKind::ExportKeyword;
+                }
+                SyntaxKind::ImportKeyword => {
+                    self.next_token();
+                    return self.token() == SyntaxKind::StringLiteral
+                        || self.token() == SyntaxKind::AsteriskToken
+                        || self.token() == SyntaxKind::OpenBraceToken
+                        || self.token().token_is_identifier_or_keyword();
+                }
+                SyntaxKind::ExportKeyword => {
+                    self.next_token();
+                    if self.token() == SyntaxKind::EqualsToken
+                        || self.token() == SyntaxKind::AsteriskToken
+                        || self.token() == SyntaxKind::OpenBraceToken
+                        || self.token() == SyntaxKind::DefaultKeyword
+                        || self.token() == SyntaxKind::AsKeyword
+                    {
+                        return true;
+                    }
+                }
+                SyntaxKind::StaticKeyword => {
+                    self.next_token();
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    fn is_let_declaration(&mut self) -> bool {
+        // In ES6 'let' always starts a lexical declaration if followed by an identifier or {
+        // or [.
+        self.look_ahead(Parser::next_token_is_identifier_or_start_of_destructuring)
+    }
+
+    fn parse_statement(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::SemicolonToken => return self.parse_empty_statement(),
+            SyntaxKind::OpenBraceToken => return self.parse_block(false, None),
+            SyntaxKind::VarKeyword => {
+                let start = self.declaration_start_without_modifiers();
+                return self.parse_variable_statement(start);
+            }
+            SyntaxKind::LetKeyword if self.is_let_declaration() => {
+                let start = self.declaration_start_without_modifiers();
+                return self.parse_variable_statement(start);
+            }
+            SyntaxKind::FunctionKeyword => {
+                let start = self.declaration_start_without_modifiers();
+                return self.parse_function_declaration(start);
+            }
+            SyntaxKind::ClassKeyword => {
+                let start = self.declaration_start_without_modifiers();
+                return self
+                    .parse_class_declaration_or_expression(start, SyntaxKind::ClassDeclaration);
+            }
+            SyntaxKind::IfKeyword => return self.parse_if_statement(),
+            SyntaxKind::DoKeyword => return self.parse_do_statement(),
+            SyntaxKind::WhileKeyword => return self.parse_while_statement(),
+            SyntaxKind::ForKeyword => return self.parse_for_or_for_in_or_for_of_statement(),
+            SyntaxKind::ContinueKeyword => {
+                return self.parse_break_or_continue_statement(SyntaxKind::ContinueStatement)
+            }
+            SyntaxKind::BreakKeyword => {
+                return self.parse_break_or_continue_statement(SyntaxKind::BreakStatement)
+            }
+            SyntaxKind::ReturnKeyword => return self.parse_return_statement(),
+            SyntaxKind::WithKeyword => return self.parse_with_statement(),
+            SyntaxKind::SwitchKeyword => return self.parse_switch_statement(),
+            SyntaxKind::ThrowKeyword => return self.parse_throw_statement(),
+            // Include 'catch' and 'finally' for error recovery.
+            SyntaxKind::TryKeyword | SyntaxKind::CatchKeyword | SyntaxKind::FinallyKeyword => {
+                return self.parse_try_statement()
+            }
+            SyntaxKind::DebuggerKeyword => return self.parse_debugger_statement(),
+            SyntaxKind::AtToken => return self.parse_declaration(),
+            SyntaxKind::AsyncKeyword
+            | SyntaxKind::InterfaceKeyword
+            | SyntaxKind::TypeKeyword
+            | SyntaxKind::ModuleKeyword
+            | SyntaxKind::NamespaceKeyword
+            | SyntaxKind::DeclareKeyword
+            | SyntaxKind::ConstKeyword
+            | SyntaxKind::EnumKeyword
+            | SyntaxKind::ExportKeyword
+            | SyntaxKind::ImportKeyword
+            | SyntaxKind::PrivateKeyword
+            | SyntaxKind::ProtectedKeyword
+            | SyntaxKind::PublicKeyword
+            | SyntaxKind::AbstractKeyword
+            | SyntaxKind::StaticKeyword
+            | SyntaxKind::ReadonlyKeyword
+            | SyntaxKind::GlobalKeyword
+                if self.is_start_of_declaration() =>
+            {
+                return self.parse_declaration()
+            }
+            _ => {}
+        }
+        self.parse_expression_or_labeled_statement()
+    }
+
+    fn declaration_start_without_modifiers(&self) -> DeclarationStart {
+        DeclarationStart {
+            pos: self.node_pos(),
+            decorators: None,
+            modifiers: None,
+        }
+    }
+
+    fn parse_declaration(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let decorators = self.parse_decorators();
+        let modifiers = self.parse_modifiers(false);
+        let start = DeclarationStart {
+            pos,
+            decorators,
+            modifiers,
+        };
+
+        match self.token() {
+            SyntaxKind::VarKeyword | SyntaxKind::LetKeyword | SyntaxKind::ConstKeyword => {
+                self.parse_variable_statement(start)
+            }
+            SyntaxKind::FunctionKeyword => self.parse_function_declaration(start),
+            SyntaxKind::ClassKeyword => {
+                self.parse_class_declaration_or_expression(start, SyntaxKind::ClassDeclaration)
+            }
+            SyntaxKind::InterfaceKeyword => self.parse_interface_declaration(start),
+            SyntaxKind::TypeKeyword => self.parse_type_alias_declaration(start),
+            SyntaxKind::EnumKeyword => self.parse_enum_declaration(start),
+            SyntaxKind::GlobalKeyword
+            | SyntaxKind::ModuleKeyword
+            | SyntaxKind::NamespaceKeyword => self.parse_module_declaration(start),
+            SyntaxKind::ImportKeyword => {
+                self.parse_import_declaration_or_import_equals_declaration(start)
+            }
+            SyntaxKind::ExportKeyword => {
+                self.next_token();
+                match self.token() {
+                    SyntaxKind::DefaultKeyword | SyntaxKind::EqualsToken => {
+                        self.parse_export_assignment(start)
+                    }
+                    SyntaxKind::AsKeyword => self.parse_namespace_export_declaration(start),
+                    _ => self.parse_export_declaration(start),
+                }
+            }
+            _ => {
+                debug_assert!(
+                    start.decorators.is_some() || start.modifiers.is_some(),
+                    "Should not have attempted to parse a declaration."
+                );
+                // We reached this point because we encountered decorators and/or modifiers and
+                // assumed a declaration would follow. For recovery and error reporting purposes,
+                // return an incomplete declaration.
+                let error_pos = self.scanner.get_start_pos();
+                self.parse_error_at_position(error_pos, 0, &diagnostics::DECLARATION_EXPECTED, &[]);
+                self.finish_declaration(start, SyntaxKind::MissingDeclaration, NodeData::Empty {})
+            }
+        }
+    }
+
+    fn parse_block(
+        &mut self,
+        ignore_missing_open_brace: bool,
+        diagnostic_message: Option<&DiagnosticMessage>,
+    ) -> NodeId {
+        let pos = self.node_pos();
+        let (statements, multi_line) = if self
+            .parse_expected(SyntaxKind::OpenBraceToken, diagnostic_message)
+            || ignore_missing_open_brace
+        {
+            let multi_line = self.has_preceding_line_break();
+            let statements =
+                self.parse_list(ParsingContext::BlockStatements, Parser::parse_statement);
+            self.parse_expected(SyntaxKind::CloseBraceToken, None);
+            (statements, multi_line)
+        } else {
+            (self.create_missing_list(), false)
+        };
+        self.finish_node(
+            SyntaxKind::Block,
+            pos,
+            NodeData::Block {
+                statements,
+                multi_line,
+            },
+        )
+    }
+
+    fn parse_empty_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::SemicolonToken, None);
+        self.finish_node(SyntaxKind::EmptyStatement, pos, NodeData::Empty {})
+    }
+
+    fn parse_if_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::IfKeyword, None);
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let expression = self.allow_in_and(Parser::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        let then_statement = self.parse_statement();
+        let else_statement = if self.parse_optional(SyntaxKind::ElseKeyword) {
+            Some(self.parse_statement())
+        } else {
+            None
+        };
+        self.finish_node(
+            SyntaxKind::IfStatement,
+            pos,
+            NodeData::IfStatement {
+                expression,
+                then_statement,
+                else_statement,
+            },
+        )
+    }
+
+    fn parse_do_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::DoKeyword, None);
+        let statement = self.parse_statement();
+        self.parse_expected(SyntaxKind::WhileKeyword, None);
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let expression = self.allow_in_and(Parser::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+
+        // From: https://mail.mozilla.org/pipermail/es-discuss/2011-August/016188.html
+        // 157 min --- All allen at wirfs-brock.com CONF --- "do{;}while(false)false" prohibited
+        // in spec but allowed in consensus reality. Approved -- this is the de-facto standard
+        // whereby do;while(0)x will have a semicolon inserted before x.
+        self.parse_optional(SyntaxKind::SemicolonToken);
+        self.finish_node(
+            SyntaxKind::DoStatement,
+            pos,
+            NodeData::DoStatement {
+                statement,
+                expression,
+            },
+        )
+    }
+
+    fn parse_while_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::WhileKeyword, None);
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let expression = self.allow_in_and(Parser::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        let statement = self.parse_statement();
+        self.finish_node(
+            SyntaxKind::WhileStatement,
+            pos,
+            NodeData::WhileOrWithStatement {
+                expression,
+                statement,
+            },
+        )
+    }
+
+    fn parse_for_or_for_in_or_for_of_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::ForKeyword, None);
+        let await_token = self.parse_optional_token(SyntaxKind::AwaitKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+
+        let initializer = if self.token() == SyntaxKind::SemicolonToken {
+            None
+        } else if self.token() == SyntaxKind::VarKeyword
+            || self.token() == SyntaxKind::LetKeyword
+            || self.token() == SyntaxKind::ConstKeyword
+        {
+            Some(self.parse_variable_declaration_list(/*in_for_statement_initializer*/ true))
+        } else {
+            Some(self.disallow_in_and(Parser::parse_expression))
+        };
+
+        let is_for_of = if await_token.is_some() {
+            self.parse_expected(SyntaxKind::OfKeyword, None)
+        } else {
+            self.parse_optional(SyntaxKind::OfKeyword)
+        };
+        if is_for_of || await_token.is_some() || self.parse_optional(SyntaxKind::InKeyword) {
+            let kind = if is_for_of || await_token.is_some() {
+                SyntaxKind::ForOfStatement
+            } else {
+                SyntaxKind::ForInStatement
+            };
+            // Only `for await (;` gets here without an initializer, and its missing `of` has
+            // already been reported.
+            let initializer = match initializer {
+                Some(initializer) => initializer,
+                None => self.create_missing_node(
+                    SyntaxKind::Identifier,
+                    true,
+                    &diagnostics::EXPRESSION_EXPECTED,
+                    &[],
+                ),
+            };
+            let expression = if kind == SyntaxKind::ForOfStatement {
+                self.allow_in_and(Parser::parse_assignment_expression_or_higher)
+            } else {
+                self.allow_in_and(Parser::parse_expression)
+            };
+            self.parse_expected(SyntaxKind::CloseParenToken, None);
+            let statement = self.parse_statement();
+            return self.finish_node(
+                kind,
+                pos,
+                NodeData::ForInOrOfStatement {
+                    await_modifier: await_token,
+                    initializer,
+                    expression,
+                    statement,
+                },
+            );
+        }
+
+        self.parse_expected(SyntaxKind::SemicolonToken, None);
+        let condition = if self.token() != SyntaxKind::SemicolonToken
+            && self.token() != SyntaxKind::CloseParenToken
+        {
+            Some(self.allow_in_and(Parser::parse_expression))
+        } else {
+            None
+        };
+        self.parse_expected(SyntaxKind::SemicolonToken, None);
+        let incrementor = if self.token() != SyntaxKind::CloseParenToken {
+            Some(self.allow_in_and(Parser::parse_expression))
+        } else {
+            None
+        };
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        let statement = self.parse_statement();
+        self.finish_node(
+            SyntaxKind::ForStatement,
+            pos,
+            NodeData::ForStatement {
+                initializer,
+                condition,
+                incrementor,
+                statement,
+            },
+        )
+    }
+
+    fn parse_break_or_continue_statement(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(
+            if kind == SyntaxKind::BreakStatement {
+                SyntaxKind::BreakKeyword
+            } else {
+                SyntaxKind::ContinueKeyword
+            },
+            None,
+        );
+        let label = if !self.can_parse_semicolon() {
+            Some(self.parse_identifier(None))
+        } else {
+            None
+        };
+        self.parse_semicolon();
+        self.finish_node(kind, pos, NodeData::BreakOrContinueStatement { label })
+    }
+
+    fn parse_return_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::ReturnKeyword, None);
+        let expression = if !self.can_parse_semicolon() {
+            Some(self.allow_in_and(Parser::parse_expression))
+        } else {
+            None
+        };
+        self.parse_semicolon();
+        self.finish_node(
+            SyntaxKind::ReturnStatement,
+            pos,
+            NodeData::ReturnStatement { expression },
+        )
+    }
+
+    fn parse_with_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::WithKeyword, None);
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let expression = self.allow_in_and(Parser::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        let statement = self.parse_statement();
+        self.finish_node(
+            SyntaxKind::WithStatement,
+            pos,
+            NodeData::WhileOrWithStatement {
+                expression,
+                statement,
+            },
+        )
+    }
+
+    fn parse_case_or_default_clause(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        if self.parse_optional(SyntaxKind::CaseKeyword) {
+            let expression = self.allow_in_and(Parser::parse_expression);
+            self.parse_expected(SyntaxKind::ColonToken, None);
+            let statements = self.parse_list(
+                ParsingContext::SwitchClauseStatements,
+                Parser::parse_statement,
+            );
+            return self.finish_node(
+                SyntaxKind::CaseClause,
+                pos,
+                NodeData::CaseClause {
+                    expression,
+                    statements,
+                },
+            );
+        }
+        self.parse_expected(SyntaxKind::DefaultKeyword, None);
+        self.parse_expected(SyntaxKind::ColonToken, None);
+        let statements = self.parse_list(
+            ParsingContext::SwitchClauseStatements,
+            Parser::parse_statement,
+        );
+        self.finish_node(
+            SyntaxKind::DefaultClause,
+            pos,
+            NodeData::DefaultClause { statements },
+        )
+    }
+
+    fn parse_switch_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::SwitchKeyword, None);
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let expression = self.allow_in_and(Parser::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        let case_block_pos = self.node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken, None);
+        let clauses = self.parse_list(
+            ParsingContext::SwitchClauses,
+            Parser::parse_case_or_default_clause,
+        );
+        self.parse_expected(SyntaxKind::CloseBraceToken, None);
+        let case_block = self.finish_node(
+            SyntaxKind::CaseBlock,
+            case_block_pos,
+            NodeData::CaseBlock { clauses },
+        );
+        self.finish_node(
+            SyntaxKind::SwitchStatement,
+            pos,
+            NodeData::SwitchStatement {
+                expression,
+                case_block,
+            },
+        )
+    }
+
+    fn parse_throw_statement(&mut self) -> NodeId {
+        // ThrowStatement[Yield] :
+        //      throw [no LineTerminator here]Expression[In, ?Yield];
+
+        // Because of automatic semicolon insertion, we need to report error if this
+        // throw could be terminated with a semicolon.  Note: we can't call 'parse_expression'
+        // directly as that might consume an expression on the following line.
+        // We just return None in that case.  The actual error will be reported in the
+        // grammar walker.
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::ThrowKeyword, None);
+        let expression = if self.has_preceding_line_break() {
+            None
+        } else {
+            Some(self.allow_in_and(Parser::parse_expression))
+        };
+        self.parse_semicolon();
+        self.finish_node(
+            SyntaxKind::ThrowStatement,
+            pos,
+            NodeData::ThrowStatement { expression },
+        )
+    }
+
+    fn parse_try_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::TryKeyword, None);
+        let try_block = self.parse_block(false, None);
+        let catch_clause = if self.token() == SyntaxKind::CatchKeyword {
+            Some(self.parse_catch_clause())
+        } else {
+            None
+        };
+
+        // If we don't have a catch clause, then we must have a finally clause.  Try to parse
+        // one out no matter what.
+        let finally_block = if catch_clause.is_none() || self.token() == SyntaxKind::FinallyKeyword
+        {
+            self.parse_expected(SyntaxKind::FinallyKeyword, None);
+            Some(self.parse_block(false, None))
+        } else {
+            None
+        };
+
+        self.finish_node(
+            SyntaxKind::TryStatement,
+            pos,
+            NodeData::TryStatement {
+                try_block,
+                catch_clause,
+                finally_block,
+            },
+        )
+    }
+
+    fn parse_catch_clause(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::CatchKeyword, None);
+        let variable_declaration = if self.parse_optional(SyntaxKind::OpenParenToken) {
+            let variable_declaration = self.parse_variable_declaration();
+            self.parse_expected(SyntaxKind::CloseParenToken, None);
+            Some(variable_declaration)
+        } else {
+            None
+        };
+        let block = self.parse_block(false, None);
+        self.finish_node(
+            SyntaxKind::CatchClause,
+            pos,
+            NodeData::CatchClause {
+                variable_declaration,
+                block,
+            },
+        )
+    }
+
+    fn parse_debugger_statement(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::DebuggerKeyword, None);
+        self.parse_semicolon();
+        self.finish_node(SyntaxKind::DebuggerStatement, pos, NodeData::Empty {})
+    }
+
+    fn parse_expression_or_labeled_statement(&mut self) -> NodeId {
+        // Avoiding having to do the lookahead for a labeled statement by just trying to parse
+        // out an expression, seeing if it is identifier and then seeing if it is followed by
+        // a colon.
+        let pos = self.node_pos();
+        let expression = self.allow_in_and(Parser::parse_expression);
+        if self.arena[expression].kind == SyntaxKind::Identifier
+            && self.parse_optional(SyntaxKind::ColonToken)
+        {
+            let statement = self.parse_statement();
+            return self.finish_node(
+                SyntaxKind::LabeledStatement,
+                pos,
+                NodeData::LabeledStatement {
+                    label: expression,
+                    statement,
+                },
+            );
+        }
+        self.parse_semicolon();
+        self.finish_node(
+            SyntaxKind::ExpressionStatement,
+            pos,
+            NodeData::ExpressionStatement { expression },
+        )
+    }
+
+    // Variable declarations
+
+    fn parse_variable_declaration_allow_exclamation(&mut self) -> NodeId {
+        self.parse_variable_declaration_worker(true)
+    }
+
+    fn parse_variable_declaration(&mut self) -> NodeId {
+        self.parse_variable_declaration_worker(false)
+    }
+
+    fn parse_variable_declaration_worker(&mut self, allow_exclamation: bool) -> NodeId {
+        let pos = self.node_pos();
+        let name = self.parse_identifier_or_pattern();
+        let exclamation_token = if allow_exclamation
+            && self.arena[name].kind == SyntaxKind::Identifier
+            && self.token() == SyntaxKind::ExclamationToken
+            && !self.has_preceding_line_break()
+        {
+            Some(self.parse_token_node())
+        } else {
+            None
+        };
+        let ty = self.parse_type_annotation();
+        let initializer = if !is_in_or_of_keyword(self.token()) {
+            self.parse_initializer()
+        } else {
+            None
+        };
+        self.finish_node(
+            SyntaxKind::VariableDeclaration,
+            pos,
+            NodeData::VariableDeclaration {
+                name,
+                exclamation_token,
+                ty,
+                initializer,
+            },
+        )
+    }
+
+    fn parse_variable_declaration_list(&mut self, in_for_statement_initializer: bool) -> NodeId {
+        let pos = self.node_pos();
+        let flags = match self.token() {
+            SyntaxKind::VarKeyword => NodeFlags::NONE,
+            SyntaxKind::LetKeyword => NodeFlags::LET,
+            SyntaxKind::ConstKeyword => NodeFlags::CONST,
+            _ => unreachable!("Expected var, let or const"),
+        };
+        self.next_token();
+
+        // The user may have written the following:
+        //
+        //    for (let of X) { }
+        //
+        // In this case, we want to parse an empty declaration list, and then parse 'of'
+        // as a keyword. The reason this is not automatic is that 'of' is a valid identifier.
+        // So we need to look ahead to determine if 'of' should be treated as a keyword in
+        // this context.
+        // The checker will then give an error that there is an empty declaration list.
+        let declarations = if self.token() == SyntaxKind::OfKeyword
+            && self.look_ahead(Parser::can_follow_contextual_of_keyword)
+        {
+            self.create_missing_list()
+        } else {
+            let saved_disallow_in = self.in_disallow_in_context();
+            self.set_context_flag(in_for_statement_initializer, NodeFlags::DISALLOW_IN_CONTEXT);
+            let declarations = self.parse_delimited_list(
+                ParsingContext::VariableDeclarations,
+                if in_for_statement_initializer {
+                    Parser::parse_variable_declaration
+                } else {
+                    Parser::parse_variable_declaration_allow_exclamation
+                },
+                false,
+            );
+            self.set_context_flag(saved_disallow_in, NodeFlags::DISALLOW_IN_CONTEXT);
+            declarations
+        };
+
+        let mut node = Node::new(
+            SyntaxKind::VariableDeclarationList,
+            pos,
+            pos,
+            NodeData::VariableDeclarationList { declarations },
+        );
+        node.flags = flags;
+        self.finish(node)
+    }
+
+    fn can_follow_contextual_of_keyword(&mut self) -> bool {
+        self.next_token_is_identifier() && self.next_token() == SyntaxKind::CloseParenToken
+    }
+
+    fn parse_variable_statement(&mut self, start: DeclarationStart) -> NodeId {
+        let declaration_list = self.parse_variable_declaration_list(false);
+        self.parse_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::VariableStatement,
+            NodeData::VariableStatement { declaration_list },
+        )
+    }
+
+    // Declarations
+
+    fn parse_function_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        self.parse_expected(SyntaxKind::FunctionKeyword, None);
+        let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+        let name = if self.has_modifier_of_kind(&start.modifiers, SyntaxKind::DefaultKeyword) {
+            self.parse_optional_identifier()
+        } else {
+            Some(self.parse_identifier(None))
+        };
+        let is_generator = if asterisk_token.is_some() {
+            SignatureFlags::YIELD
+        } else {
+            SignatureFlags::NONE
+        };
+        let is_async = if self.has_modifier_of_kind(&start.modifiers, SyntaxKind::AsyncKeyword) {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        let signature = self.fill_signature(SyntaxKind::ColonToken, is_generator | is_async);
+        let body = self.parse_function_block_or_semicolon(
+            is_generator | is_async,
+            Some(&diagnostics::OR_EXPECTED),
+        );
+        self.finish_declaration(
+            start,
+            SyntaxKind::FunctionDeclaration,
+            NodeData::FunctionLike {
+                asterisk_token,
+                name,
+                question_token: None,
+                exclamation_token: None,
+                type_parameters: signature.type_parameters,
+                parameters: signature.parameters,
+                ty: signature.ty,
+                equals_greater_than_token: None,
+                body,
+            },
+        )
+    }
+
+    fn parse_interface_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        self.parse_expected(SyntaxKind::InterfaceKeyword, None);
+        let name = self.parse_identifier(None);
+        let type_parameters = self.parse_type_parameters();
+        let heritage_clauses = self.parse_heritage_clauses();
+        let members = self.parse_object_type_members();
+        self.finish_declaration(
+            start,
+            SyntaxKind::InterfaceDeclaration,
+            NodeData::InterfaceDeclaration {
+                name,
+                type_parameters,
+                heritage_clauses,
+                members,
+            },
+        )
+    }
+
+    fn parse_type_alias_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        self.parse_expected(SyntaxKind::TypeKeyword, None);
+        let name = self.parse_identifier(None);
+        let type_parameters = self.parse_type_parameters();
+        self.parse_expected(SyntaxKind::EqualsToken, None);
+        let ty = self.parse_type();
+        self.parse_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::TypeAliasDeclaration,
+            NodeData::TypeAliasDeclaration {
+                name,
+                type_parameters,
+                ty,
+            },
+        )
+    }
+
+    // In an ambient declaration, the grammar only allows integer literals as initializers.
+    // In a non-ambient declaration, the grammar allows uninitialized members only in a
+    // ConstantEnumMemberSection, which starts at the beginning of an enum declaration
+    // or any time an integer literal initializer is encountered.
+    fn parse_enum_member(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let name = self.parse_property_name();
+        let initializer = self.allow_in_and(Parser::parse_initializer);
+        self.finish_node(
+            SyntaxKind::EnumMember,
+            pos,
+            NodeData::EnumMember { name, initializer },
+        )
+    }
+
+    fn parse_enum_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        self.parse_expected(SyntaxKind::EnumKeyword, None);
+        let name = self.parse_identifier(None);
+        let members = if self.parse_expected(SyntaxKind::OpenBraceToken, None) {
+            let members = self.parse_delimited_list(
+                ParsingContext::EnumMembers,
+                Parser::parse_enum_member,
+                false,
+            );
+            self.parse_expected(SyntaxKind::CloseBraceToken, None);
+            members
+        } else {
+            self.create_missing_list()
+        };
+        self.finish_declaration(
+            start,
+            SyntaxKind::EnumDeclaration,
+            NodeData::EnumDeclaration { name, members },
+        )
+    }
+
+    fn parse_module_block(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        let statements = if self.parse_expected(SyntaxKind::OpenBraceToken, None) {
+            let statements =
+                self.parse_list(ParsingContext::BlockStatements, Parser::parse_statement);
+            self.parse_expected(SyntaxKind::CloseBraceToken, None);
+            statements
+        } else {
+            self.create_missing_list()
+        };
+        self.finish_node(
+            SyntaxKind::ModuleBlock,
+            pos,
+            NodeData::Block {
+                statements,
+                multi_line: false,
+            },
+        )
+    }
+
+    fn parse_module_or_namespace_declaration(
+        &mut self,
+        start: DeclarationStart,
+        flags: NodeFlags,
+    ) -> NodeId {
+        // If we are parsing a dotted namespace name, we want to
+        // propagate the 'Namespace' flag across the names if set.
+        let namespace_flag = flags & NodeFlags::NAMESPACE;
+        let name = self.parse_identifier(None);
+        let body = if self.parse_optional(SyntaxKind::DotToken) {
+            let start = self.declaration_start_without_modifiers();
+            self.parse_module_or_namespace_declaration(
+                start,
+                NodeFlags::NESTED_NAMESPACE | namespace_flag,
+            )
+        } else {
+            self.parse_module_block()
+        };
+        self.finish_module_declaration(start, flags, name, Some(body))
+    }
+
+    fn parse_ambient_external_module_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        let (name, flags) = if self.token() == SyntaxKind::GlobalKeyword {
+            // parse 'global' as name of global scope augmentation
+            (self.parse_identifier(None), NodeFlags::GLOBAL_AUGMENTATION)
+        } else {
+            (self.parse_literal_node(), NodeFlags::NONE)
+        };
+
+        let body = if self.token() == SyntaxKind::OpenBraceToken {
+            Some(self.parse_module_block())
+        } else {
+            self.parse_semicolon();
+            None
+        };
+        self.finish_module_declaration(start, flags, name, body)
+    }
+
+    fn finish_module_declaration(
+        &mut self,
+        start: DeclarationStart,
+        flags: NodeFlags,
+        name: NodeId,
+        body: Option<NodeId>,
+    ) -> NodeId {
+        let mut node = Node::new(
+            SyntaxKind::ModuleDeclaration,
+            start.pos,
+            start.pos,
+            NodeData::ModuleDeclaration { name, body },
+        );
+        node.flags = flags;
+        node.decorators = start.decorators;
+        node.modifiers = start.modifiers;
+        self.finish(node)
+    }
+
+    fn parse_module_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        let mut flags = NodeFlags::NONE;
+        if self.token() == SyntaxKind::GlobalKeyword {
+            // global augmentation
+            return self.parse_ambient_external_module_declaration(start);
+        } else if self.parse_optional(SyntaxKind::NamespaceKeyword) {
+            flags |= NodeFlags::NAMESPACE;
+        } else {
+            self.parse_expected(SyntaxKind::ModuleKeyword, None);
+            if self.token() == SyntaxKind::StringLiteral {
+                return self.parse_ambient_external_module_declaration(start);
+            }
+        }
+        self.parse_module_or_namespace_declaration(start, flags)
+    }
+
+    // Imports and exports
+
+    fn is_external_module_reference(&mut self) -> bool {
+        self.token() == SyntaxKind::RequireKeyword
+            && self.look_ahead(Parser::next_token_is_open_paren)
+    }
+
+    fn parse_namespace_export_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        self.parse_expected(SyntaxKind::AsKeyword, None);
+        self.parse_expected(SyntaxKind::NamespaceKeyword, None);
+        let name = self.parse_identifier(None);
+        self.parse_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::NamespaceExportDeclaration,
+            NodeData::NamespaceImportOrExport { name },
+        )
+    }
+
+    fn parse_import_declaration_or_import_equals_declaration(
+        &mut self,
+        start: DeclarationStart,
+    ) -> NodeId {
+        self.parse_expected(SyntaxKind::ImportKeyword, None);
+        let after_import_pos = self.scanner.get_start_pos();
+
+        let mut identifier = None;
+        if self.is_identifier() {
+            let name = self.parse_identifier(None);
+            if self.token() != SyntaxKind::CommaToken && self.token() != SyntaxKind::FromKeyword {
+                return self.parse_import_equals_declaration(start, name);
+            }
+            identifier = Some(name);
+        }
+
+        // Import statement
+        // ImportDeclaration:
+        //  import ImportClause from ModuleSpecifier ;
+        //  import ModuleSpecifier;
+        let import_clause = if identifier.is_some()         // import id
+            || self.token() == SyntaxKind::AsteriskToken   // import *
+            || self.token() == SyntaxKind::OpenBraceToken
+        // import {
+        {
+            let import_clause = self.parse_import_clause(identifier, after_import_pos);
+            self.parse_expected(SyntaxKind::FromKeyword, None);
+            Some(import_clause)
+        } else {
+            None
+        };
+
+        let module_specifier = self.parse_module_specifier();
+        self.parse_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::ImportDeclaration,
+            NodeData::ImportDeclaration {
+                import_clause,
+                module_specifier,
+            },
+        )
+    }
+
+    fn parse_import_equals_declaration(&mut self, start: DeclarationStart, name: NodeId) -> NodeId {
+        self.parse_expected(SyntaxKind::EqualsToken, None);
+        let module_reference = self.parse_module_reference();
+        self.parse_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::ImportEqualsDeclaration,
+            NodeData::ImportEqualsDeclaration {
+                name,
+                module_reference,
+            },
+        )
+    }
+
+    fn parse_import_clause(&mut self, identifier: Option<NodeId>, full_start: usize) -> NodeId {
+        // ImportClause:
+        //  ImportedDefaultBinding
+        //  NameSpaceImport
+        //  NamedImports
+        //  ImportedDefaultBinding, NameSpaceImport
+        //  ImportedDefaultBinding, NamedImports
+
+        // If there was no default import or if there is comma token after default import
+        // parse namespace or named imports
+        let named_bindings = if identifier.is_none() || self.parse_optional(SyntaxKind::CommaToken)
+        {
+            Some(if self.token() == SyntaxKind::AsteriskToken {
+                self.parse_namespace_import()
+            } else {
+                self.parse_named_imports_or_exports(SyntaxKind::NamedImports)
+            })
+        } else {
+            None
+        };
+        self.finish_node(
+            SyntaxKind::ImportClause,
+            full_start,
+            NodeData::ImportClause {
+                name: identifier,
+                named_bindings,
+            },
+        )
+    }
+
+    fn parse_module_reference(&mut self) -> NodeId {
+        if self.is_external_module_reference() {
+            self.parse_external_module_reference()
+        } else {
+            self.parse_entity_name(/*allow_reserved_words*/ false, None)
+        }
+    }
+
+    fn parse_external_module_reference(&mut self) -> NodeId {
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::RequireKeyword, None);
+        self.parse_expected(SyntaxKind::OpenParenToken, None);
+        let expression = self.parse_module_specifier();
+        self.parse_expected(SyntaxKind::CloseParenToken, None);
+        self.finish_node(
+            SyntaxKind::ExternalModuleReference,
+            pos,
+            NodeData::ExternalModuleReference { expression },
+        )
+    }
+
+    fn parse_module_specifier(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::StringLiteral {
+            self.parse_literal_node()
+        } else {
+            // We allow arbitrary expressions here, even though the grammar only allows string
+            // literals.  We check to ensure that it is only a string literal later in the
+            // grammar check pass.
+            self.parse_expression()
+        }
+    }
+
+    fn parse_namespace_import(&mut self) -> NodeId {
+        // NameSpaceImport:
+        //  * as ImportedBinding
+        let pos = self.node_pos();
+        self.parse_expected(SyntaxKind::AsteriskToken, None);
+        self.parse_expected(SyntaxKind::AsKeyword, None);
+        let name = self.parse_identifier(None);
+        self.finish_node(
+            SyntaxKind::NamespaceImport,
+            pos,
+            NodeData::NamespaceImportOrExport { name },
+        )
+    }
+
+    fn parse_named_imports_or_exports(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.node_pos();
+
+        // NamedImports:
+        //  { }
+        //  { ImportsList }
+        //  { ImportsList, }
+
+        // ImportsList:
+        //  ImportSpecifier
+        //  ImportsList, ImportSpecifier
+        let elements = self.parse_bracketed_list(
+            ParsingContext::ImportOrExportSpecifiers,
+            if kind == SyntaxKind::NamedImports {
+                Parser::parse_import_specifier
+            } else {
+                Parser::parse_export_specifier
+            },
+            SyntaxKind::OpenBraceToken,
+            SyntaxKind::CloseBraceToken,
+        );
+        self.finish_node(kind, pos, NodeData::NamedImportsOrExports { elements })
+    }
+
+    fn parse_export_specifier(&mut self) -> NodeId {
+        self.parse_import_or_export_specifier(SyntaxKind::ExportSpecifier)
+    }
+
+    fn parse_import_specifier(&mut self) -> NodeId {
+        self.parse_import_or_export_specifier(SyntaxKind::ImportSpecifier)
+    }
+
+    fn parse_import_or_export_specifier(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.node_pos();
+        // ImportSpecifier:
+        //   BindingIdentifier
+        //   IdentifierName as BindingIdentifier
+        // ExportSpecifier:
+        //   IdentifierName
+        //   IdentifierName as IdentifierName
+        let mut check_identifier_is_keyword = self.token().is_keyword() && !self.is_identifier();
+        let mut check_identifier_start = self.scanner.get_token_pos();
+        let mut check_identifier_end = self.scanner.get_text_pos();
+        let identifier_name = self.parse_identifier_name(None);
+        let (property_name, name) = if self.token() == SyntaxKind::AsKeyword {
+            self.parse_expected(SyntaxKind::AsKeyword, None);
+            check_identifier_is_keyword = self.token().is_keyword() && !self.is_identifier();
+            check_identifier_start = self.scanner.get_token_pos();
+            check_identifier_end = self.scanner.get_text_pos();
+            (Some(identifier_name), self.parse_identifier_name(None))
+        } else {
+            (None, identifier_name)
+        };
+        if kind == SyntaxKind::ImportSpecifier && check_identifier_is_keyword {
+            self.parse_error_at(
+                check_identifier_start,
+                check_identifier_end,
+                &diagnostics::IDENTIFIER_EXPECTED,
+                &[],
+            );
+        }
+        self.finish_node(
+            kind,
+            pos,
+            NodeData::ImportOrExportSpecifier {
+                property_name,
+                name,
+            },
+        )
+    }
+
+    fn parse_export_declaration(&mut self, start: DeclarationStart) -> NodeId {
+        let (export_clause, module_specifier) = if self.parse_optional(SyntaxKind::AsteriskToken) {
+            self.parse_expected(SyntaxKind::FromKeyword, None);
+            (None, Some(self.parse_module_specifier()))
+        } else {
+            let export_clause = self.parse_named_imports_or_exports(SyntaxKind::NamedExports);
+            // It is not uncommon to accidentally omit the 'from' keyword. Additionally, in
+            // editing scenarios, the 'from' keyword can be parsed as a named export when the
+            // export clause is unterminated (i.e. `export { from "moduleName";`)
+            // If we don't have a 'from' keyword, see if we have a string literal such that ASI
+            // won't take effect.
+            let module_specifier = if self.token() == SyntaxKind::FromKeyword
+                || (self.token() == SyntaxKind::StringLiteral && !self.has_preceding_line_break())
+            {
+                self.parse_expected(SyntaxKind::FromKeyword, None);
+                Some(self.parse_module_specifier())
+            } else {
+                None
+            };
+            (Some(export_clause), module_specifier)
+        };
+        self.parse_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::ExportDeclaration,
+            NodeData::ExportDeclaration {
+                export_clause,
+                module_specifier,
+            },
+        )
+    }
+
+    fn parse_export_assignment(&mut self, start: DeclarationStart) -> NodeId {
+        let is_export_equals = self.parse_optional(SyntaxKind::EqualsToken);
+        if !is_export_equals {
+            self.parse_expected(SyntaxKind::DefaultKeyword, None);
+        }
+        let expression = self.parse_assignment_expression_or_higher();
+        self.parse_semicolon();
+        self.finish_declaration(
+            start,
+            SyntaxKind::ExportAssignment,
+            NodeData::ExportAssignment {
+                is_export_equals,
+                expression,
+            },
+        )
+    }
+
+    // Look-ahead helpers
+
+    fn next_token_is_identifier(&mut self) -> bool {
+        self.next_token();
+        self.is_identifier()
+    }
+
+    fn next_token_is_identifier_on_same_line(&mut self) -> bool {
+        self.next_token();
+        !self.has_preceding_line_break() && self.is_identifier()
+    }
+
+    fn next_token_is_identifier_or_string_literal_on_same_line(&mut self) -> bool {
+        self.next_token();
+        !self.has_preceding_line_break()
+            && (self.is_identifier() || self.token() == SyntaxKind::StringLiteral)
+    }
+
+    fn next_token_is_identifier_or_start_of_destructuring(&mut self) -> bool {
+        self.next_token();
+        self.is_identifier()
+            || self.token() == SyntaxKind::OpenBraceToken
+            || self.token() == SyntaxKind::OpenBracketToken
+    }
+
+    fn next_token_is_identifier_or_keyword(&mut self) -> bool {
+        self.next_token().token_is_identifier_or_keyword()
+    }
+
+    fn next_token_is_identifier_or_keyword_on_same_line(&mut self) -> bool {
+        self.next_token();
+        self.token().token_is_identifier_or_keyword() && !self.has_preceding_line_break()
+    }
+
+    fn next_token_is_identifier_or_keyword_or_literal_on_same_line(&mut self) -> bool {
+        self.next_token();
+        (self.token().token_is_identifier_or_keyword()
+            || self.token() == SyntaxKind::NumericLiteral
+            || self.token() == SyntaxKind::BigIntLiteral
+            || self.token() == SyntaxKind::StringLiteral)
+            && !self.has_preceding_line_break()
+    }
+
+    fn next_token_is_open_paren_or_less_than(&mut self) -> bool {
+        let next = self.next_token();
+        next == SyntaxKind::OpenParenToken || next == SyntaxKind::LessThanToken
+    }
+
+    fn next_token_is_open_paren_or_less_than_or_dot(&mut self) -> bool {
+        let next = self.next_token();
+        next == SyntaxKind::OpenParenToken
+            || next == SyntaxKind::LessThanToken
+            || next == SyntaxKind::DotToken
+    }
+
+    fn next_token_is_open_paren(&mut self) -> bool {
+        self.next_token() == SyntaxKind::OpenParenToken
+    }
+
+    fn next_token_is_dot(&mut self) -> bool {
+        self.next_token() == SyntaxKind::DotToken
+    }
+
+    fn next_token_is_start_of_expression(&mut self) -> bool {
+        self.next_token();
+        self.is_start_of_expression()
+    }
+
+    fn next_token_is_function_keyword_on_same_line(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::FunctionKeyword && !self.has_preceding_line_break()
+    }
+
+    fn next_token_is_class_keyword_on_same_line(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::ClassKeyword && !self.has_preceding_line_break()
+    }
+}
+
+fn parsing_context_errors(
+    context: ParsingContext,
+) -> (&'static DiagnosticMessage, &'static [&'static str]) {
+    match context {
+        ParsingContext::SourceElements | ParsingContext::BlockStatements => {
+            (&diagnostics::DECLARATION_OR_STATEMENT_EXPECTED, &[])
+        }
+        ParsingContext::SwitchClauses => (&diagnostics::CASE_OR_DEFAULT_EXPECTED, &[]),
+        ParsingContext::SwitchClauseStatements => (&diagnostics::STATEMENT_EXPECTED, &[]),
+        ParsingContext::TypeMembers => (&diagnostics::PROPERTY_OR_SIGNATURE_EXPECTED, &[]),
+        ParsingContext::ClassMembers => (
+            &diagnostics::UNEXPECTED_TOKEN_A_CONSTRUCTOR_METHOD_ACCESSOR_OR_PROPERTY_WAS_EXPECTED,
+            &[],
+        ),
+        ParsingContext::EnumMembers => (&diagnostics::ENUM_MEMBER_EXPECTED, &[]),
+        ParsingContext::HeritageClauseElement => (&diagnostics::EXPRESSION_EXPECTED, &[]),
+        ParsingContext::VariableDeclarations => (&diagnostics::VARIABLE_DECLARATION_EXPECTED, &[]),
+        ParsingContext::ObjectBindingElements => {
+            (&diagnostics::PROPERTY_DESTRUCTURING_PATTERN_EXPECTED, &[])
+        }
+        ParsingContext::ArrayBindingElements => (
+            &diagnostics::ARRAY_ELEMENT_DESTRUCTURING_PATTERN_EXPECTED,
+            &[],
+        ),
+        ParsingContext::ArgumentExpressions => (&diagnostics::ARGUMENT_EXPRESSION_EXPECTED, &[]),
+        ParsingContext::ObjectLiteralMembers => (&diagnostics::PROPERTY_ASSIGNMENT_EXPECTED, &[]),
+        ParsingContext::ArrayLiteralMembers => (&diagnostics::EXPRESSION_OR_COMMA_EXPECTED, &[]),
+        ParsingContext::Parameters => (&diagnostics::PARAMETER_DECLARATION_EXPECTED, &[]),
+        ParsingContext::TypeParameters => (&diagnostics::TYPE_PARAMETER_DECLARATION_EXPECTED, &[]),
+        ParsingContext::TypeArguments => (&diagnostics::TYPE_ARGUMENT_EXPECTED, &[]),
+        ParsingContext::TupleElementTypes => (&diagnostics::TYPE_EXPECTED, &[]),
+        ParsingContext::HeritageClauses => (&diagnostics::_0_EXPECTED, &["{"]),
+        ParsingContext::ImportOrExportSpecifiers => (&diagnostics::IDENTIFIER_EXPECTED, &[]),
+    }
+}
+
+fn is_modifier_kind(kind: SyntaxKind) -> bool {
+    !modifier_to_flag(kind).is_empty()
+}
+
+fn is_in_or_of_keyword(kind: SyntaxKind) -> bool {
+    kind == SyntaxKind::InKeyword || kind == SyntaxKind::OfKeyword
+}
+
+fn is_class_member_modifier(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::PublicKeyword
+            | SyntaxKind::PrivateKeyword
+            | SyntaxKind::ProtectedKeyword
+            | SyntaxKind::StaticKeyword
+            | SyntaxKind::ReadonlyKeyword
+    )
+}
+
+fn is_left_hand_side_expression_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::PropertyAccessExpression
+            | SyntaxKind::ElementAccessExpression
+            | SyntaxKind::NewExpression
+            | SyntaxKind::CallExpression
+            | SyntaxKind::TaggedTemplateExpression
+            | SyntaxKind::ArrayLiteralExpression
+            | SyntaxKind::ParenthesizedExpression
+            | SyntaxKind::ObjectLiteralExpression
+            | SyntaxKind::ClassExpression
+            | SyntaxKind::FunctionExpression
+            | SyntaxKind::Identifier
+            | SyntaxKind::RegularExpressionLiteral
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NoSubstitutionTemplateLiteral
+            | SyntaxKind::TemplateExpression
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::ThisKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::SuperKeyword
+            | SyntaxKind::NonNullExpression
+            | SyntaxKind::MetaProperty
+            | SyntaxKind::ImportKeyword
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Writes the tree as `(Kind pos end children...)`.
+    fn write_tree(arena: &NodeArena, id: NodeId, out: &mut String) {
+        let node = &arena[id];
+        out.push_str(&format!("({:?} {} {}", node.kind, node.pos, node.end));
+        for child in arena.children(id) {
+            out.push(' ');
+            write_tree(arena, child, out);
+        }
+        out.push(')');
+    }
+
+    // The tree of the first statement.
+    fn statement(text: &str) -> String {
+        let parsed = create_source_file("test.ts", text, ScriptTarget::Latest);
+        assert_eq!(parsed.parse_diagnostics, Vec::new(), "{}", text);
+        let statement = parsed.arena.children(parsed.source_file)[0];
+        let mut out = String::new();
+        write_tree(&parsed.arena, statement, &mut out);
+        out
+    }
+
+    fn diagnostics(text: &str) -> Vec<(u32, usize, usize, String)> {
+        create_source_file("test.ts", text, ScriptTarget::Latest)
+            .parse_diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.code,
+                    diagnostic.start,
+                    diagnostic.length,
+                    diagnostic.message_text,
+                )
+            })
+            .collect()
+    }
+
+    // Checks that every node is reachable from the source file through children whose parent
+    // links point back, so no node of an abandoned speculative parse was left behind.
+    fn assert_parents(parsed: &ParsedSourceFile) {
+        assert_eq!(parsed.arena[parsed.source_file].parent, None);
+        let mut reachable = 0;
+        let mut stack = vec![parsed.source_file];
+        while let Some(id) = stack.pop() {
+            reachable += 1;
+            for child in parsed.arena.children(id) {
+                assert_eq!(parsed.arena[child].parent, Some(id));
+                stack.push(child);
+            }
+        }
+        assert_eq!(reachable, parsed.arena.len());
+    }
+
+    #[test]
+    fn arrow_function_speculation() {
+        assert_eq!(
+            statement("(a, b) => a + b;"),
+            "(ExpressionStatement 0 16 (ArrowFunction 0 15 \
+             (Parameter 1 2 (Identifier 1 2)) (Parameter 3 5 (Identifier 3 5)) \
+             (EqualsGreaterThanToken 6 9) \
+             (BinaryExpression 9 15 (Identifier 9 11) (PlusToken 11 13) (Identifier 13 15))))"
+        );
+        assert_eq!(
+            statement("(a, b) + c;"),
+            "(ExpressionStatement 0 11 (BinaryExpression 0 10 \
+             (ParenthesizedExpression 0 6 \
+             (BinaryExpression 1 5 (Identifier 1 2) (CommaToken 2 3) (Identifier 3 5))) \
+             (PlusToken 6 8) (Identifier 8 10)))"
+        );
+        assert_eq!(
+            statement("(a): number => a;"),
+            "(ExpressionStatement 0 17 (ArrowFunction 0 16 \
+             (Parameter 1 2 (Identifier 1 2)) (NumberKeyword 4 11) \
+             (EqualsGreaterThanToken 11 14) (Identifier 14 16)))"
+        );
+    }
+
+    #[test]
+    fn less_than_and_type_arguments() {
+        assert_eq!(
+            statement("a < b > c;"),
+            "(ExpressionStatement 0 10 (BinaryExpression 0 9 \
+             (BinaryExpression 0 5 (Identifier 0 1) (LessThanToken 1 3) (Identifier 3 5)) \
+             (GreaterThanToken 5 7) (Identifier 7 9)))"
+        );
+        assert_eq!(
+            statement("f<T>(x);"),
+            "(ExpressionStatement 0 8 (CallExpression 0 7 \
+             (Identifier 0 1) (TypeReference 2 3 (Identifier 2 3)) (Identifier 5 6)))"
+        );
+    }
+
+    #[test]
+    fn template_spans() {
+        assert_eq!(
+            statement("`a${b}c${d}e`;"),
+            "(ExpressionStatement 0 14 (TemplateExpression 0 13 (TemplateHead 0 4) \
+             (TemplateSpan 4 9 (Identifier 4 5) (TemplateMiddle 5 9)) \
+             (TemplateSpan 9 13 (Identifier 9 10) (TemplateTail 10 13))))"
+        );
+    }
+
+    #[test]
+    fn automatic_semicolon_insertion() {
+        let parsed = create_source_file("test.ts", "let x = 1\nlet y = 2", ScriptTarget::Latest);
+        assert_eq!(parsed.parse_diagnostics, Vec::new());
+        let statements: Vec<(SyntaxKind, usize, usize)> = parsed
+            .arena
+            .children(parsed.source_file)
+            .into_iter()
+            .map(|id| {
+                (
+                    parsed.arena[id].kind,
+                    parsed.arena[id].pos,
+                    parsed.arena[id].end,
+                )
+            })
+            .collect();
+        assert_eq!(
+            statements,
+            vec![
+                (SyntaxKind::VariableStatement, 0, 9),
+                (SyntaxKind::VariableStatement, 9, 19),
+                (SyntaxKind::EndOfFileToken, 19, 19),
+            ]
+        );
+        assert_eq!(
+            statement("function f() { return\n1 }"),
+            "(FunctionDeclaration 0 25 (Identifier 8 10) (Block 12 25 \
+             (ReturnStatement 14 21) (ExpressionStatement 21 23 (NumericLiteral 21 23))))"
+        );
+        assert_eq!(
+            create_source_file("test.ts", "a\n++b", ScriptTarget::Latest)
+                .arena
+                .iter()
+                .filter(|(_, node)| node.kind == SyntaxKind::ExpressionStatement)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn decorators_and_modifiers() {
+        assert_eq!(
+            statement("@d export abstract class C { private static readonly x = 1; }"),
+            "(ClassDeclaration 0 61 (Decorator 0 2 (Identifier 1 2)) \
+             (ExportKeyword 2 9) (AbstractKeyword 9 18) (Identifier 24 26) \
+             (PropertyDeclaration 28 59 (PrivateKeyword 28 36) (StaticKeyword 36 43) \
+             (ReadonlyKeyword 43 52) (Identifier 52 54) (NumericLiteral 56 58)))"
+        );
+    }
+
+    #[test]
+    fn malformed_input() {
+        assert_eq!(
+            diagnostics("("),
+            vec![(1109, 1, 0, String::from("Expression expected."))]
+        );
+        assert_eq!(
+            diagnostics("x = `a${b}c"),
+            vec![(1160, 11, 0, String::from("Unterminated template literal."))]
+        );
+        assert_eq!(
+            diagnostics("`a${b"),
+            vec![(1005, 5, 0, String::from("'}' expected."))]
+        );
+        let exponentiation = diagnostics("-x ** 2;");
+        assert_eq!(exponentiation.len(), 1);
+        assert_eq!(exponentiation[0].0, 17006);
+        assert_eq!((exponentiation[0].1, exponentiation[0].2), (0, 2));
+    }
+
+    #[test]
+    fn parents_after_rollback() {
+        let mut parser = Parser::new("test.ts", "(a, b) + c;", ScriptTarget::Latest);
+        parser.next_token();
+        let node_count = parser.arena.len();
+        let head =
+            parser.try_parse(Parser::parse_possible_parenthesized_arrow_function_expression_head);
+        assert!(head.is_none());
+        assert_eq!(parser.arena.len(), node_count);
+        assert_eq!(parser.token(), SyntaxKind::OpenParenToken);
+
+        for text in &["(a, b) + c;", "f((a, b) + c, (d) => d);", "a ? (b) : c;"] {
+            assert_parents(&create_source_file("test.ts", text, ScriptTarget::Latest));
+        }
+    }
+}
@@ -1,138 +1,147 @@
-use crate::types::{CharacterCodes, SyntaxKind};
+use crate::compiler::diagnostics;
+use crate::compiler::utilities::create_diagnostic;
+use crate::types::{
+    CharacterCodes, CommentRange, Diagnostic, DiagnosticMessage, LanguageVariant, ScriptTarget,
+    SyntaxKind, TokenFlags,
+};
 use num_traits::FromPrimitive;
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
-const ABSTRACT: &'static str = "abstract";
-const ANY: &'static str = "any";
-const AS: &'static str = "as";
-const BIGINT: &'static str = "bigint";
-const BOOLEAN: &'static str = "boolean";
-const BREAK: &'static str = "break";
-const CASE: &'static str = "case";
-const CATCH: &'static str = "catch";
-const CLASS: &'static str = "class";
-const CONTINUE: &'static str = "continue";
-const CONST: &'static str = "const";
-const CONSTRUCTOR: &'static str = "constructor";
-const DEBUGGER: &'static str = "debugger";
-const DECLARE: &'static str = "declare";
-const DEFAULT: &'static str = "default";
-const DELETE: &'static str = "delete";
-const DO: &'static str = "do";
-const ELSE: &'static str = "else";
-const ENUM: &'static str = "enum";
-const EXPORT: &'static str = "export";
-const EXTENDS: &'static str = "extends";
-const FALSE: &'static str = "false";
-const FINALLY: &'static str = "finally";
-const FOR: &'static str = "for";
-const FROM: &'static str = "from";
-const FUNCTION: &'static str = "function";
-const GET: &'static str = "get";
-const IF: &'static str = "if";
-const IMPLEMENTS: &'static str = "implements";
-const IMPORT: &'static str = "import";
-const IN: &'static str = "in";
-const INFER: &'static str = "infer";
-const INSTANCEOF: &'static str = "instanceof";
-const INTERFACE: &'static str = "interface";
-const IS: &'static str = "is";
-const KEYOF: &'static str = "keyof";
-const LET: &'static str = "let";
-const MODULE: &'static str = "module";
-const NAMESPACE: &'static str = "namespace";
-const NEVER: &'static str = "never";
-const NEW: &'static str = "new";
-const NULL: &'static str = "null";
-const NUMBER: &'static str = "number";
-const OBJECT: &'static str = "object";
-const PACKAGE: &'static str = "package";
-const PRIVATE: &'static str = "private";
-const PROTECTED: &'static str = "protected";
-const PUBLIC: &'static str = "public";
-const READONLY: &'static str = "readonly";
-const REQUIRE: &'static str = "require";
-const GLOBAL: &'static str = "global";
-const RETURN: &'static str = "return";
-const SET: &'static str = "set";
-const STATIC: &'static str = "static";
-const STRING: &'static str = "string";
-const SUPER: &'static str = "super";
-const SWITCH: &'static str = "switch";
-const SYMBOL: &'static str = "symbol";
-const THIS: &'static str = "this";
-const THROW: &'static str = "throw";
-const TRUE: &'static str = "true";
-const TRY: &'static str = "try";
-const TYPE: &'static str = "type";
-const TYPEOF: &'static str = "typeof";
-const UNDEFINED: &'static str = "undefined";
-const UNIQUE: &'static str = "unique";
-const UNKNOWN: &'static str = "unknown";
-const VAR: &'static str = "var";
-const VOID: &'static str = "void";
-const WHILE: &'static str = "while";
-const WITH: &'static str = "with";
-const YIELD: &'static str = "yield";
-const ASYNC: &'static str = "async";
-const AWAIT: &'static str = "await";
-const OF: &'static str = "of";
-const OPEN_BRACE_TOKEN: &'static str = "{";
-const CLOSE_BRACE_TOKEN: &'static str = "}";
-const OPEN_PAREN_TOKEN: &'static str = "(";
-const CLOSE_PAREN_TOKEN: &'static str = ")";
-const OPEN_BRACKET_TOKEN: &'static str = "[";
-const CLOSE_BRACKET_TOKEN: &'static str = "]";
-const DOT_TOKEN: &'static str = ".";
-const DOT_DOT_DOT_TOKEN: &'static str = "...";
-const SEMICOLON_TOKEN: &'static str = ";";
-const COMMA_TOKEN: &'static str = ",";
-const LESS_THAN_TOKEN: &'static str = "<";
-const GREATER_THAN_TOKEN: &'static str = ">";
-const LESS_THAN_EQUALS_TOKEN: &'static str = "<=";
-const GREATER_THAN_EQUALS_TOKEN: &'static str = ">=";
-const EQUALS_EQUALS_TOKEN: &'static str = "==";
-const EXCLAMATION_EQUALS_TOKEN: &'static str = "!=";
-const EQUALS_EQUALS_EQUALS_TOKEN: &'static str = "===";
-const EXCLAMATION_EQUALS_EQUALS_TOKEN: &'static str = "!==";
-const EQUALS_GREATER_THAN_TOKEN: &'static str = "=>";
-const PLUS_TOKEN: &'static str = "+";
-const MINUS_TOKEN: &'static str = "-";
-const ASTERISK_ASTERISK_TOKEN: &'static str = "**";
-const ASTERISK_TOKEN: &'static str = "*";
-const SLASH_TOKEN: &'static str = "/";
-const PERCENT_TOKEN: &'static str = "%";
-const PLUS_PLUS_TOKEN: &'static str = "++";
-const MINUS_MINUS_TOKEN: &'static str = "--";
-const LESS_THAN_LESS_THAN_TOKEN: &'static str = "<<";
-const LESS_THAN_SLASH_TOKEN: &'static str = "</";
-const GREATER_THAN_GREATER_THAN_TOKEN: &'static str = ">>";
-const GREATER_THAN_GREATER_THAN_GREATER_THAN_TOKEN: &'static str = ">>>";
-const AMPERSAND_TOKEN: &'static str = "&";
-const BAR_TOKEN: &'static str = "|";
-const CARET_TOKEN: &'static str = "^";
-const EXCLAMATION_TOKEN: &'static str = "!";
-const TILDE_TOKEN: &'static str = "~";
-const AMPERSAND_AMPERSAND_TOKEN: &'static str = "&&";
-const BAR_BAR_TOKEN: &'static str = "||";
-const QUESTION_TOKEN: &'static str = "?";
-const COLON_TOKEN: &'static str = ":";
-const EQUALS_TOKEN: &'static str = "=";
-const PLUS_EQUALS_TOKEN: &'static str = "+=";
-const MINUS_EQUALS_TOKEN: &'static str = "-=";
-const ASTERISK_EQUALS_TOKEN: &'static str = "*=";
-const ASTERISK_ASTERISK_EQUALS_TOKEN: &'static str = "**=";
-const SLASH_EQUALS_TOKEN: &'static str = "/=";
-const PERCENT_EQUALS_TOKEN: &'static str = "%=";
-const LESS_THAN_LESS_THAN_EQUALS_TOKEN: &'static str = "<<=";
-const GREATER_THAN_GREATER_THAN_EQUALS_TOKEN: &'static str = ">>=";
-const GREATER_THAN_GREATER_THAN_GREATER_THAN_EQUALS_TOKEN: &'static str = ">>>=";
-const AMPERSAND_EQUALS_TOKEN: &'static str = "&=";
-const BAR_EQUALS_TOKEN: &'static str = "|=";
-const CARET_EQUALS_TOKEN: &'static str = "^=";
-const AT_TOKEN: &'static str = "@";
-
-#[wasm_bindgen(js_name = "tokenToString")]
+const ABSTRACT: &str = "abstract";
+const ANY: &str = "any";
+const AS: &str = "as";
+const BIGINT: &str = "bigint";
+const BOOLEAN: &str = "boolean";
+const BREAK: &str = "break";
+const CASE: &str = "case";
+const CATCH: &str = "catch";
+const CLASS: &str = "class";
+const CONTINUE: &str = "continue";
+const CONST: &str = "const";
+const CONSTRUCTOR: &str = "constructor";
+const DEBUGGER: &str = "debugger";
+const DECLARE: &str = "declare";
+const DEFAULT: &str = "default";
+const DELETE: &str = "delete";
+const DO: &str = "do";
+const ELSE: &str = "else";
+const ENUM: &str = "enum";
+const EXPORT: &str = "export";
+const EXTENDS: &str = "extends";
+const FALSE: &str = "false";
+const FINALLY: &str = "finally";
+const FOR: &str = "for";
+const FROM: &str = "from";
+const FUNCTION: &str = "function";
+const GET: &str = "get";
+const IF: &str = "if";
+const IMPLEMENTS: &str = "implements";
+const IMPORT: &str = "import";
+const IN: &str = "in";
+const INFER: &str = "infer";
+const INSTANCEOF: &str = "instanceof";
+const INTERFACE: &str = "interface";
+const IS: &str = "is";
+const KEYOF: &str = "keyof";
+const LET: &str = "let";
+const MODULE: &str = "module";
+const NAMESPACE: &str = "namespace";
+const NEVER: &str = "never";
+const NEW: &str = "new";
+const NULL: &str = "null";
+const NUMBER: &str = "number";
+const OBJECT: &str = "object";
+const PACKAGE: &str = "package";
+const PRIVATE: &str = "private";
+const PROTECTED: &str = "protected";
+const PUBLIC: &str = "public";
+const READONLY: &str = "readonly";
+const REQUIRE: &str = "require";
+const GLOBAL: &str = "global";
+const RETURN: &str = "return";
+const SET: &str = "set";
+const STATIC: &str = "static";
+const STRING: &str = "string";
+const SUPER: &str = "super";
+const SWITCH: &str = "switch";
+const SYMBOL: &str = "symbol";
+const THIS: &str = "this";
+const THROW: &str = "throw";
+const TRUE: &str = "true";
+const TRY: &str = "try";
+const TYPE: &str = "type";
+const TYPEOF: &str = "typeof";
+const UNDEFINED: &str = "undefined";
+const UNIQUE: &str = "unique";
+const UNKNOWN: &str = "unknown";
+const VAR: &str = "var";
+const VOID: &str = "void";
+const WHILE: &str = "while";
+const WITH: &str = "with";
+const YIELD: &str = "yield";
+const ASYNC: &str = "async";
+const AWAIT: &str = "await";
+const OF: &str = "of";
+const OPEN_BRACE_TOKEN: &str = "{";
+const CLOSE_BRACE_TOKEN: &str = "}";
+const OPEN_PAREN_TOKEN: &str = "(";
+const CLOSE_PAREN_TOKEN: &str = ")";
+const OPEN_BRACKET_TOKEN: &str = "[";
+const CLOSE_BRACKET_TOKEN: &str = "]";
+const DOT_TOKEN: &str = ".";
+const DOT_DOT_DOT_TOKEN: &str = "...";
+const SEMICOLON_TOKEN: &str = ";";
+const COMMA_TOKEN: &str = ",";
+const LESS_THAN_TOKEN: &str = "<";
+const GREATER_THAN_TOKEN: &str = ">";
+const LESS_THAN_EQUALS_TOKEN: &str = "<=";
+const GREATER_THAN_EQUALS_TOKEN: &str = ">=";
+const EQUALS_EQUALS_TOKEN: &str = "==";
+const EXCLAMATION_EQUALS_TOKEN: &str = "!=";
+const EQUALS_EQUALS_EQUALS_TOKEN: &str = "===";
+const EXCLAMATION_EQUALS_EQUALS_TOKEN: &str = "!==";
+const EQUALS_GREATER_THAN_TOKEN: &str = "=>";
+const PLUS_TOKEN: &str = "+";
+const MINUS_TOKEN: &str = "-";
+const ASTERISK_ASTERISK_TOKEN: &str = "**";
+const ASTERISK_TOKEN: &str = "*";
+const SLASH_TOKEN: &str = "/";
+const PERCENT_TOKEN: &str = "%";
+const PLUS_PLUS_TOKEN: &str = "++";
+const MINUS_MINUS_TOKEN: &str = "--";
+const LESS_THAN_LESS_THAN_TOKEN: &str = "<<";
+const LESS_THAN_SLASH_TOKEN: &str = "</";
+const GREATER_THAN_GREATER_THAN_TOKEN: &str = ">>";
+const GREATER_THAN_GREATER_THAN_GREATER_THAN_TOKEN: &str = ">>>";
+const AMPERSAND_TOKEN: &str = "&";
+const BAR_TOKEN: &str = "|";
+const CARET_TOKEN: &str = "^";
+const EXCLAMATION_TOKEN: &str = "!";
+const TILDE_TOKEN: &str = "~";
+const AMPERSAND_AMPERSAND_TOKEN: &str = "&&";
+const BAR_BAR_TOKEN: &str = "||";
+const QUESTION_TOKEN: &str = "?";
+const COLON_TOKEN: &str = ":";
+const EQUALS_TOKEN: &str = "=";
+const PLUS_EQUALS_TOKEN: &str = "+=";
+const MINUS_EQUALS_TOKEN: &str = "-=";
+const ASTERISK_EQUALS_TOKEN: &str = "*=";
+const ASTERISK_ASTERISK_EQUALS_TOKEN: &str = "**=";
+const SLASH_EQUALS_TOKEN: &str = "/=";
+const PERCENT_EQUALS_TOKEN: &str = "%=";
+const LESS_THAN_LESS_THAN_EQUALS_TOKEN: &str = "<<=";
+const GREATER_THAN_GREATER_THAN_EQUALS_TOKEN: &str = ">>=";
+const GREATER_THAN_GREATER_THAN_GREATER_THAN_EQUALS_TOKEN: &str = ">>>=";
+const AMPERSAND_EQUALS_TOKEN: &str = "&=";
+const BAR_EQUALS_TOKEN: &str = "|=";
+const CARET_EQUALS_TOKEN: &str = "^=";
+const AT_TOKEN: &str = "@";
+
+// Flags accepted after the closing '/' of a regular expression literal
+const REGULAR_EXPRESSION_FLAGS: &str = "gimsuy";
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "tokenToString"))]
 pub fn token_to_string(t: u32) -> Option<String> {
     FromPrimitive::from_u32(t)
         .map(|t: SyntaxKind| match t {
@@ -278,7 +287,7 @@ pub fn token_to_string(t: u32) -> Option<String> {
 }
 
 /* Does not include line breaks. For that, see isWhiteSpaceLike(). */
-#[wasm_bindgen(js_name = "isWhiteSpaceSingleLine")]
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isWhiteSpaceSingleLine"))]
 pub fn is_white_space_single_line(ch: u32) -> bool {
     // Note: NextLine is in the Zs space, and should be considered to be a whitespace.
     // It is explicitly not a line-break as it isn't in the exact set specified by EcmaScript.
@@ -302,7 +311,7 @@ pub fn is_white_space_single_line(ch: u32) -> bool {
         .unwrap_or_default() // the default of bool is false
 }
 
-#[wasm_bindgen(js_name = "isLineBreak")]
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isLineBreak"))]
 pub fn is_line_break(ch: u32) -> bool {
     // ES5 7.3:
     // The ECMAScript line terminator characters are listed in Table 3.
@@ -325,17 +334,108 @@ pub fn is_line_break(ch: u32) -> bool {
         .unwrap_or_default() // the default of bool is false
 }
 
-#[wasm_bindgen(js_name = "isWhiteSpaceLike")]
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isWhiteSpaceLike"))]
 pub fn is_white_space_like(ch: u32) -> bool {
     is_white_space_single_line(ch) || is_line_break(ch)
 }
 
-#[wasm_bindgen(js_name = "couldStartTrivia")]
-pub fn could_start_trivia(text: &str, pos: usize) -> bool {
-    text.chars()
-        .nth(pos)
+/// The text being scanned, stored as UTF-16 code units so that positions and `char_code_at`
+/// agree exactly with those of the JavaScript string it came from. JavaScript holds on to one
+/// of these to call `skipTrivia` and friends repeatedly without passing the text again.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceText {
+    code_units: Vec<u16>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl SourceText {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(text: &str) -> SourceText {
+        SourceText {
+            code_units: text.encode_utf16().collect(),
+        }
+    }
+
+    /// The length of the text in UTF-16 code units, like `String.prototype.length`.
+    pub fn len(&self) -> usize {
+        self.code_units.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.code_units.is_empty()
+    }
+
+    /// The UTF-16 code unit at `pos`, or `None` where `String.prototype.charCodeAt` gives `NaN`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "charCodeAt"))]
+    pub fn char_code_at(&self, pos: usize) -> Option<u32> {
+        self.code_units.get(pos).map(|&ch| u32::from(ch))
+    }
+
+    /// Like `String.prototype.codePointAt`: the code point starting at `pos`, combining a
+    /// surrogate pair into one value.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "codePointAt"))]
+    pub fn code_point_at(&self, pos: usize) -> Option<u32> {
+        let first = self.char_code_at(pos)?;
+        if (0xD800..=0xDBFF).contains(&first) {
+            if let Some(second) = self.char_code_at(pos + 1) {
+                if (0xDC00..=0xDFFF).contains(&second) {
+                    return Some((first - 0xD800) * 0x400 + second - 0xDC00 + 0x10000);
+                }
+            }
+        }
+        Some(first)
+    }
+
+    /// Like `String.prototype.substring`, except that surrogates split by `start` or `end` are
+    /// replaced by U+FFFD since a Rust `String` cannot hold them.
+    pub fn substring(&self, start: usize, end: usize) -> String {
+        String::from_utf16_lossy(self.slice(start, end))
+    }
+}
+
+impl SourceText {
+    /// The code units between `start` and `end`, both clamped to the text.
+    pub fn slice(&self, start: usize, end: usize) -> &[u16] {
+        let end = end.min(self.len());
+        &self.code_units[start.min(end)..end]
+    }
+}
+
+impl From<&str> for SourceText {
+    fn from(text: &str) -> SourceText {
+        SourceText::new(text)
+    }
+}
+
+impl std::ops::Index<usize> for SourceText {
+    type Output = u16;
+
+    fn index(&self, pos: usize) -> &u16 {
+        &self.code_units[pos]
+    }
+}
+
+impl std::fmt::Display for SourceText {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.substring(0, self.len()))
+    }
+}
+
+/// The number of UTF-16 code units needed to encode `ch`.
+fn char_size(ch: u32) -> usize {
+    if ch >= 0x10000 {
+        2
+    } else {
+        1
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "couldStartTrivia"))]
+pub fn could_start_trivia(text: &SourceText, pos: usize) -> bool {
+    text.char_code_at(pos)
         .map(|ch| {
-            FromPrimitive::from_u32(ch as u32)
+            FromPrimitive::from_u32(ch)
                 .map(|charcode: CharacterCodes| match charcode {
                     CharacterCodes::CarriageReturn |
                     CharacterCodes::LineFeed |
@@ -354,9 +454,4151 @@ pub fn could_start_trivia(text: &str, pos: usize) -> bool {
                     CharacterCodes::Hash =>
                         // Only if its the beginning can we have #! trivia
                         pos == 0,
-                    _ => ch as u32 > CharacterCodes::MaxAsciiCharacter as u32,
+                    _ => ch > CharacterCodes::MaxAsciiCharacter as u32,
                 })
-                .unwrap_or_default()
+                // Most non-ASCII code units, surrogates included, have no named code
+                .unwrap_or(ch > CharacterCodes::MaxAsciiCharacter as u32)
         })
         .unwrap_or_default()
 }
+
+// All conflict markers consist of the same character repeated seven times.  If it is
+// a <<<<<<< or >>>>>>> marker then it is also followed by a space.
+const MERGE_CONFLICT_MARKER_LENGTH: usize = 7; // "<<<<<<<".len()
+
+fn is_conflict_marker_trivia(text: &SourceText, pos: usize) -> bool {
+    // Conflict markers must be at the start of a line.
+    if pos == 0 || is_line_break(text[pos - 1] as u32) {
+        let ch = text[pos];
+
+        if pos + MERGE_CONFLICT_MARKER_LENGTH < text.len() {
+            if text
+                .slice(pos, pos + MERGE_CONFLICT_MARKER_LENGTH)
+                .iter()
+                .any(|&c| c != ch)
+            {
+                return false;
+            }
+
+            return ch == CharacterCodes::Equals as u16
+                || text[pos + MERGE_CONFLICT_MARKER_LENGTH] == CharacterCodes::Space as u16;
+        }
+    }
+
+    false
+}
+
+fn scan_conflict_marker_trivia(text: &SourceText, mut pos: usize) -> usize {
+    let ch = text[pos];
+    let len = text.len();
+
+    if ch == CharacterCodes::LessThan as u16 || ch == CharacterCodes::GreaterThan as u16 {
+        while pos < len && !is_line_break(text[pos] as u32) {
+            pos += 1;
+        }
+    } else {
+        debug_assert!(ch == CharacterCodes::Bar as u16 || ch == CharacterCodes::Equals as u16);
+        // Consume everything from the start of a ||||||| or ======= marker to the start
+        // of the next ======= or >>>>>>> marker.
+        while pos < len {
+            let current_char = text[pos];
+            if (current_char == CharacterCodes::Equals as u16
+                || current_char == CharacterCodes::GreaterThan as u16)
+                && current_char != ch
+                && is_conflict_marker_trivia(text, pos)
+            {
+                break;
+            }
+
+            pos += 1;
+        }
+    }
+
+    pos
+}
+
+// Shebangs are only recognized at the start of a file: `#!` followed by anything but a line break.
+fn is_shebang_trivia(text: &SourceText, pos: usize) -> bool {
+    debug_assert_eq!(pos, 0);
+    text.char_code_at(0) == Some(CharacterCodes::Hash as u32)
+        && text.char_code_at(1) == Some(CharacterCodes::Exclamation as u32)
+}
+
+fn scan_shebang_trivia(text: &SourceText, mut pos: usize) -> usize {
+    while pos < text.len() && !is_line_break(text[pos] as u32) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Invokes a callback for each comment range following the provided position.
+///
+/// Single-line comment ranges include the leading double-slash characters but not the ending
+/// line break. Multi-line comment ranges include the leading slash-asterisk and trailing
+/// asterisk-slash characters.
+///
+/// If `reduce` is true, the result of calling the callback is accumulated in a fashion similar
+/// to a left fold. If false, iteration stops when the callback returns `Some`.
+///
+/// If `trailing` is false, whitespace is skipped until the first line break and comments
+/// between that location and the next token are returned. If true, comments occurring
+/// between the given position and the next line break are returned.
+fn iterate_comment_ranges<U>(
+    reduce: bool,
+    text: &SourceText,
+    mut pos: usize,
+    trailing: bool,
+    mut cb: impl FnMut(CommentRange, Option<U>) -> Option<U>,
+    initial: Option<U>,
+) -> Option<U> {
+    let mut pending: Option<CommentRange> = None;
+    let mut collecting = trailing;
+    let mut accumulator = initial;
+    if pos == 0 {
+        collecting = true;
+        if is_shebang_trivia(text, pos) {
+            pos = scan_shebang_trivia(text, pos);
+        }
+    }
+    while pos < text.len() {
+        let ch = text[pos] as u32;
+        match FromPrimitive::from_u32(ch) {
+            Some(CharacterCodes::CarriageReturn) | Some(CharacterCodes::LineFeed) => {
+                if ch == CharacterCodes::CarriageReturn as u32
+                    && text.char_code_at(pos + 1) == Some(CharacterCodes::LineFeed as u32)
+                {
+                    pos += 1;
+                }
+                pos += 1;
+                if trailing {
+                    break;
+                }
+
+                collecting = true;
+                if let Some(pending) = pending.as_mut() {
+                    pending.has_trailing_new_line = true;
+                }
+            }
+            Some(CharacterCodes::Tab)
+            | Some(CharacterCodes::VerticalTab)
+            | Some(CharacterCodes::FormFeed)
+            | Some(CharacterCodes::Space) => {
+                pos += 1;
+            }
+            Some(CharacterCodes::Slash)
+                if text.char_code_at(pos + 1) == Some(CharacterCodes::Slash as u32)
+                    || text.char_code_at(pos + 1) == Some(CharacterCodes::Asterisk as u32) =>
+            {
+                let is_single_line = text[pos + 1] == CharacterCodes::Slash as u16;
+                let start_pos = pos;
+                let mut has_trailing_new_line = false;
+                pos += 2;
+                if is_single_line {
+                    while pos < text.len() {
+                        if is_line_break(text[pos] as u32) {
+                            has_trailing_new_line = true;
+                            break;
+                        }
+                        pos += 1;
+                    }
+                } else {
+                    while pos < text.len() {
+                        if text[pos] == CharacterCodes::Asterisk as u16
+                            && text.char_code_at(pos + 1) == Some(CharacterCodes::Slash as u32)
+                        {
+                            pos += 2;
+                            break;
+                        }
+                        pos += 1;
+                    }
+                }
+
+                if collecting {
+                    if let Some(pending) = pending {
+                        accumulator = cb(pending, accumulator);
+                        if !reduce && accumulator.is_some() {
+                            // If we are not reducing and we have a result, return it.
+                            return accumulator;
+                        }
+                    }
+
+                    pending = Some(CommentRange {
+                        pos: start_pos,
+                        end: pos,
+                        kind: if is_single_line {
+                            SyntaxKind::SingleLineCommentTrivia
+                        } else {
+                            SyntaxKind::MultiLineCommentTrivia
+                        },
+                        has_trailing_new_line,
+                    });
+                }
+            }
+            _ if ch > CharacterCodes::MaxAsciiCharacter as u32 && is_white_space_like(ch) => {
+                if is_line_break(ch) {
+                    if let Some(pending) = pending.as_mut() {
+                        pending.has_trailing_new_line = true;
+                    }
+                }
+                pos += 1;
+            }
+            _ => break,
+        }
+    }
+
+    if let Some(pending) = pending {
+        accumulator = cb(pending, accumulator);
+    }
+
+    accumulator
+}
+
+pub fn for_each_leading_comment_range<U>(
+    text: &SourceText,
+    pos: usize,
+    cb: impl FnMut(&CommentRange) -> Option<U>,
+) -> Option<U> {
+    for_each_comment_range(text, pos, false, cb)
+}
+
+pub fn for_each_trailing_comment_range<U>(
+    text: &SourceText,
+    pos: usize,
+    cb: impl FnMut(&CommentRange) -> Option<U>,
+) -> Option<U> {
+    for_each_comment_range(text, pos, true, cb)
+}
+
+fn for_each_comment_range<U>(
+    text: &SourceText,
+    pos: usize,
+    trailing: bool,
+    mut cb: impl FnMut(&CommentRange) -> Option<U>,
+) -> Option<U> {
+    iterate_comment_ranges(false, text, pos, trailing, |range, _| cb(&range), None)
+}
+
+pub fn reduce_each_leading_comment_range<U>(
+    text: &SourceText,
+    pos: usize,
+    cb: impl FnMut(&CommentRange, U) -> U,
+    initial: U,
+) -> U {
+    reduce_each_comment_range(text, pos, false, cb, initial)
+}
+
+pub fn reduce_each_trailing_comment_range<U>(
+    text: &SourceText,
+    pos: usize,
+    cb: impl FnMut(&CommentRange, U) -> U,
+    initial: U,
+) -> U {
+    reduce_each_comment_range(text, pos, true, cb, initial)
+}
+
+fn reduce_each_comment_range<U>(
+    text: &SourceText,
+    pos: usize,
+    trailing: bool,
+    mut cb: impl FnMut(&CommentRange, U) -> U,
+    initial: U,
+) -> U {
+    let reduced = iterate_comment_ranges(
+        true,
+        text,
+        pos,
+        trailing,
+        |range, memo| memo.map(|memo| cb(&range, memo)),
+        Some(initial),
+    );
+    // The accumulator only ever goes from Some to Some when reducing
+    reduced.unwrap()
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getLeadingCommentRanges"))]
+pub fn get_leading_comment_ranges(text: &SourceText, pos: usize) -> Vec<CommentRange> {
+    reduce_each_leading_comment_range(text, pos, append_comment_range, Vec::new())
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTrailingCommentRanges"))]
+pub fn get_trailing_comment_ranges(text: &SourceText, pos: usize) -> Vec<CommentRange> {
+    reduce_each_trailing_comment_range(text, pos, append_comment_range, Vec::new())
+}
+
+fn append_comment_range(
+    range: &CommentRange,
+    mut comments: Vec<CommentRange>,
+) -> Vec<CommentRange> {
+    comments.push(*range);
+    comments
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getShebang"))]
+pub fn get_shebang(text: &str) -> Option<String> {
+    // Only the first line can hold a shebang, so there is no need to encode the rest.
+    if !text.starts_with("#!") {
+        return None;
+    }
+    let end = text
+        .find(|ch: char| is_line_break(ch as u32))
+        .unwrap_or(text.len());
+    Some(String::from(&text[..end]))
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "skipTrivia"))]
+pub fn skip_trivia(
+    text: &SourceText,
+    pos: i32,
+    stop_after_line_break: Option<bool>,
+    stop_at_comments: Option<bool>,
+) -> i32 {
+    // Synthesized nodes have negative positions, which are returned as-is
+    if pos < 0 {
+        return pos;
+    }
+
+    skip_trivia_in(
+        text,
+        pos as usize,
+        stop_after_line_break.unwrap_or(false),
+        stop_at_comments.unwrap_or(false),
+    ) as i32
+}
+
+/// Like `skip_trivia`, for Rust callers whose positions are never synthesized.
+pub fn skip_trivia_in(
+    text: &SourceText,
+    mut pos: usize,
+    stop_after_line_break: bool,
+    stop_at_comments: bool,
+) -> usize {
+    // Keep in sync with could_start_trivia
+    loop {
+        let ch = match text.char_code_at(pos) {
+            Some(ch) => ch,
+            None => return pos,
+        };
+        match FromPrimitive::from_u32(ch) {
+            Some(CharacterCodes::CarriageReturn) | Some(CharacterCodes::LineFeed) => {
+                if ch == CharacterCodes::CarriageReturn as u32
+                    && text.char_code_at(pos + 1) == Some(CharacterCodes::LineFeed as u32)
+                {
+                    pos += 1;
+                }
+                pos += 1;
+                if stop_after_line_break {
+                    return pos;
+                }
+                continue;
+            }
+            Some(CharacterCodes::Tab)
+            | Some(CharacterCodes::VerticalTab)
+            | Some(CharacterCodes::FormFeed)
+            | Some(CharacterCodes::Space) => {
+                pos += 1;
+                continue;
+            }
+            Some(CharacterCodes::Slash) if !stop_at_comments => {
+                if text.char_code_at(pos + 1) == Some(CharacterCodes::Slash as u32) {
+                    pos += 2;
+                    while pos < text.len() {
+                        if is_line_break(text[pos] as u32) {
+                            break;
+                        }
+                        pos += 1;
+                    }
+                    continue;
+                }
+                if text.char_code_at(pos + 1) == Some(CharacterCodes::Asterisk as u32) {
+                    pos += 2;
+                    while pos < text.len() {
+                        if text[pos] == CharacterCodes::Asterisk as u16
+                            && text.char_code_at(pos + 1) == Some(CharacterCodes::Slash as u32)
+                        {
+                            pos += 2;
+                            break;
+                        }
+                        pos += 1;
+                    }
+                    continue;
+                }
+            }
+            Some(CharacterCodes::LessThan)
+            | Some(CharacterCodes::Bar)
+            | Some(CharacterCodes::Equals)
+            | Some(CharacterCodes::GreaterThan) => {
+                if is_conflict_marker_trivia(text, pos) {
+                    pos = scan_conflict_marker_trivia(text, pos);
+                    continue;
+                }
+            }
+            Some(CharacterCodes::Hash) => {
+                if pos == 0 && is_shebang_trivia(text, pos) {
+                    pos = scan_shebang_trivia(text, pos);
+                    continue;
+                }
+            }
+            _ => {
+                if ch > CharacterCodes::MaxAsciiCharacter as u32 && is_white_space_like(ch) {
+                    pos += 1;
+                    continue;
+                }
+            }
+        }
+        return pos;
+    }
+}
+
+/// Looks up the keyword or punctuation token spelled by `s`. Candidates are narrowed down by
+/// the length and first byte of `s`, so at most a handful of strings are ever compared.
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "stringToToken"))]
+pub fn string_to_token(s: &str) -> Option<SyntaxKind> {
+    let first = *s.as_bytes().first()?;
+    let candidates: &[(&str, SyntaxKind)] = match (s.len(), first) {
+        (1, b'!') => &[(EXCLAMATION_TOKEN, SyntaxKind::ExclamationToken)],
+        (1, b'%') => &[(PERCENT_TOKEN, SyntaxKind::PercentToken)],
+        (1, b'&') => &[(AMPERSAND_TOKEN, SyntaxKind::AmpersandToken)],
+        (1, b'(') => &[(OPEN_PAREN_TOKEN, SyntaxKind::OpenParenToken)],
+        (1, b')') => &[(CLOSE_PAREN_TOKEN, SyntaxKind::CloseParenToken)],
+        (1, b'*') => &[(ASTERISK_TOKEN, SyntaxKind::AsteriskToken)],
+        (1, b'+') => &[(PLUS_TOKEN, SyntaxKind::PlusToken)],
+        (1, b',') => &[(COMMA_TOKEN, SyntaxKind::CommaToken)],
+        (1, b'-') => &[(MINUS_TOKEN, SyntaxKind::MinusToken)],
+        (1, b'.') => &[(DOT_TOKEN, SyntaxKind::DotToken)],
+        (1, b'/') => &[(SLASH_TOKEN, SyntaxKind::SlashToken)],
+        (1, b':') => &[(COLON_TOKEN, SyntaxKind::ColonToken)],
+        (1, b';') => &[(SEMICOLON_TOKEN, SyntaxKind::SemicolonToken)],
+        (1, b'<') => &[(LESS_THAN_TOKEN, SyntaxKind::LessThanToken)],
+        (1, b'=') => &[(EQUALS_TOKEN, SyntaxKind::EqualsToken)],
+        (1, b'>') => &[(GREATER_THAN_TOKEN, SyntaxKind::GreaterThanToken)],
+        (1, b'?') => &[(QUESTION_TOKEN, SyntaxKind::QuestionToken)],
+        (1, b'@') => &[(AT_TOKEN, SyntaxKind::AtToken)],
+        (1, b'[') => &[(OPEN_BRACKET_TOKEN, SyntaxKind::OpenBracketToken)],
+        (1, b']') => &[(CLOSE_BRACKET_TOKEN, SyntaxKind::CloseBracketToken)],
+        (1, b'^') => &[(CARET_TOKEN, SyntaxKind::CaretToken)],
+        (1, b'{') => &[(OPEN_BRACE_TOKEN, SyntaxKind::OpenBraceToken)],
+        (1, b'|') => &[(BAR_TOKEN, SyntaxKind::BarToken)],
+        (1, b'}') => &[(CLOSE_BRACE_TOKEN, SyntaxKind::CloseBraceToken)],
+        (1, b'~') => &[(TILDE_TOKEN, SyntaxKind::TildeToken)],
+        (2, b'!') => &[(EXCLAMATION_EQUALS_TOKEN, SyntaxKind::ExclamationEqualsToken)],
+        (2, b'%') => &[(PERCENT_EQUALS_TOKEN, SyntaxKind::PercentEqualsToken)],
+        (2, b'&') => &[
+            (
+                AMPERSAND_AMPERSAND_TOKEN,
+                SyntaxKind::AmpersandAmpersandToken,
+            ),
+            (AMPERSAND_EQUALS_TOKEN, SyntaxKind::AmpersandEqualsToken),
+        ],
+        (2, b'*') => &[
+            (ASTERISK_ASTERISK_TOKEN, SyntaxKind::AsteriskAsteriskToken),
+            (ASTERISK_EQUALS_TOKEN, SyntaxKind::AsteriskEqualsToken),
+        ],
+        (2, b'+') => &[
+            (PLUS_PLUS_TOKEN, SyntaxKind::PlusPlusToken),
+            (PLUS_EQUALS_TOKEN, SyntaxKind::PlusEqualsToken),
+        ],
+        (2, b'-') => &[
+            (MINUS_MINUS_TOKEN, SyntaxKind::MinusMinusToken),
+            (MINUS_EQUALS_TOKEN, SyntaxKind::MinusEqualsToken),
+        ],
+        (2, b'/') => &[(SLASH_EQUALS_TOKEN, SyntaxKind::SlashEqualsToken)],
+        (2, b'<') => &[
+            (LESS_THAN_EQUALS_TOKEN, SyntaxKind::LessThanEqualsToken),
+            (LESS_THAN_LESS_THAN_TOKEN, SyntaxKind::LessThanLessThanToken),
+            (LESS_THAN_SLASH_TOKEN, SyntaxKind::LessThanSlashToken),
+        ],
+        (2, b'=') => &[
+            (EQUALS_EQUALS_TOKEN, SyntaxKind::EqualsEqualsToken),
+            (
+                EQUALS_GREATER_THAN_TOKEN,
+                SyntaxKind::EqualsGreaterThanToken,
+            ),
+        ],
+        (2, b'>') => &[
+            (
+                GREATER_THAN_EQUALS_TOKEN,
+                SyntaxKind::GreaterThanEqualsToken,
+            ),
+            (
+                GREATER_THAN_GREATER_THAN_TOKEN,
+                SyntaxKind::GreaterThanGreaterThanToken,
+            ),
+        ],
+        (2, b'^') => &[(CARET_EQUALS_TOKEN, SyntaxKind::CaretEqualsToken)],
+        (2, b'a') => &[(AS, SyntaxKind::AsKeyword)],
+        (2, b'd') => &[(DO, SyntaxKind::DoKeyword)],
+        (2, b'i') => &[
+            (IF, SyntaxKind::IfKeyword),
+            (IN, SyntaxKind::InKeyword),
+            (IS, SyntaxKind::IsKeyword),
+        ],
+        (2, b'o') => &[(OF, SyntaxKind::OfKeyword)],
+        (2, b'|') => &[
+            (BAR_BAR_TOKEN, SyntaxKind::BarBarToken),
+            (BAR_EQUALS_TOKEN, SyntaxKind::BarEqualsToken),
+        ],
+        (3, b'!') => &[(
+            EXCLAMATION_EQUALS_EQUALS_TOKEN,
+            SyntaxKind::ExclamationEqualsEqualsToken,
+        )],
+        (3, b'*') => &[(
+            ASTERISK_ASTERISK_EQUALS_TOKEN,
+            SyntaxKind::AsteriskAsteriskEqualsToken,
+        )],
+        (3, b'.') => &[(DOT_DOT_DOT_TOKEN, SyntaxKind::DotDotDotToken)],
+        (3, b'<') => &[(
+            LESS_THAN_LESS_THAN_EQUALS_TOKEN,
+            SyntaxKind::LessThanLessThanEqualsToken,
+        )],
+        (3, b'=') => &[(
+            EQUALS_EQUALS_EQUALS_TOKEN,
+            SyntaxKind::EqualsEqualsEqualsToken,
+        )],
+        (3, b'>') => &[
+            (
+                GREATER_THAN_GREATER_THAN_GREATER_THAN_TOKEN,
+                SyntaxKind::GreaterThanGreaterThanGreaterThanToken,
+            ),
+            (
+                GREATER_THAN_GREATER_THAN_EQUALS_TOKEN,
+                SyntaxKind::GreaterThanGreaterThanEqualsToken,
+            ),
+        ],
+        (3, b'a') => &[(ANY, SyntaxKind::AnyKeyword)],
+        (3, b'f') => &[(FOR, SyntaxKind::ForKeyword)],
+        (3, b'g') => &[(GET, SyntaxKind::GetKeyword)],
+        (3, b'l') => &[(LET, SyntaxKind::LetKeyword)],
+        (3, b'n') => &[(NEW, SyntaxKind::NewKeyword)],
+        (3, b's') => &[(SET, SyntaxKind::SetKeyword)],
+        (3, b't') => &[(TRY, SyntaxKind::TryKeyword)],
+        (3, b'v') => &[(VAR, SyntaxKind::VarKeyword)],
+        (4, b'>') => &[(
+            GREATER_THAN_GREATER_THAN_GREATER_THAN_EQUALS_TOKEN,
+            SyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken,
+        )],
+        (4, b'c') => &[(CASE, SyntaxKind::CaseKeyword)],
+        (4, b'e') => &[
+            (ELSE, SyntaxKind::ElseKeyword),
+            (ENUM, SyntaxKind::EnumKeyword),
+        ],
+        (4, b'f') => &[(FROM, SyntaxKind::FromKeyword)],
+        (4, b'n') => &[(NULL, SyntaxKind::NullKeyword)],
+        (4, b't') => &[
+            (THIS, SyntaxKind::ThisKeyword),
+            (TRUE, SyntaxKind::TrueKeyword),
+            (TYPE, SyntaxKind::TypeKeyword),
+        ],
+        (4, b'v') => &[(VOID, SyntaxKind::VoidKeyword)],
+        (4, b'w') => &[(WITH, SyntaxKind::WithKeyword)],
+        (5, b'a') => &[
+            (ASYNC, SyntaxKind::AsyncKeyword),
+            (AWAIT, SyntaxKind::AwaitKeyword),
+        ],
+        (5, b'b') => &[(BREAK, SyntaxKind::BreakKeyword)],
+        (5, b'c') => &[
+            (CATCH, SyntaxKind::CatchKeyword),
+            (CLASS, SyntaxKind::ClassKeyword),
+            (CONST, SyntaxKind::ConstKeyword),
+        ],
+        (5, b'f') => &[(FALSE, SyntaxKind::FalseKeyword)],
+        (5, b'i') => &[(INFER, SyntaxKind::InferKeyword)],
+        (5, b'k') => &[(KEYOF, SyntaxKind::KeyOfKeyword)],
+        (5, b'n') => &[(NEVER, SyntaxKind::NeverKeyword)],
+        (5, b's') => &[(SUPER, SyntaxKind::SuperKeyword)],
+        (5, b't') => &[(THROW, SyntaxKind::ThrowKeyword)],
+        (5, b'w') => &[(WHILE, SyntaxKind::WhileKeyword)],
+        (5, b'y') => &[(YIELD, SyntaxKind::YieldKeyword)],
+        (6, b'b') => &[(BIGINT, SyntaxKind::BigIntKeyword)],
+        (6, b'd') => &[(DELETE, SyntaxKind::DeleteKeyword)],
+        (6, b'e') => &[(EXPORT, SyntaxKind::ExportKeyword)],
+        (6, b'g') => &[(GLOBAL, SyntaxKind::GlobalKeyword)],
+        (6, b'i') => &[(IMPORT, SyntaxKind::ImportKeyword)],
+        (6, b'm') => &[(MODULE, SyntaxKind::ModuleKeyword)],
+        (6, b'n') => &[(NUMBER, SyntaxKind::NumberKeyword)],
+        (6, b'o') => &[(OBJECT, SyntaxKind::ObjectKeyword)],
+        (6, b'p') => &[(PUBLIC, SyntaxKind::PublicKeyword)],
+        (6, b'r') => &[(RETURN, SyntaxKind::ReturnKeyword)],
+        (6, b's') => &[
+            (STATIC, SyntaxKind::StaticKeyword),
+            (STRING, SyntaxKind::StringKeyword),
+            (SWITCH, SyntaxKind::SwitchKeyword),
+            (SYMBOL, SyntaxKind::SymbolKeyword),
+        ],
+        (6, b't') => &[(TYPEOF, SyntaxKind::TypeOfKeyword)],
+        (6, b'u') => &[(UNIQUE, SyntaxKind::UniqueKeyword)],
+        (7, b'b') => &[(BOOLEAN, SyntaxKind::BooleanKeyword)],
+        (7, b'd') => &[
+            (DECLARE, SyntaxKind::DeclareKeyword),
+            (DEFAULT, SyntaxKind::DefaultKeyword),
+        ],
+        (7, b'e') => &[(EXTENDS, SyntaxKind::ExtendsKeyword)],
+        (7, b'f') => &[(FINALLY, SyntaxKind::FinallyKeyword)],
+        (7, b'p') => &[
+            (PACKAGE, SyntaxKind::PackageKeyword),
+            (PRIVATE, SyntaxKind::PrivateKeyword),
+        ],
+        (7, b'r') => &[(REQUIRE, SyntaxKind::RequireKeyword)],
+        (7, b'u') => &[(UNKNOWN, SyntaxKind::UnknownKeyword)],
+        (8, b'a') => &[(ABSTRACT, SyntaxKind::AbstractKeyword)],
+        (8, b'c') => &[(CONTINUE, SyntaxKind::ContinueKeyword)],
+        (8, b'd') => &[(DEBUGGER, SyntaxKind::DebuggerKeyword)],
+        (8, b'f') => &[(FUNCTION, SyntaxKind::FunctionKeyword)],
+        (8, b'r') => &[(READONLY, SyntaxKind::ReadonlyKeyword)],
+        (9, b'i') => &[(INTERFACE, SyntaxKind::InterfaceKeyword)],
+        (9, b'n') => &[(NAMESPACE, SyntaxKind::NamespaceKeyword)],
+        (9, b'p') => &[(PROTECTED, SyntaxKind::ProtectedKeyword)],
+        (9, b'u') => &[(UNDEFINED, SyntaxKind::UndefinedKeyword)],
+        (10, b'i') => &[
+            (IMPLEMENTS, SyntaxKind::ImplementsKeyword),
+            (INSTANCEOF, SyntaxKind::InstanceOfKeyword),
+        ],
+        (11, b'c') => &[(CONSTRUCTOR, SyntaxKind::ConstructorKeyword)],
+        _ => return None,
+    };
+    candidates
+        .iter()
+        .find(|&&(text, _)| text == s)
+        .map(|&(_, token)| token)
+}
+
+fn is_digit(ch: u32) -> bool {
+    ch >= CharacterCodes::_0 as u32 && ch <= CharacterCodes::_9 as u32
+}
+
+fn is_octal_digit(ch: u32) -> bool {
+    ch >= CharacterCodes::_0 as u32 && ch <= CharacterCodes::_7 as u32
+}
+
+fn is_hex_digit(ch: u32) -> bool {
+    is_digit(ch)
+        || ch >= CharacterCodes::UppercaseA as u32 && ch <= CharacterCodes::UppercaseF as u32
+        || ch >= CharacterCodes::LowercaseA as u32 && ch <= CharacterCodes::LowercaseF as u32
+}
+
+fn is_code_point(code: f64) -> bool {
+    code <= f64::from(0x0010_FFFF)
+}
+
+/*
+    As per ECMAScript Language Specification 3th Edition, Section 7.6: Identifiers
+    IdentifierStart ::
+        Can contain Unicode 3.0.0 categories:
+        Uppercase letter (Lu),
+        Lowercase letter (Ll),
+        Titlecase letter (Lt),
+        Modifier letter (Lm),
+        Other letter (Lo), or
+        Letter number (Nl).
+    IdentifierPart :: =
+        Can contain IdentifierStart + Unicode 3.0.0 categories:
+        Non-spacing mark (Mn),
+        Combining spacing mark (Mc),
+        Decimal number (Nd), or
+        Connector punctuation (Pc).
+
+    Codepoint ranges for ES3 Identifiers are extracted from the Unicode 3.0.0 specification at:
+    http://www.unicode.org/Public/3.0-Update/UnicodeData-3.0.0.txt
+*/
+const UNICODE_ES3_IDENTIFIER_START: &[u32] = &[
+    170, 170, 181, 181, 186, 186, 192, 214, 216, 246, 248, 543, 546, 563, 592, 685, 688, 696, 699,
+    705, 720, 721, 736, 740, 750, 750, 890, 890, 902, 902, 904, 906, 908, 908, 910, 929, 931, 974,
+    976, 983, 986, 1011, 1024, 1153, 1164, 1220, 1223, 1224, 1227, 1228, 1232, 1269, 1272, 1273,
+    1329, 1366, 1369, 1369, 1377, 1415, 1488, 1514, 1520, 1522, 1569, 1594, 1600, 1610, 1649, 1747,
+    1749, 1749, 1765, 1766, 1786, 1788, 1808, 1808, 1810, 1836, 1920, 1957, 2309, 2361, 2365, 2365,
+    2384, 2384, 2392, 2401, 2437, 2444, 2447, 2448, 2451, 2472, 2474, 2480, 2482, 2482, 2486, 2489,
+    2524, 2525, 2527, 2529, 2544, 2545, 2565, 2570, 2575, 2576, 2579, 2600, 2602, 2608, 2610, 2611,
+    2613, 2614, 2616, 2617, 2649, 2652, 2654, 2654, 2674, 2676, 2693, 2699, 2701, 2701, 2703, 2705,
+    2707, 2728, 2730, 2736, 2738, 2739, 2741, 2745, 2749, 2749, 2768, 2768, 2784, 2784, 2821, 2828,
+    2831, 2832, 2835, 2856, 2858, 2864, 2866, 2867, 2870, 2873, 2877, 2877, 2908, 2909, 2911, 2913,
+    2949, 2954, 2958, 2960, 2962, 2965, 2969, 2970, 2972, 2972, 2974, 2975, 2979, 2980, 2984, 2986,
+    2990, 2997, 2999, 3001, 3077, 3084, 3086, 3088, 3090, 3112, 3114, 3123, 3125, 3129, 3168, 3169,
+    3205, 3212, 3214, 3216, 3218, 3240, 3242, 3251, 3253, 3257, 3294, 3294, 3296, 3297, 3333, 3340,
+    3342, 3344, 3346, 3368, 3370, 3385, 3424, 3425, 3461, 3478, 3482, 3505, 3507, 3515, 3517, 3517,
+    3520, 3526, 3585, 3632, 3634, 3635, 3648, 3654, 3713, 3714, 3716, 3716, 3719, 3720, 3722, 3722,
+    3725, 3725, 3732, 3735, 3737, 3743, 3745, 3747, 3749, 3749, 3751, 3751, 3754, 3755, 3757, 3760,
+    3762, 3763, 3773, 3773, 3776, 3780, 3782, 3782, 3804, 3805, 3840, 3840, 3904, 3911, 3913, 3946,
+    3976, 3979, 4096, 4129, 4131, 4135, 4137, 4138, 4176, 4181, 4256, 4293, 4304, 4342, 4352, 4441,
+    4447, 4514, 4520, 4601, 4608, 4614, 4616, 4678, 4680, 4680, 4682, 4685, 4688, 4694, 4696, 4696,
+    4698, 4701, 4704, 4742, 4744, 4744, 4746, 4749, 4752, 4782, 4784, 4784, 4786, 4789, 4792, 4798,
+    4800, 4800, 4802, 4805, 4808, 4814, 4816, 4822, 4824, 4846, 4848, 4878, 4880, 4880, 4882, 4885,
+    4888, 4894, 4896, 4934, 4936, 4954, 5024, 5108, 5121, 5740, 5743, 5750, 5761, 5786, 5792, 5866,
+    6016, 6067, 6176, 6263, 6272, 6312, 7680, 7835, 7840, 7929, 7936, 7957, 7960, 7965, 7968, 8005,
+    8008, 8013, 8016, 8023, 8025, 8025, 8027, 8027, 8029, 8029, 8031, 8061, 8064, 8116, 8118, 8124,
+    8126, 8126, 8130, 8132, 8134, 8140, 8144, 8147, 8150, 8155, 8160, 8172, 8178, 8180, 8182, 8188,
+    8319, 8319, 8450, 8450, 8455, 8455, 8458, 8467, 8469, 8469, 8473, 8477, 8484, 8484, 8486, 8486,
+    8488, 8488, 8490, 8493, 8495, 8497, 8499, 8505, 8544, 8579, 12293, 12295, 12321, 12329, 12337,
+    12341, 12344, 12346, 12353, 12436, 12445, 12446, 12449, 12538, 12540, 12542, 12549, 12588,
+    12593, 12686, 12704, 12727, 13312, 19893, 19968, 40869, 40960, 42124, 44032, 55203, 63744,
+    64045, 64256, 64262, 64275, 64279, 64285, 64285, 64287, 64296, 64298, 64310, 64312, 64316,
+    64318, 64318, 64320, 64321, 64323, 64324, 64326, 64433, 64467, 64829, 64848, 64911, 64914,
+    64967, 65008, 65019, 65136, 65138, 65140, 65140, 65142, 65276, 65313, 65338, 65345, 65370,
+    65382, 65470, 65474, 65479, 65482, 65487, 65490, 65495, 65498, 65500,
+];
+const UNICODE_ES3_IDENTIFIER_PART: &[u32] = &[
+    170, 170, 181, 181, 186, 186, 192, 214, 216, 246, 248, 543, 546, 563, 592, 685, 688, 696, 699,
+    705, 720, 721, 736, 740, 750, 750, 768, 846, 864, 866, 890, 890, 902, 902, 904, 906, 908, 908,
+    910, 929, 931, 974, 976, 983, 986, 1011, 1024, 1153, 1155, 1158, 1164, 1220, 1223, 1224, 1227,
+    1228, 1232, 1269, 1272, 1273, 1329, 1366, 1369, 1369, 1377, 1415, 1425, 1441, 1443, 1465, 1467,
+    1469, 1471, 1471, 1473, 1474, 1476, 1476, 1488, 1514, 1520, 1522, 1569, 1594, 1600, 1621, 1632,
+    1641, 1648, 1747, 1749, 1756, 1759, 1768, 1770, 1773, 1776, 1788, 1808, 1836, 1840, 1866, 1920,
+    1968, 2305, 2307, 2309, 2361, 2364, 2381, 2384, 2388, 2392, 2403, 2406, 2415, 2433, 2435, 2437,
+    2444, 2447, 2448, 2451, 2472, 2474, 2480, 2482, 2482, 2486, 2489, 2492, 2492, 2494, 2500, 2503,
+    2504, 2507, 2509, 2519, 2519, 2524, 2525, 2527, 2531, 2534, 2545, 2562, 2562, 2565, 2570, 2575,
+    2576, 2579, 2600, 2602, 2608, 2610, 2611, 2613, 2614, 2616, 2617, 2620, 2620, 2622, 2626, 2631,
+    2632, 2635, 2637, 2649, 2652, 2654, 2654, 2662, 2676, 2689, 2691, 2693, 2699, 2701, 2701, 2703,
+    2705, 2707, 2728, 2730, 2736, 2738, 2739, 2741, 2745, 2748, 2757, 2759, 2761, 2763, 2765, 2768,
+    2768, 2784, 2784, 2790, 2799, 2817, 2819, 2821, 2828, 2831, 2832, 2835, 2856, 2858, 2864, 2866,
+    2867, 2870, 2873, 2876, 2883, 2887, 2888, 2891, 2893, 2902, 2903, 2908, 2909, 2911, 2913, 2918,
+    2927, 2946, 2947, 2949, 2954, 2958, 2960, 2962, 2965, 2969, 2970, 2972, 2972, 2974, 2975, 2979,
+    2980, 2984, 2986, 2990, 2997, 2999, 3001, 3006, 3010, 3014, 3016, 3018, 3021, 3031, 3031, 3047,
+    3055, 3073, 3075, 3077, 3084, 3086, 3088, 3090, 3112, 3114, 3123, 3125, 3129, 3134, 3140, 3142,
+    3144, 3146, 3149, 3157, 3158, 3168, 3169, 3174, 3183, 3202, 3203, 3205, 3212, 3214, 3216, 3218,
+    3240, 3242, 3251, 3253, 3257, 3262, 3268, 3270, 3272, 3274, 3277, 3285, 3286, 3294, 3294, 3296,
+    3297, 3302, 3311, 3330, 3331, 3333, 3340, 3342, 3344, 3346, 3368, 3370, 3385, 3390, 3395, 3398,
+    3400, 3402, 3405, 3415, 3415, 3424, 3425, 3430, 3439, 3458, 3459, 3461, 3478, 3482, 3505, 3507,
+    3515, 3517, 3517, 3520, 3526, 3530, 3530, 3535, 3540, 3542, 3542, 3544, 3551, 3570, 3571, 3585,
+    3642, 3648, 3662, 3664, 3673, 3713, 3714, 3716, 3716, 3719, 3720, 3722, 3722, 3725, 3725, 3732,
+    3735, 3737, 3743, 3745, 3747, 3749, 3749, 3751, 3751, 3754, 3755, 3757, 3769, 3771, 3773, 3776,
+    3780, 3782, 3782, 3784, 3789, 3792, 3801, 3804, 3805, 3840, 3840, 3864, 3865, 3872, 3881, 3893,
+    3893, 3895, 3895, 3897, 3897, 3902, 3911, 3913, 3946, 3953, 3972, 3974, 3979, 3984, 3991, 3993,
+    4028, 4038, 4038, 4096, 4129, 4131, 4135, 4137, 4138, 4140, 4146, 4150, 4153, 4160, 4169, 4176,
+    4185, 4256, 4293, 4304, 4342, 4352, 4441, 4447, 4514, 4520, 4601, 4608, 4614, 4616, 4678, 4680,
+    4680, 4682, 4685, 4688, 4694, 4696, 4696, 4698, 4701, 4704, 4742, 4744, 4744, 4746, 4749, 4752,
+    4782, 4784, 4784, 4786, 4789, 4792, 4798, 4800, 4800, 4802, 4805, 4808, 4814, 4816, 4822, 4824,
+    4846, 4848, 4878, 4880, 4880, 4882, 4885, 4888, 4894, 4896, 4934, 4936, 4954, 4969, 4977, 5024,
+    5108, 5121, 5740, 5743, 5750, 5761, 5786, 5792, 5866, 6016, 6099, 6112, 6121, 6160, 6169, 6176,
+    6263, 6272, 6313, 7680, 7835, 7840, 7929, 7936, 7957, 7960, 7965, 7968, 8005, 8008, 8013, 8016,
+    8023, 8025, 8025, 8027, 8027, 8029, 8029, 8031, 8061, 8064, 8116, 8118, 8124, 8126, 8126, 8130,
+    8132, 8134, 8140, 8144, 8147, 8150, 8155, 8160, 8172, 8178, 8180, 8182, 8188, 8255, 8256, 8319,
+    8319, 8400, 8412, 8417, 8417, 8450, 8450, 8455, 8455, 8458, 8467, 8469, 8469, 8473, 8477, 8484,
+    8484, 8486, 8486, 8488, 8488, 8490, 8493, 8495, 8497, 8499, 8505, 8544, 8579, 12293, 12295,
+    12321, 12335, 12337, 12341, 12344, 12346, 12353, 12436, 12441, 12442, 12445, 12446, 12449,
+    12542, 12549, 12588, 12593, 12686, 12704, 12727, 13312, 19893, 19968, 40869, 40960, 42124,
+    44032, 55203, 63744, 64045, 64256, 64262, 64275, 64279, 64285, 64296, 64298, 64310, 64312,
+    64316, 64318, 64318, 64320, 64321, 64323, 64324, 64326, 64433, 64467, 64829, 64848, 64911,
+    64914, 64967, 65008, 65019, 65056, 65059, 65075, 65076, 65101, 65103, 65136, 65138, 65140,
+    65140, 65142, 65276, 65296, 65305, 65313, 65338, 65343, 65343, 65345, 65370, 65381, 65470,
+    65474, 65479, 65482, 65487, 65490, 65495, 65498, 65500,
+];
+
+/*
+    As per ECMAScript Language Specification 5th Edition, Section 7.6: ISyntaxToken Names and Identifiers
+    IdentifierStart ::
+        Can contain Unicode 6.2 categories:
+        Uppercase letter (Lu),
+        Lowercase letter (Ll),
+        Titlecase letter (Lt),
+        Modifier letter (Lm),
+        Other letter (Lo), or
+        Letter number (Nl).
+    IdentifierPart ::
+        Can contain IdentifierStart + Unicode 6.2 categories:
+        Non-spacing mark (Mn),
+        Combining spacing mark (Mc),
+        Decimal number (Nd),
+        Connector punctuation (Pc),
+        <ZWNJ>, or
+        <ZWJ>.
+
+    Codepoint ranges for ES5 Identifiers are extracted from the Unicode 6.2 specification at:
+    http://www.unicode.org/Public/6.2.0/ucd/UnicodeData.txt
+*/
+const UNICODE_ES5_IDENTIFIER_START: &[u32] = &[
+    170, 170, 181, 181, 186, 186, 192, 214, 216, 246, 248, 705, 710, 721, 736, 740, 748, 748, 750,
+    750, 880, 884, 886, 887, 890, 893, 902, 902, 904, 906, 908, 908, 910, 929, 931, 1013, 1015,
+    1153, 1162, 1319, 1329, 1366, 1369, 1369, 1377, 1415, 1488, 1514, 1520, 1522, 1568, 1610, 1646,
+    1647, 1649, 1747, 1749, 1749, 1765, 1766, 1774, 1775, 1786, 1788, 1791, 1791, 1808, 1808, 1810,
+    1839, 1869, 1957, 1969, 1969, 1994, 2026, 2036, 2037, 2042, 2042, 2048, 2069, 2074, 2074, 2084,
+    2084, 2088, 2088, 2112, 2136, 2208, 2208, 2210, 2220, 2308, 2361, 2365, 2365, 2384, 2384, 2392,
+    2401, 2417, 2423, 2425, 2431, 2437, 2444, 2447, 2448, 2451, 2472, 2474, 2480, 2482, 2482, 2486,
+    2489, 2493, 2493, 2510, 2510, 2524, 2525, 2527, 2529, 2544, 2545, 2565, 2570, 2575, 2576, 2579,
+    2600, 2602, 2608, 2610, 2611, 2613, 2614, 2616, 2617, 2649, 2652, 2654, 2654, 2674, 2676, 2693,
+    2701, 2703, 2705, 2707, 2728, 2730, 2736, 2738, 2739, 2741, 2745, 2749, 2749, 2768, 2768, 2784,
+    2785, 2821, 2828, 2831, 2832, 2835, 2856, 2858, 2864, 2866, 2867, 2869, 2873, 2877, 2877, 2908,
+    2909, 2911, 2913, 2929, 2929, 2947, 2947, 2949, 2954, 2958, 2960, 2962, 2965, 2969, 2970, 2972,
+    2972, 2974, 2975, 2979, 2980, 2984, 2986, 2990, 3001, 3024, 3024, 3077, 3084, 3086, 3088, 3090,
+    3112, 3114, 3123, 3125, 3129, 3133, 3133, 3160, 3161, 3168, 3169, 3205, 3212, 3214, 3216, 3218,
+    3240, 3242, 3251, 3253, 3257, 3261, 3261, 3294, 3294, 3296, 3297, 3313, 3314, 3333, 3340, 3342,
+    3344, 3346, 3386, 3389, 3389, 3406, 3406, 3424, 3425, 3450, 3455, 3461, 3478, 3482, 3505, 3507,
+    3515, 3517, 3517, 3520, 3526, 3585, 3632, 3634, 3635, 3648, 3654, 3713, 3714, 3716, 3716, 3719,
+    3720, 3722, 3722, 3725, 3725, 3732, 3735, 3737, 3743, 3745, 3747, 3749, 3749, 3751, 3751, 3754,
+    3755, 3757, 3760, 3762, 3763, 3773, 3773, 3776, 3780, 3782, 3782, 3804, 3807, 3840, 3840, 3904,
+    3911, 3913, 3948, 3976, 3980, 4096, 4138, 4159, 4159, 4176, 4181, 4186, 4189, 4193, 4193, 4197,
+    4198, 4206, 4208, 4213, 4225, 4238, 4238, 4256, 4293, 4295, 4295, 4301, 4301, 4304, 4346, 4348,
+    4680, 4682, 4685, 4688, 4694, 4696, 4696, 4698, 4701, 4704, 4744, 4746, 4749, 4752, 4784, 4786,
+    4789, 4792, 4798, 4800, 4800, 4802, 4805, 4808, 4822, 4824, 4880, 4882, 4885, 4888, 4954, 4992,
+    5007, 5024, 5108, 5121, 5740, 5743, 5759, 5761, 5786, 5792, 5866, 5870, 5872, 5888, 5900, 5902,
+    5905, 5920, 5937, 5952, 5969, 5984, 5996, 5998, 6000, 6016, 6067, 6103, 6103, 6108, 6108, 6176,
+    6263, 6272, 6312, 6314, 6314, 6320, 6389, 6400, 6428, 6480, 6509, 6512, 6516, 6528, 6571, 6593,
+    6599, 6656, 6678, 6688, 6740, 6823, 6823, 6917, 6963, 6981, 6987, 7043, 7072, 7086, 7087, 7098,
+    7141, 7168, 7203, 7245, 7247, 7258, 7293, 7401, 7404, 7406, 7409, 7413, 7414, 7424, 7615, 7680,
+    7957, 7960, 7965, 7968, 8005, 8008, 8013, 8016, 8023, 8025, 8025, 8027, 8027, 8029, 8029, 8031,
+    8061, 8064, 8116, 8118, 8124, 8126, 8126, 8130, 8132, 8134, 8140, 8144, 8147, 8150, 8155, 8160,
+    8172, 8178, 8180, 8182, 8188, 8305, 8305, 8319, 8319, 8336, 8348, 8450, 8450, 8455, 8455, 8458,
+    8467, 8469, 8469, 8473, 8477, 8484, 8484, 8486, 8486, 8488, 8488, 8490, 8493, 8495, 8505, 8508,
+    8511, 8517, 8521, 8526, 8526, 8544, 8584, 11264, 11310, 11312, 11358, 11360, 11492, 11499,
+    11502, 11506, 11507, 11520, 11557, 11559, 11559, 11565, 11565, 11568, 11623, 11631, 11631,
+    11648, 11670, 11680, 11686, 11688, 11694, 11696, 11702, 11704, 11710, 11712, 11718, 11720,
+    11726, 11728, 11734, 11736, 11742, 11823, 11823, 12293, 12295, 12321, 12329, 12337, 12341,
+    12344, 12348, 12353, 12438, 12445, 12447, 12449, 12538, 12540, 12543, 12549, 12589, 12593,
+    12686, 12704, 12730, 12784, 12799, 13312, 19893, 19968, 40908, 40960, 42124, 42192, 42237,
+    42240, 42508, 42512, 42527, 42538, 42539, 42560, 42606, 42623, 42647, 42656, 42735, 42775,
+    42783, 42786, 42888, 42891, 42894, 42896, 42899, 42912, 42922, 43000, 43009, 43011, 43013,
+    43015, 43018, 43020, 43042, 43072, 43123, 43138, 43187, 43250, 43255, 43259, 43259, 43274,
+    43301, 43312, 43334, 43360, 43388, 43396, 43442, 43471, 43471, 43520, 43560, 43584, 43586,
+    43588, 43595, 43616, 43638, 43642, 43642, 43648, 43695, 43697, 43697, 43701, 43702, 43705,
+    43709, 43712, 43712, 43714, 43714, 43739, 43741, 43744, 43754, 43762, 43764, 43777, 43782,
+    43785, 43790, 43793, 43798, 43808, 43814, 43816, 43822, 43968, 44002, 44032, 55203, 55216,
+    55238, 55243, 55291, 63744, 64109, 64112, 64217, 64256, 64262, 64275, 64279, 64285, 64285,
+    64287, 64296, 64298, 64310, 64312, 64316, 64318, 64318, 64320, 64321, 64323, 64324, 64326,
+    64433, 64467, 64829, 64848, 64911, 64914, 64967, 65008, 65019, 65136, 65140, 65142, 65276,
+    65313, 65338, 65345, 65370, 65382, 65470, 65474, 65479, 65482, 65487, 65490, 65495, 65498,
+    65500,
+];
+const UNICODE_ES5_IDENTIFIER_PART: &[u32] = &[
+    170, 170, 181, 181, 186, 186, 192, 214, 216, 246, 248, 705, 710, 721, 736, 740, 748, 748, 750,
+    750, 768, 884, 886, 887, 890, 893, 902, 902, 904, 906, 908, 908, 910, 929, 931, 1013, 1015,
+    1153, 1155, 1159, 1162, 1319, 1329, 1366, 1369, 1369, 1377, 1415, 1425, 1469, 1471, 1471, 1473,
+    1474, 1476, 1477, 1479, 1479, 1488, 1514, 1520, 1522, 1552, 1562, 1568, 1641, 1646, 1747, 1749,
+    1756, 1759, 1768, 1770, 1788, 1791, 1791, 1808, 1866, 1869, 1969, 1984, 2037, 2042, 2042, 2048,
+    2093, 2112, 2139, 2208, 2208, 2210, 2220, 2276, 2302, 2304, 2403, 2406, 2415, 2417, 2423, 2425,
+    2431, 2433, 2435, 2437, 2444, 2447, 2448, 2451, 2472, 2474, 2480, 2482, 2482, 2486, 2489, 2492,
+    2500, 2503, 2504, 2507, 2510, 2519, 2519, 2524, 2525, 2527, 2531, 2534, 2545, 2561, 2563, 2565,
+    2570, 2575, 2576, 2579, 2600, 2602, 2608, 2610, 2611, 2613, 2614, 2616, 2617, 2620, 2620, 2622,
+    2626, 2631, 2632, 2635, 2637, 2641, 2641, 2649, 2652, 2654, 2654, 2662, 2677, 2689, 2691, 2693,
+    2701, 2703, 2705, 2707, 2728, 2730, 2736, 2738, 2739, 2741, 2745, 2748, 2757, 2759, 2761, 2763,
+    2765, 2768, 2768, 2784, 2787, 2790, 2799, 2817, 2819, 2821, 2828, 2831, 2832, 2835, 2856, 2858,
+    2864, 2866, 2867, 2869, 2873, 2876, 2884, 2887, 2888, 2891, 2893, 2902, 2903, 2908, 2909, 2911,
+    2915, 2918, 2927, 2929, 2929, 2946, 2947, 2949, 2954, 2958, 2960, 2962, 2965, 2969, 2970, 2972,
+    2972, 2974, 2975, 2979, 2980, 2984, 2986, 2990, 3001, 3006, 3010, 3014, 3016, 3018, 3021, 3024,
+    3024, 3031, 3031, 3046, 3055, 3073, 3075, 3077, 3084, 3086, 3088, 3090, 3112, 3114, 3123, 3125,
+    3129, 3133, 3140, 3142, 3144, 3146, 3149, 3157, 3158, 3160, 3161, 3168, 3171, 3174, 3183, 3202,
+    3203, 3205, 3212, 3214, 3216, 3218, 3240, 3242, 3251, 3253, 3257, 3260, 3268, 3270, 3272, 3274,
+    3277, 3285, 3286, 3294, 3294, 3296, 3299, 3302, 3311, 3313, 3314, 3330, 3331, 3333, 3340, 3342,
+    3344, 3346, 3386, 3389, 3396, 3398, 3400, 3402, 3406, 3415, 3415, 3424, 3427, 3430, 3439, 3450,
+    3455, 3458, 3459, 3461, 3478, 3482, 3505, 3507, 3515, 3517, 3517, 3520, 3526, 3530, 3530, 3535,
+    3540, 3542, 3542, 3544, 3551, 3570, 3571, 3585, 3642, 3648, 3662, 3664, 3673, 3713, 3714, 3716,
+    3716, 3719, 3720, 3722, 3722, 3725, 3725, 3732, 3735, 3737, 3743, 3745, 3747, 3749, 3749, 3751,
+    3751, 3754, 3755, 3757, 3769, 3771, 3773, 3776, 3780, 3782, 3782, 3784, 3789, 3792, 3801, 3804,
+    3807, 3840, 3840, 3864, 3865, 3872, 3881, 3893, 3893, 3895, 3895, 3897, 3897, 3902, 3911, 3913,
+    3948, 3953, 3972, 3974, 3991, 3993, 4028, 4038, 4038, 4096, 4169, 4176, 4253, 4256, 4293, 4295,
+    4295, 4301, 4301, 4304, 4346, 4348, 4680, 4682, 4685, 4688, 4694, 4696, 4696, 4698, 4701, 4704,
+    4744, 4746, 4749, 4752, 4784, 4786, 4789, 4792, 4798, 4800, 4800, 4802, 4805, 4808, 4822, 4824,
+    4880, 4882, 4885, 4888, 4954, 4957, 4959, 4992, 5007, 5024, 5108, 5121, 5740, 5743, 5759, 5761,
+    5786, 5792, 5866, 5870, 5872, 5888, 5900, 5902, 5908, 5920, 5940, 5952, 5971, 5984, 5996, 5998,
+    6000, 6002, 6003, 6016, 6099, 6103, 6103, 6108, 6109, 6112, 6121, 6155, 6157, 6160, 6169, 6176,
+    6263, 6272, 6314, 6320, 6389, 6400, 6428, 6432, 6443, 6448, 6459, 6470, 6509, 6512, 6516, 6528,
+    6571, 6576, 6601, 6608, 6617, 6656, 6683, 6688, 6750, 6752, 6780, 6783, 6793, 6800, 6809, 6823,
+    6823, 6912, 6987, 6992, 7001, 7019, 7027, 7040, 7155, 7168, 7223, 7232, 7241, 7245, 7293, 7376,
+    7378, 7380, 7414, 7424, 7654, 7676, 7957, 7960, 7965, 7968, 8005, 8008, 8013, 8016, 8023, 8025,
+    8025, 8027, 8027, 8029, 8029, 8031, 8061, 8064, 8116, 8118, 8124, 8126, 8126, 8130, 8132, 8134,
+    8140, 8144, 8147, 8150, 8155, 8160, 8172, 8178, 8180, 8182, 8188, 8204, 8205, 8255, 8256, 8276,
+    8276, 8305, 8305, 8319, 8319, 8336, 8348, 8400, 8412, 8417, 8417, 8421, 8432, 8450, 8450, 8455,
+    8455, 8458, 8467, 8469, 8469, 8473, 8477, 8484, 8484, 8486, 8486, 8488, 8488, 8490, 8493, 8495,
+    8505, 8508, 8511, 8517, 8521, 8526, 8526, 8544, 8584, 11264, 11310, 11312, 11358, 11360, 11492,
+    11499, 11507, 11520, 11557, 11559, 11559, 11565, 11565, 11568, 11623, 11631, 11631, 11647,
+    11670, 11680, 11686, 11688, 11694, 11696, 11702, 11704, 11710, 11712, 11718, 11720, 11726,
+    11728, 11734, 11736, 11742, 11744, 11775, 11823, 11823, 12293, 12295, 12321, 12335, 12337,
+    12341, 12344, 12348, 12353, 12438, 12441, 12442, 12445, 12447, 12449, 12538, 12540, 12543,
+    12549, 12589, 12593, 12686, 12704, 12730, 12784, 12799, 13312, 19893, 19968, 40908, 40960,
+    42124, 42192, 42237, 42240, 42508, 42512, 42539, 42560, 42607, 42612, 42621, 42623, 42647,
+    42655, 42737, 42775, 42783, 42786, 42888, 42891, 42894, 42896, 42899, 42912, 42922, 43000,
+    43047, 43072, 43123, 43136, 43204, 43216, 43225, 43232, 43255, 43259, 43259, 43264, 43309,
+    43312, 43347, 43360, 43388, 43392, 43456, 43471, 43481, 43520, 43574, 43584, 43597, 43600,
+    43609, 43616, 43638, 43642, 43643, 43648, 43714, 43739, 43741, 43744, 43759, 43762, 43766,
+    43777, 43782, 43785, 43790, 43793, 43798, 43808, 43814, 43816, 43822, 43968, 44010, 44012,
+    44013, 44016, 44025, 44032, 55203, 55216, 55238, 55243, 55291, 63744, 64109, 64112, 64217,
+    64256, 64262, 64275, 64279, 64285, 64296, 64298, 64310, 64312, 64316, 64318, 64318, 64320,
+    64321, 64323, 64324, 64326, 64433, 64467, 64829, 64848, 64911, 64914, 64967, 65008, 65019,
+    65024, 65039, 65056, 65062, 65075, 65076, 65101, 65103, 65136, 65140, 65142, 65276, 65296,
+    65305, 65313, 65338, 65343, 65343, 65345, 65370, 65382, 65470, 65474, 65479, 65482, 65487,
+    65490, 65495, 65498, 65500,
+];
+
+/*
+    As per ECMAScript Language Specification 2015 and later, Section 11.6: Names and Keywords
+    IdentifierStart ::
+        Can contain code points with the Unicode property ID_Start.
+    IdentifierPart ::
+        Can contain code points with the Unicode property ID_Continue, <ZWNJ>, or <ZWJ>.
+
+    Codepoint ranges for ESNext Identifiers are generated from the Unicode 14.0 derived core
+    properties at:
+    https://www.unicode.org/Public/14.0.0/ucd/DerivedCoreProperties.txt
+*/
+const UNICODE_ESNEXT_IDENTIFIER_START: &[u32] = &[
+    170, 170, 181, 181, 186, 186, 192, 214, 216, 246, 248, 705, 710, 721, 736, 740, 748, 748, 750,
+    750, 880, 884, 886, 887, 890, 893, 895, 895, 902, 902, 904, 906, 908, 908, 910, 929, 931, 1013,
+    1015, 1153, 1162, 1327, 1329, 1366, 1369, 1369, 1376, 1416, 1488, 1514, 1519, 1522, 1568, 1610,
+    1646, 1647, 1649, 1747, 1749, 1749, 1765, 1766, 1774, 1775, 1786, 1788, 1791, 1791, 1808, 1808,
+    1810, 1839, 1869, 1957, 1969, 1969, 1994, 2026, 2036, 2037, 2042, 2042, 2048, 2069, 2074, 2074,
+    2084, 2084, 2088, 2088, 2112, 2136, 2144, 2154, 2160, 2183, 2185, 2190, 2208, 2249, 2308, 2361,
+    2365, 2365, 2384, 2384, 2392, 2401, 2417, 2432, 2437, 2444, 2447, 2448, 2451, 2472, 2474, 2480,
+    2482, 2482, 2486, 2489, 2493, 2493, 2510, 2510, 2524, 2525, 2527, 2529, 2544, 2545, 2556, 2556,
+    2565, 2570, 2575, 2576, 2579, 2600, 2602, 2608, 2610, 2611, 2613, 2614, 2616, 2617, 2649, 2652,
+    2654, 2654, 2674, 2676, 2693, 2701, 2703, 2705, 2707, 2728, 2730, 2736, 2738, 2739, 2741, 2745,
+    2749, 2749, 2768, 2768, 2784, 2785, 2809, 2809, 2821, 2828, 2831, 2832, 2835, 2856, 2858, 2864,
+    2866, 2867, 2869, 2873, 2877, 2877, 2908, 2909, 2911, 2913, 2929, 2929, 2947, 2947, 2949, 2954,
+    2958, 2960, 2962, 2965, 2969, 2970, 2972, 2972, 2974, 2975, 2979, 2980, 2984, 2986, 2990, 3001,
+    3024, 3024, 3077, 3084, 3086, 3088, 3090, 3112, 3114, 3129, 3133, 3133, 3160, 3162, 3165, 3165,
+    3168, 3169, 3200, 3200, 3205, 3212, 3214, 3216, 3218, 3240, 3242, 3251, 3253, 3257, 3261, 3261,
+    3293, 3294, 3296, 3297, 3313, 3314, 3332, 3340, 3342, 3344, 3346, 3386, 3389, 3389, 3406, 3406,
+    3412, 3414, 3423, 3425, 3450, 3455, 3461, 3478, 3482, 3505, 3507, 3515, 3517, 3517, 3520, 3526,
+    3585, 3632, 3634, 3635, 3648, 3654, 3713, 3714, 3716, 3716, 3718, 3722, 3724, 3747, 3749, 3749,
+    3751, 3760, 3762, 3763, 3773, 3773, 3776, 3780, 3782, 3782, 3804, 3807, 3840, 3840, 3904, 3911,
+    3913, 3948, 3976, 3980, 4096, 4138, 4159, 4159, 4176, 4181, 4186, 4189, 4193, 4193, 4197, 4198,
+    4206, 4208, 4213, 4225, 4238, 4238, 4256, 4293, 4295, 4295, 4301, 4301, 4304, 4346, 4348, 4680,
+    4682, 4685, 4688, 4694, 4696, 4696, 4698, 4701, 4704, 4744, 4746, 4749, 4752, 4784, 4786, 4789,
+    4792, 4798, 4800, 4800, 4802, 4805, 4808, 4822, 4824, 4880, 4882, 4885, 4888, 4954, 4992, 5007,
+    5024, 5109, 5112, 5117, 5121, 5740, 5743, 5759, 5761, 5786, 5792, 5866, 5870, 5880, 5888, 5905,
+    5919, 5937, 5952, 5969, 5984, 5996, 5998, 6000, 6016, 6067, 6103, 6103, 6108, 6108, 6176, 6264,
+    6272, 6312, 6314, 6314, 6320, 6389, 6400, 6430, 6480, 6509, 6512, 6516, 6528, 6571, 6576, 6601,
+    6656, 6678, 6688, 6740, 6823, 6823, 6917, 6963, 6981, 6988, 7043, 7072, 7086, 7087, 7098, 7141,
+    7168, 7203, 7245, 7247, 7258, 7293, 7296, 7304, 7312, 7354, 7357, 7359, 7401, 7404, 7406, 7411,
+    7413, 7414, 7418, 7418, 7424, 7615, 7680, 7957, 7960, 7965, 7968, 8005, 8008, 8013, 8016, 8023,
+    8025, 8025, 8027, 8027, 8029, 8029, 8031, 8061, 8064, 8116, 8118, 8124, 8126, 8126, 8130, 8132,
+    8134, 8140, 8144, 8147, 8150, 8155, 8160, 8172, 8178, 8180, 8182, 8188, 8305, 8305, 8319, 8319,
+    8336, 8348, 8450, 8450, 8455, 8455, 8458, 8467, 8469, 8469, 8472, 8477, 8484, 8484, 8486, 8486,
+    8488, 8488, 8490, 8505, 8508, 8511, 8517, 8521, 8526, 8526, 8544, 8584, 11264, 11492, 11499,
+    11502, 11506, 11507, 11520, 11557, 11559, 11559, 11565, 11565, 11568, 11623, 11631, 11631,
+    11648, 11670, 11680, 11686, 11688, 11694, 11696, 11702, 11704, 11710, 11712, 11718, 11720,
+    11726, 11728, 11734, 11736, 11742, 12293, 12295, 12321, 12329, 12337, 12341, 12344, 12348,
+    12353, 12438, 12443, 12447, 12449, 12538, 12540, 12543, 12549, 12591, 12593, 12686, 12704,
+    12735, 12784, 12799, 13312, 19903, 19968, 42124, 42192, 42237, 42240, 42508, 42512, 42527,
+    42538, 42539, 42560, 42606, 42623, 42653, 42656, 42735, 42775, 42783, 42786, 42888, 42891,
+    42954, 42960, 42961, 42963, 42963, 42965, 42969, 42994, 43009, 43011, 43013, 43015, 43018,
+    43020, 43042, 43072, 43123, 43138, 43187, 43250, 43255, 43259, 43259, 43261, 43262, 43274,
+    43301, 43312, 43334, 43360, 43388, 43396, 43442, 43471, 43471, 43488, 43492, 43494, 43503,
+    43514, 43518, 43520, 43560, 43584, 43586, 43588, 43595, 43616, 43638, 43642, 43642, 43646,
+    43695, 43697, 43697, 43701, 43702, 43705, 43709, 43712, 43712, 43714, 43714, 43739, 43741,
+    43744, 43754, 43762, 43764, 43777, 43782, 43785, 43790, 43793, 43798, 43808, 43814, 43816,
+    43822, 43824, 43866, 43868, 43881, 43888, 44002, 44032, 55203, 55216, 55238, 55243, 55291,
+    63744, 64109, 64112, 64217, 64256, 64262, 64275, 64279, 64285, 64285, 64287, 64296, 64298,
+    64310, 64312, 64316, 64318, 64318, 64320, 64321, 64323, 64324, 64326, 64433, 64467, 64829,
+    64848, 64911, 64914, 64967, 65008, 65019, 65136, 65140, 65142, 65276, 65313, 65338, 65345,
+    65370, 65382, 65470, 65474, 65479, 65482, 65487, 65490, 65495, 65498, 65500, 65536, 65547,
+    65549, 65574, 65576, 65594, 65596, 65597, 65599, 65613, 65616, 65629, 65664, 65786, 65856,
+    65908, 66176, 66204, 66208, 66256, 66304, 66335, 66349, 66378, 66384, 66421, 66432, 66461,
+    66464, 66499, 66504, 66511, 66513, 66517, 66560, 66717, 66736, 66771, 66776, 66811, 66816,
+    66855, 66864, 66915, 66928, 66938, 66940, 66954, 66956, 66962, 66964, 66965, 66967, 66977,
+    66979, 66993, 66995, 67001, 67003, 67004, 67072, 67382, 67392, 67413, 67424, 67431, 67456,
+    67461, 67463, 67504, 67506, 67514, 67584, 67589, 67592, 67592, 67594, 67637, 67639, 67640,
+    67644, 67644, 67647, 67669, 67680, 67702, 67712, 67742, 67808, 67826, 67828, 67829, 67840,
+    67861, 67872, 67897, 67968, 68023, 68030, 68031, 68096, 68096, 68112, 68115, 68117, 68119,
+    68121, 68149, 68192, 68220, 68224, 68252, 68288, 68295, 68297, 68324, 68352, 68405, 68416,
+    68437, 68448, 68466, 68480, 68497, 68608, 68680, 68736, 68786, 68800, 68850, 68864, 68899,
+    69248, 69289, 69296, 69297, 69376, 69404, 69415, 69415, 69424, 69445, 69488, 69505, 69552,
+    69572, 69600, 69622, 69635, 69687, 69745, 69746, 69749, 69749, 69763, 69807, 69840, 69864,
+    69891, 69926, 69956, 69956, 69959, 69959, 69968, 70002, 70006, 70006, 70019, 70066, 70081,
+    70084, 70106, 70106, 70108, 70108, 70144, 70161, 70163, 70187, 70272, 70278, 70280, 70280,
+    70282, 70285, 70287, 70301, 70303, 70312, 70320, 70366, 70405, 70412, 70415, 70416, 70419,
+    70440, 70442, 70448, 70450, 70451, 70453, 70457, 70461, 70461, 70480, 70480, 70493, 70497,
+    70656, 70708, 70727, 70730, 70751, 70753, 70784, 70831, 70852, 70853, 70855, 70855, 71040,
+    71086, 71128, 71131, 71168, 71215, 71236, 71236, 71296, 71338, 71352, 71352, 71424, 71450,
+    71488, 71494, 71680, 71723, 71840, 71903, 71935, 71942, 71945, 71945, 71948, 71955, 71957,
+    71958, 71960, 71983, 71999, 71999, 72001, 72001, 72096, 72103, 72106, 72144, 72161, 72161,
+    72163, 72163, 72192, 72192, 72203, 72242, 72250, 72250, 72272, 72272, 72284, 72329, 72349,
+    72349, 72368, 72440, 72704, 72712, 72714, 72750, 72768, 72768, 72818, 72847, 72960, 72966,
+    72968, 72969, 72971, 73008, 73030, 73030, 73056, 73061, 73063, 73064, 73066, 73097, 73112,
+    73112, 73440, 73458, 73648, 73648, 73728, 74649, 74752, 74862, 74880, 75075, 77712, 77808,
+    77824, 78894, 82944, 83526, 92160, 92728, 92736, 92766, 92784, 92862, 92880, 92909, 92928,
+    92975, 92992, 92995, 93027, 93047, 93053, 93071, 93760, 93823, 93952, 94026, 94032, 94032,
+    94099, 94111, 94176, 94177, 94179, 94179, 94208, 100343, 100352, 101589, 101632, 101640,
+    110576, 110579, 110581, 110587, 110589, 110590, 110592, 110882, 110928, 110930, 110948, 110951,
+    110960, 111355, 113664, 113770, 113776, 113788, 113792, 113800, 113808, 113817, 119808, 119892,
+    119894, 119964, 119966, 119967, 119970, 119970, 119973, 119974, 119977, 119980, 119982, 119993,
+    119995, 119995, 119997, 120003, 120005, 120069, 120071, 120074, 120077, 120084, 120086, 120092,
+    120094, 120121, 120123, 120126, 120128, 120132, 120134, 120134, 120138, 120144, 120146, 120485,
+    120488, 120512, 120514, 120538, 120540, 120570, 120572, 120596, 120598, 120628, 120630, 120654,
+    120656, 120686, 120688, 120712, 120714, 120744, 120746, 120770, 120772, 120779, 122624, 122654,
+    123136, 123180, 123191, 123197, 123214, 123214, 123536, 123565, 123584, 123627, 124896, 124902,
+    124904, 124907, 124909, 124910, 124912, 124926, 124928, 125124, 125184, 125251, 125259, 125259,
+    126464, 126467, 126469, 126495, 126497, 126498, 126500, 126500, 126503, 126503, 126505, 126514,
+    126516, 126519, 126521, 126521, 126523, 126523, 126530, 126530, 126535, 126535, 126537, 126537,
+    126539, 126539, 126541, 126543, 126545, 126546, 126548, 126548, 126551, 126551, 126553, 126553,
+    126555, 126555, 126557, 126557, 126559, 126559, 126561, 126562, 126564, 126564, 126567, 126570,
+    126572, 126578, 126580, 126583, 126585, 126588, 126590, 126590, 126592, 126601, 126603, 126619,
+    126625, 126627, 126629, 126633, 126635, 126651, 131072, 173791, 173824, 177976, 177984, 178205,
+    178208, 183969, 183984, 191456, 194560, 195101, 196608, 201546,
+];
+const UNICODE_ESNEXT_IDENTIFIER_PART: &[u32] = &[
+    170, 170, 181, 181, 183, 183, 186, 186, 192, 214, 216, 246, 248, 705, 710, 721, 736, 740, 748,
+    748, 750, 750, 768, 884, 886, 887, 890, 893, 895, 895, 902, 906, 908, 908, 910, 929, 931, 1013,
+    1015, 1153, 1155, 1159, 1162, 1327, 1329, 1366, 1369, 1369, 1376, 1416, 1425, 1469, 1471, 1471,
+    1473, 1474, 1476, 1477, 1479, 1479, 1488, 1514, 1519, 1522, 1552, 1562, 1568, 1641, 1646, 1747,
+    1749, 1756, 1759, 1768, 1770, 1788, 1791, 1791, 1808, 1866, 1869, 1969, 1984, 2037, 2042, 2042,
+    2045, 2045, 2048, 2093, 2112, 2139, 2144, 2154, 2160, 2183, 2185, 2190, 2200, 2273, 2275, 2403,
+    2406, 2415, 2417, 2435, 2437, 2444, 2447, 2448, 2451, 2472, 2474, 2480, 2482, 2482, 2486, 2489,
+    2492, 2500, 2503, 2504, 2507, 2510, 2519, 2519, 2524, 2525, 2527, 2531, 2534, 2545, 2556, 2556,
+    2558, 2558, 2561, 2563, 2565, 2570, 2575, 2576, 2579, 2600, 2602, 2608, 2610, 2611, 2613, 2614,
+    2616, 2617, 2620, 2620, 2622, 2626, 2631, 2632, 2635, 2637, 2641, 2641, 2649, 2652, 2654, 2654,
+    2662, 2677, 2689, 2691, 2693, 2701, 2703, 2705, 2707, 2728, 2730, 2736, 2738, 2739, 2741, 2745,
+    2748, 2757, 2759, 2761, 2763, 2765, 2768, 2768, 2784, 2787, 2790, 2799, 2809, 2815, 2817, 2819,
+    2821, 2828, 2831, 2832, 2835, 2856, 2858, 2864, 2866, 2867, 2869, 2873, 2876, 2884, 2887, 2888,
+    2891, 2893, 2901, 2903, 2908, 2909, 2911, 2915, 2918, 2927, 2929, 2929, 2946, 2947, 2949, 2954,
+    2958, 2960, 2962, 2965, 2969, 2970, 2972, 2972, 2974, 2975, 2979, 2980, 2984, 2986, 2990, 3001,
+    3006, 3010, 3014, 3016, 3018, 3021, 3024, 3024, 3031, 3031, 3046, 3055, 3072, 3084, 3086, 3088,
+    3090, 3112, 3114, 3129, 3132, 3140, 3142, 3144, 3146, 3149, 3157, 3158, 3160, 3162, 3165, 3165,
+    3168, 3171, 3174, 3183, 3200, 3203, 3205, 3212, 3214, 3216, 3218, 3240, 3242, 3251, 3253, 3257,
+    3260, 3268, 3270, 3272, 3274, 3277, 3285, 3286, 3293, 3294, 3296, 3299, 3302, 3311, 3313, 3314,
+    3328, 3340, 3342, 3344, 3346, 3396, 3398, 3400, 3402, 3406, 3412, 3415, 3423, 3427, 3430, 3439,
+    3450, 3455, 3457, 3459, 3461, 3478, 3482, 3505, 3507, 3515, 3517, 3517, 3520, 3526, 3530, 3530,
+    3535, 3540, 3542, 3542, 3544, 3551, 3558, 3567, 3570, 3571, 3585, 3642, 3648, 3662, 3664, 3673,
+    3713, 3714, 3716, 3716, 3718, 3722, 3724, 3747, 3749, 3749, 3751, 3773, 3776, 3780, 3782, 3782,
+    3784, 3789, 3792, 3801, 3804, 3807, 3840, 3840, 3864, 3865, 3872, 3881, 3893, 3893, 3895, 3895,
+    3897, 3897, 3902, 3911, 3913, 3948, 3953, 3972, 3974, 3991, 3993, 4028, 4038, 4038, 4096, 4169,
+    4176, 4253, 4256, 4293, 4295, 4295, 4301, 4301, 4304, 4346, 4348, 4680, 4682, 4685, 4688, 4694,
+    4696, 4696, 4698, 4701, 4704, 4744, 4746, 4749, 4752, 4784, 4786, 4789, 4792, 4798, 4800, 4800,
+    4802, 4805, 4808, 4822, 4824, 4880, 4882, 4885, 4888, 4954, 4957, 4959, 4969, 4977, 4992, 5007,
+    5024, 5109, 5112, 5117, 5121, 5740, 5743, 5759, 5761, 5786, 5792, 5866, 5870, 5880, 5888, 5909,
+    5919, 5940, 5952, 5971, 5984, 5996, 5998, 6000, 6002, 6003, 6016, 6099, 6103, 6103, 6108, 6109,
+    6112, 6121, 6155, 6157, 6159, 6169, 6176, 6264, 6272, 6314, 6320, 6389, 6400, 6430, 6432, 6443,
+    6448, 6459, 6470, 6509, 6512, 6516, 6528, 6571, 6576, 6601, 6608, 6618, 6656, 6683, 6688, 6750,
+    6752, 6780, 6783, 6793, 6800, 6809, 6823, 6823, 6832, 6845, 6847, 6862, 6912, 6988, 6992, 7001,
+    7019, 7027, 7040, 7155, 7168, 7223, 7232, 7241, 7245, 7293, 7296, 7304, 7312, 7354, 7357, 7359,
+    7376, 7378, 7380, 7418, 7424, 7957, 7960, 7965, 7968, 8005, 8008, 8013, 8016, 8023, 8025, 8025,
+    8027, 8027, 8029, 8029, 8031, 8061, 8064, 8116, 8118, 8124, 8126, 8126, 8130, 8132, 8134, 8140,
+    8144, 8147, 8150, 8155, 8160, 8172, 8178, 8180, 8182, 8188, 8204, 8205, 8255, 8256, 8276, 8276,
+    8305, 8305, 8319, 8319, 8336, 8348, 8400, 8412, 8417, 8417, 8421, 8432, 8450, 8450, 8455, 8455,
+    8458, 8467, 8469, 8469, 8472, 8477, 8484, 8484, 8486, 8486, 8488, 8488, 8490, 8505, 8508, 8511,
+    8517, 8521, 8526, 8526, 8544, 8584, 11264, 11492, 11499, 11507, 11520, 11557, 11559, 11559,
+    11565, 11565, 11568, 11623, 11631, 11631, 11647, 11670, 11680, 11686, 11688, 11694, 11696,
+    11702, 11704, 11710, 11712, 11718, 11720, 11726, 11728, 11734, 11736, 11742, 11744, 11775,
+    12293, 12295, 12321, 12335, 12337, 12341, 12344, 12348, 12353, 12438, 12441, 12447, 12449,
+    12538, 12540, 12543, 12549, 12591, 12593, 12686, 12704, 12735, 12784, 12799, 13312, 19903,
+    19968, 42124, 42192, 42237, 42240, 42508, 42512, 42539, 42560, 42607, 42612, 42621, 42623,
+    42737, 42775, 42783, 42786, 42888, 42891, 42954, 42960, 42961, 42963, 42963, 42965, 42969,
+    42994, 43047, 43052, 43052, 43072, 43123, 43136, 43205, 43216, 43225, 43232, 43255, 43259,
+    43259, 43261, 43309, 43312, 43347, 43360, 43388, 43392, 43456, 43471, 43481, 43488, 43518,
+    43520, 43574, 43584, 43597, 43600, 43609, 43616, 43638, 43642, 43714, 43739, 43741, 43744,
+    43759, 43762, 43766, 43777, 43782, 43785, 43790, 43793, 43798, 43808, 43814, 43816, 43822,
+    43824, 43866, 43868, 43881, 43888, 44010, 44012, 44013, 44016, 44025, 44032, 55203, 55216,
+    55238, 55243, 55291, 63744, 64109, 64112, 64217, 64256, 64262, 64275, 64279, 64285, 64296,
+    64298, 64310, 64312, 64316, 64318, 64318, 64320, 64321, 64323, 64324, 64326, 64433, 64467,
+    64829, 64848, 64911, 64914, 64967, 65008, 65019, 65024, 65039, 65056, 65071, 65075, 65076,
+    65101, 65103, 65136, 65140, 65142, 65276, 65296, 65305, 65313, 65338, 65343, 65343, 65345,
+    65370, 65382, 65470, 65474, 65479, 65482, 65487, 65490, 65495, 65498, 65500, 65536, 65547,
+    65549, 65574, 65576, 65594, 65596, 65597, 65599, 65613, 65616, 65629, 65664, 65786, 65856,
+    65908, 66045, 66045, 66176, 66204, 66208, 66256, 66272, 66272, 66304, 66335, 66349, 66378,
+    66384, 66426, 66432, 66461, 66464, 66499, 66504, 66511, 66513, 66517, 66560, 66717, 66720,
+    66729, 66736, 66771, 66776, 66811, 66816, 66855, 66864, 66915, 66928, 66938, 66940, 66954,
+    66956, 66962, 66964, 66965, 66967, 66977, 66979, 66993, 66995, 67001, 67003, 67004, 67072,
+    67382, 67392, 67413, 67424, 67431, 67456, 67461, 67463, 67504, 67506, 67514, 67584, 67589,
+    67592, 67592, 67594, 67637, 67639, 67640, 67644, 67644, 67647, 67669, 67680, 67702, 67712,
+    67742, 67808, 67826, 67828, 67829, 67840, 67861, 67872, 67897, 67968, 68023, 68030, 68031,
+    68096, 68099, 68101, 68102, 68108, 68115, 68117, 68119, 68121, 68149, 68152, 68154, 68159,
+    68159, 68192, 68220, 68224, 68252, 68288, 68295, 68297, 68326, 68352, 68405, 68416, 68437,
+    68448, 68466, 68480, 68497, 68608, 68680, 68736, 68786, 68800, 68850, 68864, 68903, 68912,
+    68921, 69248, 69289, 69291, 69292, 69296, 69297, 69376, 69404, 69415, 69415, 69424, 69456,
+    69488, 69509, 69552, 69572, 69600, 69622, 69632, 69702, 69734, 69749, 69759, 69818, 69826,
+    69826, 69840, 69864, 69872, 69881, 69888, 69940, 69942, 69951, 69956, 69959, 69968, 70003,
+    70006, 70006, 70016, 70084, 70089, 70092, 70094, 70106, 70108, 70108, 70144, 70161, 70163,
+    70199, 70206, 70206, 70272, 70278, 70280, 70280, 70282, 70285, 70287, 70301, 70303, 70312,
+    70320, 70378, 70384, 70393, 70400, 70403, 70405, 70412, 70415, 70416, 70419, 70440, 70442,
+    70448, 70450, 70451, 70453, 70457, 70459, 70468, 70471, 70472, 70475, 70477, 70480, 70480,
+    70487, 70487, 70493, 70499, 70502, 70508, 70512, 70516, 70656, 70730, 70736, 70745, 70750,
+    70753, 70784, 70853, 70855, 70855, 70864, 70873, 71040, 71093, 71096, 71104, 71128, 71133,
+    71168, 71232, 71236, 71236, 71248, 71257, 71296, 71352, 71360, 71369, 71424, 71450, 71453,
+    71467, 71472, 71481, 71488, 71494, 71680, 71738, 71840, 71913, 71935, 71942, 71945, 71945,
+    71948, 71955, 71957, 71958, 71960, 71989, 71991, 71992, 71995, 72003, 72016, 72025, 72096,
+    72103, 72106, 72151, 72154, 72161, 72163, 72164, 72192, 72254, 72263, 72263, 72272, 72345,
+    72349, 72349, 72368, 72440, 72704, 72712, 72714, 72758, 72760, 72768, 72784, 72793, 72818,
+    72847, 72850, 72871, 72873, 72886, 72960, 72966, 72968, 72969, 72971, 73014, 73018, 73018,
+    73020, 73021, 73023, 73031, 73040, 73049, 73056, 73061, 73063, 73064, 73066, 73102, 73104,
+    73105, 73107, 73112, 73120, 73129, 73440, 73462, 73648, 73648, 73728, 74649, 74752, 74862,
+    74880, 75075, 77712, 77808, 77824, 78894, 82944, 83526, 92160, 92728, 92736, 92766, 92768,
+    92777, 92784, 92862, 92864, 92873, 92880, 92909, 92912, 92916, 92928, 92982, 92992, 92995,
+    93008, 93017, 93027, 93047, 93053, 93071, 93760, 93823, 93952, 94026, 94031, 94087, 94095,
+    94111, 94176, 94177, 94179, 94180, 94192, 94193, 94208, 100343, 100352, 101589, 101632, 101640,
+    110576, 110579, 110581, 110587, 110589, 110590, 110592, 110882, 110928, 110930, 110948, 110951,
+    110960, 111355, 113664, 113770, 113776, 113788, 113792, 113800, 113808, 113817, 113821, 113822,
+    118528, 118573, 118576, 118598, 119141, 119145, 119149, 119154, 119163, 119170, 119173, 119179,
+    119210, 119213, 119362, 119364, 119808, 119892, 119894, 119964, 119966, 119967, 119970, 119970,
+    119973, 119974, 119977, 119980, 119982, 119993, 119995, 119995, 119997, 120003, 120005, 120069,
+    120071, 120074, 120077, 120084, 120086, 120092, 120094, 120121, 120123, 120126, 120128, 120132,
+    120134, 120134, 120138, 120144, 120146, 120485, 120488, 120512, 120514, 120538, 120540, 120570,
+    120572, 120596, 120598, 120628, 120630, 120654, 120656, 120686, 120688, 120712, 120714, 120744,
+    120746, 120770, 120772, 120779, 120782, 120831, 121344, 121398, 121403, 121452, 121461, 121461,
+    121476, 121476, 121499, 121503, 121505, 121519, 122624, 122654, 122880, 122886, 122888, 122904,
+    122907, 122913, 122915, 122916, 122918, 122922, 123136, 123180, 123184, 123197, 123200, 123209,
+    123214, 123214, 123536, 123566, 123584, 123641, 124896, 124902, 124904, 124907, 124909, 124910,
+    124912, 124926, 124928, 125124, 125136, 125142, 125184, 125259, 125264, 125273, 126464, 126467,
+    126469, 126495, 126497, 126498, 126500, 126500, 126503, 126503, 126505, 126514, 126516, 126519,
+    126521, 126521, 126523, 126523, 126530, 126530, 126535, 126535, 126537, 126537, 126539, 126539,
+    126541, 126543, 126545, 126546, 126548, 126548, 126551, 126551, 126553, 126553, 126555, 126555,
+    126557, 126557, 126559, 126559, 126561, 126562, 126564, 126564, 126567, 126570, 126572, 126578,
+    126580, 126583, 126585, 126588, 126590, 126590, 126592, 126601, 126603, 126619, 126625, 126627,
+    126629, 126633, 126635, 126651, 130032, 130041, 131072, 173791, 173824, 177976, 177984, 178205,
+    178208, 183969, 183984, 191456, 194560, 195101, 196608, 201546, 917760, 917999,
+];
+
+fn lookup_in_unicode_map(code: u32, map: &[u32]) -> bool {
+    // Bail out quickly if it couldn't possibly be in the map.
+    if code < map[0] {
+        return false;
+    }
+
+    // Perform binary search in one of the Unicode range maps
+    let mut lo = 0;
+    let mut hi = map.len();
+    while lo + 1 < hi {
+        let mut mid = lo + (hi - lo) / 2;
+        // mid has to be even to catch a range's beginning
+        mid -= mid % 2;
+        if map[mid] <= code && code <= map[mid + 1] {
+            return true;
+        }
+
+        if code < map[mid] {
+            hi = mid;
+        } else {
+            lo = mid + 2;
+        }
+    }
+
+    false
+}
+
+// As in TypeScript, an unspecified language version falls back to the ES3 tables.
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isUnicodeIdentifierStart"))]
+pub fn is_unicode_identifier_start(code: u32, language_version: Option<ScriptTarget>) -> bool {
+    match language_version {
+        Some(version) if version >= ScriptTarget::ES2015 => {
+            lookup_in_unicode_map(code, UNICODE_ESNEXT_IDENTIFIER_START)
+        }
+        Some(ScriptTarget::ES5) => lookup_in_unicode_map(code, UNICODE_ES5_IDENTIFIER_START),
+        _ => lookup_in_unicode_map(code, UNICODE_ES3_IDENTIFIER_START),
+    }
+}
+
+fn is_unicode_identifier_part(code: u32, language_version: Option<ScriptTarget>) -> bool {
+    match language_version {
+        Some(version) if version >= ScriptTarget::ES2015 => {
+            lookup_in_unicode_map(code, UNICODE_ESNEXT_IDENTIFIER_PART)
+        }
+        Some(ScriptTarget::ES5) => lookup_in_unicode_map(code, UNICODE_ES5_IDENTIFIER_PART),
+        _ => lookup_in_unicode_map(code, UNICODE_ES3_IDENTIFIER_PART),
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isIdentifierStart"))]
+pub fn is_identifier_start(ch: u32, language_version: Option<ScriptTarget>) -> bool {
+    ch >= CharacterCodes::UppercaseA as u32 && ch <= CharacterCodes::UppercaseZ as u32
+        || ch >= CharacterCodes::LowercaseA as u32 && ch <= CharacterCodes::LowercaseZ as u32
+        || ch == CharacterCodes::Dollar as u32
+        || ch == CharacterCodes::Underscore as u32
+        || ch > CharacterCodes::MaxAsciiCharacter as u32
+            && is_unicode_identifier_start(ch, language_version)
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isIdentifierPart"))]
+pub fn is_identifier_part(ch: u32, language_version: Option<ScriptTarget>) -> bool {
+    ch >= CharacterCodes::UppercaseA as u32 && ch <= CharacterCodes::UppercaseZ as u32
+        || ch >= CharacterCodes::LowercaseA as u32 && ch <= CharacterCodes::LowercaseZ as u32
+        || is_digit(ch)
+        || ch == CharacterCodes::Dollar as u32
+        || ch == CharacterCodes::Underscore as u32
+        || ch > CharacterCodes::MaxAsciiCharacter as u32
+            && is_unicode_identifier_part(ch, language_version)
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isIdentifierText"))]
+pub fn is_identifier_text(name: &str, language_version: Option<ScriptTarget>) -> bool {
+    let text = SourceText::new(name);
+    let mut ch = match text.code_point_at(0) {
+        Some(ch) if is_identifier_start(ch, language_version) => ch,
+        _ => return false,
+    };
+
+    let mut i = char_size(ch);
+    while i < text.len() {
+        ch = text.code_point_at(i).unwrap();
+        if !is_identifier_part(ch, language_version) {
+            return false;
+        }
+        i += char_size(ch);
+    }
+
+    true
+}
+
+/// Converts a number to a string the same way JavaScript's `Number.prototype.toString` does.
+fn number_to_string(value: f64) -> String {
+    if value.is_nan() {
+        return String::from("NaN");
+    }
+    if value == 0.0 {
+        return String::from("0");
+    }
+    if value.is_infinite() {
+        return String::from(if value < 0.0 { "-Infinity" } else { "Infinity" });
+    }
+    if value < 0.0 {
+        return format!("-{}", number_to_string(-value));
+    }
+
+    // The shortest digit string that round-trips, along with its decimal exponent
+    let exponential = format!("{:e}", value);
+    let (mantissa, exponent) = exponential.split_at(exponential.find('e').unwrap());
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent[1..].parse::<i32>().unwrap() + 1;
+
+    if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat(-n as usize), digits)
+    } else {
+        let sign = if n - 1 < 0 { '-' } else { '+' };
+        if k == 1 {
+            format!("{}e{}{}", digits, sign, (n - 1).abs())
+        } else {
+            format!(
+                "{}.{}e{}{}",
+                &digits[..1],
+                &digits[1..],
+                sign,
+                (n - 1).abs()
+            )
+        }
+    }
+}
+
+/// Parses a string of digits in the given radix, like JavaScript's `parseInt`.
+fn parse_int(digits: &str, radix: u32) -> f64 {
+    match u128::from_str_radix(digits, radix) {
+        Ok(value) => value as f64,
+        Err(_) => digits.chars().fold(0.0, |value, ch| {
+            value * f64::from(radix) + f64::from(ch.to_digit(radix).unwrap_or(0))
+        }),
+    }
+}
+
+/// Converts a binary or octal bigint literal (e.g. `0b101n`) to its base 10 digits,
+/// so the literal can be represented without loss of precision.
+fn parse_pseudo_big_int(text: &str) -> String {
+    let radix = match text.as_bytes().get(1) {
+        Some(b'b') | Some(b'B') => 2,
+        Some(b'o') | Some(b'O') => 8,
+        Some(b'x') | Some(b'X') => 16,
+        _ => {
+            // already in decimal; omit trailing "n" and skip leading 0s
+            let value = text.trim_end_matches('n').trim_start_matches('0');
+            return String::from(if value.is_empty() { "0" } else { value });
+        }
+    };
+
+    // Little-endian base 10 digits
+    let mut result: Vec<u32> = vec![0];
+    for digit in text[2..]
+        .trim_end_matches('n')
+        .chars()
+        .filter_map(|ch| ch.to_digit(radix))
+    {
+        let mut carry = digit;
+        for d in result.iter_mut() {
+            let value = *d * radix + carry;
+            *d = value % 10;
+            carry = value / 10;
+        }
+        while carry > 0 {
+            result.push(carry % 10);
+            carry /= 10;
+        }
+    }
+    while result.len() > 1 && result[result.len() - 1] == 0 {
+        result.pop();
+    }
+    result
+        .iter()
+        .rev()
+        .map(|&d| std::char::from_digit(d, 10).unwrap())
+        .collect()
+}
+
+// Derived from the 10.1.1 UTF16Encoding of the ES6 Spec.
+fn utf16_encode(code_point: u32) -> Vec<u16> {
+    debug_assert!(code_point <= 0x0010_FFFF);
+
+    if code_point <= 65535 {
+        return vec![code_point as u16];
+    }
+
+    let code_unit1 = ((code_point - 65536) / 1024) as u16 + 0xD800;
+    let code_unit2 = ((code_point - 65536) % 1024) as u16 + 0xDC00;
+
+    vec![code_unit1, code_unit2]
+}
+
+// What `scan_jsx_token` has seen of a run of JSX text so far.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum JsxTextContent {
+    // Only whitespace on the first line.
+    LeadingWhitespace,
+    // Only whitespace, including a line break.
+    AllWhitespace,
+    // Something other than whitespace.
+    Text,
+}
+
+// The part of the scanner state that speculative scanning restores.
+pub(crate) struct ScannerState {
+    pos: usize,
+    start_pos: usize,
+    token_pos: usize,
+    token: SyntaxKind,
+    token_value: Vec<u16>,
+    token_flags: TokenFlags,
+    error_count: usize,
+}
+
+/// A stateful tokenizer over a (possibly partial) range of a piece of text.
+/// This is the Rust counterpart of the object returned by `createScanner`.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct Scanner {
+    text: SourceText,
+    skip_trivia: bool,
+    language_version: ScriptTarget,
+    language_variant: LanguageVariant,
+
+    // Current position (end position of text of current token)
+    pos: usize,
+
+    // end of text
+    end: usize,
+
+    // Start position of whitespace before current token
+    start_pos: usize,
+
+    // Start position of text of current token
+    token_pos: usize,
+
+    token: SyntaxKind,
+    token_value: Vec<u16>,
+    token_flags: TokenFlags,
+
+    in_jsdoc_type: i32,
+
+    errors: Vec<Diagnostic>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Scanner {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(skip_trivia: bool) -> Scanner {
+        Scanner {
+            text: SourceText::default(),
+            skip_trivia,
+            language_version: ScriptTarget::Latest,
+            language_variant: LanguageVariant::Standard,
+            pos: 0,
+            end: 0,
+            start_pos: 0,
+            token_pos: 0,
+            token: SyntaxKind::Unknown,
+            token_value: Vec::new(),
+            token_flags: TokenFlags::empty(),
+            in_jsdoc_type: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getStartPos"))]
+    pub fn get_start_pos(&self) -> usize {
+        self.start_pos
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTextPos"))]
+    pub fn get_text_pos(&self) -> usize {
+        self.pos
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getToken"))]
+    pub fn get_token(&self) -> SyntaxKind {
+        self.token
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTokenPos"))]
+    pub fn get_token_pos(&self) -> usize {
+        self.token_pos
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTokenText"))]
+    pub fn get_token_text(&self) -> String {
+        self.substring(self.token_pos, self.pos)
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTokenValue"))]
+    pub fn get_token_value(&self) -> String {
+        String::from_utf16_lossy(&self.token_value)
+    }
+
+    /// The token value as UTF-16 code units, which unlike `getTokenValue` preserves
+    /// lone surrogates produced by escape sequences.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTokenValueUtf16"))]
+    pub fn get_token_value_utf16(&self) -> Vec<u16> {
+        self.token_value.clone()
+    }
+
+    /// The raw text of the current template literal token, without its delimiters and
+    /// with line terminators normalized, as exposed by `TemplateStringsArray#raw`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTemplateRawText"))]
+    pub fn get_template_raw_text(&self) -> String {
+        let is_last = self.token == SyntaxKind::NoSubstitutionTemplateLiteral
+            || self.token == SyntaxKind::TemplateTail;
+        let end = if self.is_unterminated() {
+            self.pos
+        } else if is_last {
+            self.pos.saturating_sub(1)
+        } else {
+            self.pos.saturating_sub(2)
+        };
+        self.substring(self.token_pos + 1, end)
+            .replace("\r\n", "\n")
+            .replace('\r', "\n")
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTokenFlags"))]
+    pub fn get_token_flags_bits(&self) -> u32 {
+        self.get_token_flags().bits()
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getNumericLiteralFlags"))]
+    pub fn get_numeric_literal_flags_bits(&self) -> u32 {
+        self.get_numeric_literal_flags().bits()
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "hasUnicodeEscape"))]
+    pub fn has_unicode_escape(&self) -> bool {
+        self.token_flags.contains(TokenFlags::UNICODE_ESCAPE)
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "hasExtendedUnicodeEscape"))]
+    pub fn has_extended_unicode_escape(&self) -> bool {
+        self.token_flags
+            .contains(TokenFlags::EXTENDED_UNICODE_ESCAPE)
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "hasInvalidEscape"))]
+    pub fn has_invalid_escape(&self) -> bool {
+        self.token_flags
+            .contains(TokenFlags::CONTAINS_INVALID_ESCAPE)
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "hasPrecedingLineBreak"))]
+    pub fn has_preceding_line_break(&self) -> bool {
+        self.token_flags.contains(TokenFlags::PRECEDING_LINE_BREAK)
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isIdentifier"))]
+    pub fn is_identifier(&self) -> bool {
+        self.token == SyntaxKind::Identifier || self.token > SyntaxKind::LastReservedWord
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isUnterminated"))]
+    pub fn is_unterminated(&self) -> bool {
+        self.token_flags.contains(TokenFlags::UNTERMINATED)
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "isReservedWord"))]
+    pub fn is_reserved_word(&self) -> bool {
+        self.token.is_reserved_word()
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getText"))]
+    pub fn get_text(&self) -> String {
+        self.text.to_string()
+    }
+
+    // Sets the text for the scanner to scan.  An optional subrange starting point and length
+    // can be provided to have the scanner only scan a portion of the text.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "setText"))]
+    pub fn set_text(&mut self, text: &str, start: Option<usize>, length: Option<usize>) {
+        self.text = SourceText::new(text);
+        self.errors.clear();
+        self.end = match length {
+            Some(length) => (start.unwrap_or(0) + length).min(self.text.len()),
+            None => self.text.len(),
+        };
+        self.set_text_pos(start.unwrap_or(0));
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "setScriptTarget"))]
+    pub fn set_script_target(&mut self, script_target: ScriptTarget) {
+        self.language_version = script_target;
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "setLanguageVariant"))]
+    pub fn set_language_variant(&mut self, variant: LanguageVariant) {
+        self.language_variant = variant;
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "setInJSDocType"))]
+    pub fn set_in_jsdoc_type(&mut self, in_type: bool) {
+        self.in_jsdoc_type += if in_type { 1 } else { -1 };
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "setTextPos"))]
+    pub fn set_text_pos(&mut self, text_pos: usize) {
+        self.pos = text_pos;
+        self.start_pos = text_pos;
+        self.token_pos = text_pos;
+        self.token = SyntaxKind::Unknown;
+        self.token_value = Vec::new();
+        self.token_flags = TokenFlags::empty();
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "reScanGreaterToken"))]
+    pub fn re_scan_greater_token(&mut self) -> SyntaxKind {
+        if self.token == SyntaxKind::GreaterThanToken {
+            if self.char_at(self.pos) == Some(CharacterCodes::GreaterThan) {
+                self.token = if self.char_at(self.pos + 1) == Some(CharacterCodes::GreaterThan) {
+                    if self.char_at(self.pos + 2) == Some(CharacterCodes::Equals) {
+                        self.pos += 3;
+                        SyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken
+                    } else {
+                        self.pos += 2;
+                        SyntaxKind::GreaterThanGreaterThanGreaterThanToken
+                    }
+                } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                    self.pos += 2;
+                    SyntaxKind::GreaterThanGreaterThanEqualsToken
+                } else {
+                    self.pos += 1;
+                    SyntaxKind::GreaterThanGreaterThanToken
+                };
+            } else if self.char_at(self.pos) == Some(CharacterCodes::Equals) {
+                self.pos += 1;
+                self.token = SyntaxKind::GreaterThanEqualsToken;
+            }
+        }
+        self.token
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "reScanSlashToken"))]
+    pub fn re_scan_slash_token(&mut self) -> SyntaxKind {
+        if self.token == SyntaxKind::SlashToken || self.token == SyntaxKind::SlashEqualsToken {
+            let mut p = self.token_pos + 1;
+            let mut in_escape = false;
+            let mut in_character_class = false;
+            loop {
+                // If we reach the end of a file, or hit a newline, then this is an unterminated
+                // regex.  Report error and return what we have so far.
+                let ch = match self.char_code_at(p) {
+                    Some(ch) => ch,
+                    None => {
+                        self.token_flags |= TokenFlags::UNTERMINATED;
+                        self.error(&diagnostics::UNTERMINATED_REGULAR_EXPRESSION_LITERAL);
+                        break;
+                    }
+                };
+                if is_line_break(ch) {
+                    self.token_flags |= TokenFlags::UNTERMINATED;
+                    self.error(&diagnostics::UNTERMINATED_REGULAR_EXPRESSION_LITERAL);
+                    break;
+                }
+
+                if in_escape {
+                    // Parsing an escape character;
+                    // reset the flag and just advance to the next char.
+                    in_escape = false;
+                } else if ch == CharacterCodes::Slash as u32 && !in_character_class {
+                    // A slash within a character class is permissible,
+                    // but in general it signals the end of the regexp literal.
+                    p += 1;
+                    break;
+                } else if ch == CharacterCodes::OpenBracket as u32 {
+                    in_character_class = true;
+                } else if ch == CharacterCodes::Backslash as u32 {
+                    in_escape = true;
+                } else if ch == CharacterCodes::CloseBracket as u32 {
+                    in_character_class = false;
+                }
+                p += 1;
+            }
+
+            let mut seen_flags = Vec::new();
+            while let Some(flag) = self
+                .char_code_at(p)
+                .filter(|&ch| is_identifier_part(ch, Some(self.language_version)))
+            {
+                let flag = flag as u16;
+                if !REGULAR_EXPRESSION_FLAGS.encode_utf16().any(|f| f == flag) {
+                    self.error_at(&diagnostics::UNKNOWN_REGULAR_EXPRESSION_FLAG, p, 1);
+                } else if seen_flags.contains(&flag) {
+                    self.error_at(&diagnostics::DUPLICATE_REGULAR_EXPRESSION_FLAG, p, 1);
+                } else {
+                    seen_flags.push(flag);
+                }
+                p += 1;
+            }
+            self.pos = p;
+            self.token_value = self.substring_utf16(self.token_pos, self.pos);
+            self.token = SyntaxKind::RegularExpressionLiteral;
+        }
+        self.token
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "reScanTemplateToken"))]
+    pub fn re_scan_template_token(&mut self, is_tagged_template: bool) -> SyntaxKind {
+        debug_assert_eq!(
+            self.token,
+            SyntaxKind::CloseBraceToken,
+            "'reScanTemplateToken' should only be called on a '}}'"
+        );
+        self.pos = self.token_pos;
+        self.token = self.scan_template_and_set_token_value(is_tagged_template);
+        self.token
+    }
+
+    #[cfg_attr(
+        feature = "wasm",
+        wasm_bindgen(js_name = "reScanTemplateHeadOrNoSubstitutionTemplate")
+    )]
+    pub fn re_scan_template_head_or_no_substitution_template(&mut self) -> SyntaxKind {
+        self.pos = self.token_pos;
+        self.token = self.scan_template_and_set_token_value(true);
+        self.token
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "reScanJsxToken"))]
+    pub fn re_scan_jsx_token(&mut self) -> SyntaxKind {
+        self.pos = self.start_pos;
+        self.token_pos = self.start_pos;
+        self.token = self.scan_jsx_token();
+        self.token
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "reScanLessThanToken"))]
+    pub fn re_scan_less_than_token(&mut self) -> SyntaxKind {
+        if self.token == SyntaxKind::LessThanLessThanToken {
+            self.pos = self.token_pos + 1;
+            self.token = SyntaxKind::LessThanToken;
+        }
+        self.token
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "scanJsxToken"))]
+    pub fn scan_jsx_token(&mut self) -> SyntaxKind {
+        self.start_pos = self.pos;
+        self.token_pos = self.pos;
+
+        let mut ch = match self.char_code_at(self.pos) {
+            Some(ch) => ch,
+            None => {
+                self.token = SyntaxKind::EndOfFileToken;
+                return self.token;
+            }
+        };
+        if ch == CharacterCodes::LessThan as u32 {
+            if self.char_at(self.pos + 1) == Some(CharacterCodes::Slash) {
+                self.pos += 2;
+                self.token = SyntaxKind::LessThanSlashToken;
+                return self.token;
+            }
+            self.pos += 1;
+            self.token = SyntaxKind::LessThanToken;
+            return self.token;
+        }
+
+        if ch == CharacterCodes::OpenBrace as u32 {
+            self.pos += 1;
+            self.token = SyntaxKind::OpenBraceToken;
+            return self.token;
+        }
+
+        let mut content = JsxTextContent::LeadingWhitespace;
+        while let Some(next) = self.char_code_at(self.pos) {
+            ch = next;
+            if ch == CharacterCodes::OpenBrace as u32 {
+                break;
+            }
+            if ch == CharacterCodes::LessThan as u32 {
+                if is_conflict_marker_trivia(&self.text, self.pos) {
+                    self.pos = self.scan_conflict_marker_trivia();
+                    self.token = SyntaxKind::ConflictMarkerTrivia;
+                    return self.token;
+                }
+                break;
+            }
+
+            // If we have only seen whitespace so far and see a line break, we want to ignore
+            // that whitespace.
+            // i.e (- : whitespace)
+            //      <div>----
+            //      </div> becomes <div></div>
+            //
+            //      <div>----</div> becomes <div>----</div>
+            if is_line_break(ch) && content == JsxTextContent::LeadingWhitespace {
+                content = JsxTextContent::AllWhitespace;
+            } else if !is_white_space_like(ch) {
+                content = JsxTextContent::Text;
+            }
+            self.pos += 1;
+        }
+
+        self.token_value = self.substring_utf16(self.start_pos, self.pos);
+        self.token = if content == JsxTextContent::AllWhitespace {
+            SyntaxKind::JsxTextAllWhiteSpaces
+        } else {
+            SyntaxKind::JsxText
+        };
+        self.token
+    }
+
+    // Scans a JSX identifier; these differ from normal identifiers in that
+    // they allow dashes
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "scanJsxIdentifier"))]
+    pub fn scan_jsx_identifier(&mut self) -> SyntaxKind {
+        if self.token.token_is_identifier_or_keyword() {
+            let first_char_position = self.pos;
+            while let Some(ch) = self.code_point_at(self.pos) {
+                let is_valid = if first_char_position == self.pos {
+                    is_identifier_start(ch, Some(self.language_version))
+                } else {
+                    is_identifier_part(ch, Some(self.language_version))
+                };
+                if ch == CharacterCodes::Minus as u32 || is_valid {
+                    self.pos += char_size(ch);
+                } else {
+                    break;
+                }
+            }
+            let mut token_value = std::mem::take(&mut self.token_value);
+            self.push_substring(&mut token_value, first_char_position, self.pos);
+            self.token_value = token_value;
+        }
+        self.token
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "scanJsxAttributeValue"))]
+    pub fn scan_jsx_attribute_value(&mut self) -> SyntaxKind {
+        self.start_pos = self.pos;
+
+        match self.char_at(self.pos) {
+            Some(CharacterCodes::DoubleQuote) | Some(CharacterCodes::SingleQuote) => {
+                self.token_value = self.scan_string(true);
+                self.token = SyntaxKind::StringLiteral;
+                self.token
+            }
+            // If this scans anything other than `{`, it's a parse error.
+            _ => self.scan(),
+        }
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "scanJSDocToken"))]
+    pub fn scan_jsdoc_token(&mut self) -> SyntaxKind {
+        self.start_pos = self.pos;
+        self.token_pos = self.pos;
+        self.token_flags = TokenFlags::empty();
+        let ch = match self.code_point_at(self.pos) {
+            Some(ch) => ch,
+            None => {
+                self.token = SyntaxKind::EndOfFileToken;
+                return self.token;
+            }
+        };
+        self.pos += char_size(ch);
+        self.token = match FromPrimitive::from_u32(ch) {
+            Some(CharacterCodes::Tab)
+            | Some(CharacterCodes::VerticalTab)
+            | Some(CharacterCodes::FormFeed)
+            | Some(CharacterCodes::Space) => {
+                while self
+                    .char_code_at(self.pos)
+                    .is_some_and(is_white_space_single_line)
+                {
+                    self.pos += 1;
+                }
+                SyntaxKind::WhitespaceTrivia
+            }
+            Some(CharacterCodes::At) => SyntaxKind::AtToken,
+            Some(CharacterCodes::LineFeed) | Some(CharacterCodes::CarriageReturn) => {
+                self.token_flags |= TokenFlags::PRECEDING_LINE_BREAK;
+                SyntaxKind::NewLineTrivia
+            }
+            Some(CharacterCodes::Asterisk) => SyntaxKind::AsteriskToken,
+            Some(CharacterCodes::OpenBrace) => SyntaxKind::OpenBraceToken,
+            Some(CharacterCodes::CloseBrace) => SyntaxKind::CloseBraceToken,
+            Some(CharacterCodes::OpenBracket) => SyntaxKind::OpenBracketToken,
+            Some(CharacterCodes::CloseBracket) => SyntaxKind::CloseBracketToken,
+            Some(CharacterCodes::LessThan) => SyntaxKind::LessThanToken,
+            Some(CharacterCodes::Equals) => SyntaxKind::EqualsToken,
+            Some(CharacterCodes::Comma) => SyntaxKind::CommaToken,
+            Some(CharacterCodes::Dot) => SyntaxKind::DotToken,
+            Some(CharacterCodes::Backtick) => {
+                while self.pos < self.end
+                    && self.char_at(self.pos) != Some(CharacterCodes::Backtick)
+                {
+                    self.pos += 1;
+                }
+                self.token_value = self.substring_utf16(self.token_pos + 1, self.pos);
+                self.pos += 1;
+                SyntaxKind::NoSubstitutionTemplateLiteral
+            }
+            _ if is_identifier_start(ch, Some(ScriptTarget::Latest)) => {
+                while let Some(ch) = self.code_point_at(self.pos) {
+                    if !is_identifier_part(ch, Some(ScriptTarget::Latest)) {
+                        break;
+                    }
+                    self.pos += char_size(ch);
+                }
+                self.token_value = self.substring_utf16(self.token_pos, self.pos);
+                self.get_identifier_token()
+            }
+            _ => SyntaxKind::Unknown,
+        };
+        self.token
+    }
+
+    pub fn scan(&mut self) -> SyntaxKind {
+        self.start_pos = self.pos;
+        self.token_flags = TokenFlags::empty();
+        let mut asterisk_seen = false;
+        loop {
+            self.token_pos = self.pos;
+            let ch = match self.char_code_at(self.pos) {
+                Some(ch) => ch,
+                None => {
+                    self.token = SyntaxKind::EndOfFileToken;
+                    return self.token;
+                }
+            };
+
+            // Special handling for shebang
+            if ch == CharacterCodes::Hash as u32
+                && self.pos == 0
+                && is_shebang_trivia(&self.text, self.pos)
+            {
+                self.pos = scan_shebang_trivia(&self.text, self.pos);
+                if self.skip_trivia {
+                    continue;
+                }
+                self.token = SyntaxKind::ShebangTrivia;
+                return self.token;
+            }
+
+            let token = match FromPrimitive::from_u32(ch) {
+                Some(CharacterCodes::LineFeed) | Some(CharacterCodes::CarriageReturn) => {
+                    self.token_flags |= TokenFlags::PRECEDING_LINE_BREAK;
+                    if self.skip_trivia {
+                        self.pos += 1;
+                        continue;
+                    }
+                    if ch == CharacterCodes::CarriageReturn as u32
+                        && self.char_at(self.pos + 1) == Some(CharacterCodes::LineFeed)
+                    {
+                        // consume both CR and LF
+                        self.pos += 2;
+                    } else {
+                        self.pos += 1;
+                    }
+                    SyntaxKind::NewLineTrivia
+                }
+                Some(CharacterCodes::Tab)
+                | Some(CharacterCodes::VerticalTab)
+                | Some(CharacterCodes::FormFeed)
+                | Some(CharacterCodes::Space)
+                | Some(CharacterCodes::NonBreakingSpace)
+                | Some(CharacterCodes::Ogham)
+                | Some(CharacterCodes::EnQuad)
+                | Some(CharacterCodes::EmQuad)
+                | Some(CharacterCodes::EnSpace)
+                | Some(CharacterCodes::EmSpace)
+                | Some(CharacterCodes::ThreePerEmSpace)
+                | Some(CharacterCodes::FourPerEmSpace)
+                | Some(CharacterCodes::SixPerEmSpace)
+                | Some(CharacterCodes::FigureSpace)
+                | Some(CharacterCodes::PunctuationSpace)
+                | Some(CharacterCodes::ThinSpace)
+                | Some(CharacterCodes::HairSpace)
+                | Some(CharacterCodes::ZeroWidthSpace)
+                | Some(CharacterCodes::NarrowNoBreakSpace)
+                | Some(CharacterCodes::MathematicalSpace)
+                | Some(CharacterCodes::IdeographicSpace)
+                | Some(CharacterCodes::ByteOrderMark) => {
+                    if self.skip_trivia {
+                        self.pos += 1;
+                        continue;
+                    }
+                    while self
+                        .char_code_at(self.pos)
+                        .is_some_and(is_white_space_single_line)
+                    {
+                        self.pos += 1;
+                    }
+                    SyntaxKind::WhitespaceTrivia
+                }
+                Some(CharacterCodes::Exclamation) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        if self.char_at(self.pos + 2) == Some(CharacterCodes::Equals) {
+                            self.pos += 3;
+                            SyntaxKind::ExclamationEqualsEqualsToken
+                        } else {
+                            self.pos += 2;
+                            SyntaxKind::ExclamationEqualsToken
+                        }
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::ExclamationToken
+                    }
+                }
+                Some(CharacterCodes::DoubleQuote) | Some(CharacterCodes::SingleQuote) => {
+                    self.token_value = self.scan_string(false);
+                    SyntaxKind::StringLiteral
+                }
+                Some(CharacterCodes::Backtick) => self.scan_template_and_set_token_value(false),
+                Some(CharacterCodes::Percent) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::PercentEqualsToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::PercentToken
+                    }
+                }
+                Some(CharacterCodes::Ampersand) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Ampersand) {
+                        self.pos += 2;
+                        SyntaxKind::AmpersandAmpersandToken
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::AmpersandEqualsToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::AmpersandToken
+                    }
+                }
+                Some(CharacterCodes::OpenParen) => {
+                    self.pos += 1;
+                    SyntaxKind::OpenParenToken
+                }
+                Some(CharacterCodes::CloseParen) => {
+                    self.pos += 1;
+                    SyntaxKind::CloseParenToken
+                }
+                Some(CharacterCodes::Asterisk) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::AsteriskEqualsToken
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Asterisk) {
+                        if self.char_at(self.pos + 2) == Some(CharacterCodes::Equals) {
+                            self.pos += 3;
+                            SyntaxKind::AsteriskAsteriskEqualsToken
+                        } else {
+                            self.pos += 2;
+                            SyntaxKind::AsteriskAsteriskToken
+                        }
+                    } else {
+                        self.pos += 1;
+                        if self.in_jsdoc_type != 0
+                            && !asterisk_seen
+                            && self.token_flags.contains(TokenFlags::PRECEDING_LINE_BREAK)
+                        {
+                            // decoration at the start of a JSDoc comment line
+                            asterisk_seen = true;
+                            continue;
+                        }
+                        SyntaxKind::AsteriskToken
+                    }
+                }
+                Some(CharacterCodes::Plus) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Plus) {
+                        self.pos += 2;
+                        SyntaxKind::PlusPlusToken
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::PlusEqualsToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::PlusToken
+                    }
+                }
+                Some(CharacterCodes::Comma) => {
+                    self.pos += 1;
+                    SyntaxKind::CommaToken
+                }
+                Some(CharacterCodes::Minus) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Minus) {
+                        self.pos += 2;
+                        SyntaxKind::MinusMinusToken
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::MinusEqualsToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::MinusToken
+                    }
+                }
+                Some(CharacterCodes::Dot) => {
+                    if self.char_code_at(self.pos + 1).is_some_and(is_digit) {
+                        let value = self.scan_number().1;
+                        self.set_token_value(&value);
+                        SyntaxKind::NumericLiteral
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Dot)
+                        && self.char_at(self.pos + 2) == Some(CharacterCodes::Dot)
+                    {
+                        self.pos += 3;
+                        SyntaxKind::DotDotDotToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::DotToken
+                    }
+                }
+                Some(CharacterCodes::Slash) => {
+                    // Single-line comment
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Slash) {
+                        self.pos += 2;
+
+                        while let Some(ch) = self.char_code_at(self.pos) {
+                            if is_line_break(ch) {
+                                break;
+                            }
+                            self.pos += 1;
+                        }
+
+                        if self.skip_trivia {
+                            continue;
+                        }
+                        SyntaxKind::SingleLineCommentTrivia
+                    }
+                    // Multi-line comment
+                    else if self.char_at(self.pos + 1) == Some(CharacterCodes::Asterisk) {
+                        self.pos += 2;
+                        if self.char_at(self.pos) == Some(CharacterCodes::Asterisk)
+                            && self.char_at(self.pos + 1) != Some(CharacterCodes::Slash)
+                        {
+                            self.token_flags |= TokenFlags::PRECEDING_JSDOC_COMMENT;
+                        }
+
+                        let mut comment_closed = false;
+                        while let Some(ch) = self.char_code_at(self.pos) {
+                            if ch == CharacterCodes::Asterisk as u32
+                                && self.char_at(self.pos + 1) == Some(CharacterCodes::Slash)
+                            {
+                                self.pos += 2;
+                                comment_closed = true;
+                                break;
+                            }
+
+                            if is_line_break(ch) {
+                                self.token_flags |= TokenFlags::PRECEDING_LINE_BREAK;
+                            }
+                            self.pos += 1;
+                        }
+
+                        if !comment_closed {
+                            self.error(&diagnostics::ASTERISK_SLASH_EXPECTED);
+                        }
+
+                        if self.skip_trivia {
+                            continue;
+                        }
+                        SyntaxKind::MultiLineCommentTrivia
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::SlashEqualsToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::SlashToken
+                    }
+                }
+                Some(CharacterCodes::_0)
+                    if self.pos + 2 < self.end
+                        && (self.char_at(self.pos + 1) == Some(CharacterCodes::UppercaseX)
+                            || self.char_at(self.pos + 1) == Some(CharacterCodes::LowercaseX)) =>
+                {
+                    self.pos += 2;
+                    let mut value = self.scan_minimum_number_of_hex_digits(1, true);
+                    if value.is_empty() {
+                        self.error(&diagnostics::HEXADECIMAL_DIGIT_EXPECTED);
+                        value = String::from("0");
+                    }
+                    let value = format!("0x{}", value);
+                    self.token_flags |= TokenFlags::HEX_SPECIFIER;
+                    let (token, value) = self.check_big_int_suffix(value);
+                    self.set_token_value(&value);
+                    token
+                }
+                Some(CharacterCodes::_0)
+                    if self.pos + 2 < self.end
+                        && (self.char_at(self.pos + 1) == Some(CharacterCodes::UppercaseB)
+                            || self.char_at(self.pos + 1) == Some(CharacterCodes::LowercaseB)) =>
+                {
+                    self.pos += 2;
+                    let mut value = self.scan_binary_or_octal_digits(2);
+                    if value.is_empty() {
+                        self.error(&diagnostics::BINARY_DIGIT_EXPECTED);
+                        value = String::from("0");
+                    }
+                    let value = format!("0b{}", value);
+                    self.token_flags |= TokenFlags::BINARY_SPECIFIER;
+                    let (token, value) = self.check_big_int_suffix(value);
+                    self.set_token_value(&value);
+                    token
+                }
+                Some(CharacterCodes::_0)
+                    if self.pos + 2 < self.end
+                        && (self.char_at(self.pos + 1) == Some(CharacterCodes::UppercaseO)
+                            || self.char_at(self.pos + 1) == Some(CharacterCodes::LowercaseO)) =>
+                {
+                    self.pos += 2;
+                    let mut value = self.scan_binary_or_octal_digits(8);
+                    if value.is_empty() {
+                        self.error(&diagnostics::OCTAL_DIGIT_EXPECTED);
+                        value = String::from("0");
+                    }
+                    let value = format!("0o{}", value);
+                    self.token_flags |= TokenFlags::OCTAL_SPECIFIER;
+                    let (token, value) = self.check_big_int_suffix(value);
+                    self.set_token_value(&value);
+                    token
+                }
+                // Try to parse as an octal
+                Some(CharacterCodes::_0)
+                    if self.pos + 1 < self.end
+                        && self.char_code_at(self.pos + 1).is_some_and(is_octal_digit) =>
+                {
+                    let start = self.pos;
+                    let value = number_to_string(self.scan_octal_digits());
+                    self.set_token_value(&value);
+                    self.token_flags |= TokenFlags::OCTAL;
+                    if self.language_version >= ScriptTarget::ES5 {
+                        self.errors.push(create_diagnostic(
+                            start,
+                            self.pos - start,
+                            &diagnostics::OCTAL_LITERALS_ARE_NOT_AVAILABLE_WHEN_TARGETING_ECMASCRIPT_5_AND_HIGHER_USE_THE_SYNTAX_0,
+                            &[&format!("0o{}", value)],
+                        ));
+                    }
+                    SyntaxKind::NumericLiteral
+                }
+                // This fall-through is a deviation from the EcmaScript grammar. The grammar says that a leading zero
+                // can only be followed by an octal digit, a dot, or the end of the number literal. However, we are being
+                // permissive and allowing decimal digits of the form 08* and 09* (which many browsers also do).
+                Some(CharacterCodes::_0)
+                | Some(CharacterCodes::_1)
+                | Some(CharacterCodes::_2)
+                | Some(CharacterCodes::_3)
+                | Some(CharacterCodes::_4)
+                | Some(CharacterCodes::_5)
+                | Some(CharacterCodes::_6)
+                | Some(CharacterCodes::_7)
+                | Some(CharacterCodes::_8)
+                | Some(CharacterCodes::_9) => {
+                    let (token, value) = self.scan_number();
+                    self.set_token_value(&value);
+                    token
+                }
+                Some(CharacterCodes::Colon) => {
+                    self.pos += 1;
+                    SyntaxKind::ColonToken
+                }
+                Some(CharacterCodes::Semicolon) => {
+                    self.pos += 1;
+                    SyntaxKind::SemicolonToken
+                }
+                Some(CharacterCodes::LessThan)
+                | Some(CharacterCodes::Equals)
+                | Some(CharacterCodes::GreaterThan)
+                | Some(CharacterCodes::Bar)
+                    if is_conflict_marker_trivia(&self.text, self.pos) =>
+                {
+                    self.pos = self.scan_conflict_marker_trivia();
+                    if self.skip_trivia {
+                        continue;
+                    }
+                    SyntaxKind::ConflictMarkerTrivia
+                }
+                Some(CharacterCodes::LessThan) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::LessThan) {
+                        if self.char_at(self.pos + 2) == Some(CharacterCodes::Equals) {
+                            self.pos += 3;
+                            SyntaxKind::LessThanLessThanEqualsToken
+                        } else {
+                            self.pos += 2;
+                            SyntaxKind::LessThanLessThanToken
+                        }
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::LessThanEqualsToken
+                    } else if self.language_variant == LanguageVariant::JSX
+                        && self.char_at(self.pos + 1) == Some(CharacterCodes::Slash)
+                        && self.char_at(self.pos + 2) != Some(CharacterCodes::Asterisk)
+                    {
+                        self.pos += 2;
+                        SyntaxKind::LessThanSlashToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::LessThanToken
+                    }
+                }
+                Some(CharacterCodes::Equals) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        if self.char_at(self.pos + 2) == Some(CharacterCodes::Equals) {
+                            self.pos += 3;
+                            SyntaxKind::EqualsEqualsEqualsToken
+                        } else {
+                            self.pos += 2;
+                            SyntaxKind::EqualsEqualsToken
+                        }
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::GreaterThan) {
+                        self.pos += 2;
+                        SyntaxKind::EqualsGreaterThanToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::EqualsToken
+                    }
+                }
+                Some(CharacterCodes::GreaterThan) => {
+                    self.pos += 1;
+                    SyntaxKind::GreaterThanToken
+                }
+                Some(CharacterCodes::Question) => {
+                    self.pos += 1;
+                    SyntaxKind::QuestionToken
+                }
+                Some(CharacterCodes::OpenBracket) => {
+                    self.pos += 1;
+                    SyntaxKind::OpenBracketToken
+                }
+                Some(CharacterCodes::CloseBracket) => {
+                    self.pos += 1;
+                    SyntaxKind::CloseBracketToken
+                }
+                Some(CharacterCodes::Caret) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::CaretEqualsToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::CaretToken
+                    }
+                }
+                Some(CharacterCodes::OpenBrace) => {
+                    self.pos += 1;
+                    SyntaxKind::OpenBraceToken
+                }
+                Some(CharacterCodes::Bar) => {
+                    if self.char_at(self.pos + 1) == Some(CharacterCodes::Bar) {
+                        self.pos += 2;
+                        SyntaxKind::BarBarToken
+                    } else if self.char_at(self.pos + 1) == Some(CharacterCodes::Equals) {
+                        self.pos += 2;
+                        SyntaxKind::BarEqualsToken
+                    } else {
+                        self.pos += 1;
+                        SyntaxKind::BarToken
+                    }
+                }
+                Some(CharacterCodes::CloseBrace) => {
+                    self.pos += 1;
+                    SyntaxKind::CloseBraceToken
+                }
+                Some(CharacterCodes::Tilde) => {
+                    self.pos += 1;
+                    SyntaxKind::TildeToken
+                }
+                Some(CharacterCodes::At) => {
+                    self.pos += 1;
+                    SyntaxKind::AtToken
+                }
+                Some(CharacterCodes::Backslash) => match self.peek_unicode_escape() {
+                    Some(cooked_char)
+                        if is_identifier_start(cooked_char, Some(self.language_version)) =>
+                    {
+                        self.pos += 6;
+                        self.token_flags |= TokenFlags::UNICODE_ESCAPE;
+                        let mut token_value = utf16_encode(cooked_char);
+                        token_value.extend(self.scan_identifier_parts());
+                        self.token_value = token_value;
+                        self.get_identifier_token()
+                    }
+                    _ => {
+                        self.error(&diagnostics::INVALID_CHARACTER);
+                        self.pos += char_size(ch);
+                        SyntaxKind::Unknown
+                    }
+                },
+                _ => {
+                    let ch = self.code_point_at(self.pos).unwrap_or(ch);
+                    if is_identifier_start(ch, Some(self.language_version)) {
+                        self.pos += char_size(ch);
+                        let mut token_value = self.substring_utf16(self.token_pos, self.pos);
+                        token_value.extend(self.scan_identifier_parts());
+                        self.token_value = token_value;
+                        self.get_identifier_token()
+                    } else if is_white_space_single_line(ch) {
+                        self.pos += 1;
+                        continue;
+                    } else if is_line_break(ch) {
+                        self.token_flags |= TokenFlags::PRECEDING_LINE_BREAK;
+                        self.pos += 1;
+                        continue;
+                    } else {
+                        self.error(&diagnostics::INVALID_CHARACTER);
+                        self.pos += char_size(ch);
+                        SyntaxKind::Unknown
+                    }
+                }
+            };
+            self.token = token;
+            return token;
+        }
+    }
+}
+
+impl Scanner {
+    /// Returns the errors reported since the text was last set.
+    pub fn get_errors(&self) -> &[Diagnostic] {
+        &self.errors
+    }
+
+    pub fn get_token_flags(&self) -> TokenFlags {
+        self.token_flags
+    }
+
+    /// The flags of the current token that describe how a numeric literal was written.
+    pub fn get_numeric_literal_flags(&self) -> TokenFlags {
+        self.token_flags & TokenFlags::NUMERIC_LITERAL_FLAGS
+    }
+
+    /// Invokes the callback with the scanner and then restores it to its current state, so the
+    /// callback can scan ahead without consuming anything.
+    pub fn look_ahead<T>(&mut self, callback: impl FnOnce(&mut Scanner) -> T) -> T {
+        let state = self.save_state();
+        let result = callback(self);
+        self.restore_state(state);
+        result
+    }
+
+    /// Invokes the callback with the scanner, keeping whatever it scanned if it returns `Some`
+    /// and restoring the scanner to its current state if it returns `None`.
+    pub fn try_scan<T>(&mut self, callback: impl FnOnce(&mut Scanner) -> Option<T>) -> Option<T> {
+        let state = self.save_state();
+        let result = callback(self);
+        if result.is_none() {
+            self.restore_state(state);
+        }
+        result
+    }
+
+    /// Invokes the callback with the scanner limited to `length` characters from `start`, and
+    /// then restores the scanner to its current range and state. Errors reported by the callback
+    /// are kept.
+    pub fn scan_range<T>(
+        &mut self,
+        start: usize,
+        length: usize,
+        callback: impl FnOnce(&mut Scanner) -> T,
+    ) -> T {
+        let save_end = self.end;
+        let mut state = self.save_state();
+
+        self.end = (start + length).min(self.text.len());
+        self.set_text_pos(start);
+        let result = callback(self);
+
+        self.end = save_end;
+        state.error_count = self.errors.len();
+        self.restore_state(state);
+        result
+    }
+
+    // The whole text, for callers that need to look at it without re-encoding `get_text()`.
+    pub(crate) fn source_text(&self) -> &SourceText {
+        &self.text
+    }
+
+    pub(crate) fn save_state(&self) -> ScannerState {
+        ScannerState {
+            pos: self.pos,
+            start_pos: self.start_pos,
+            token_pos: self.token_pos,
+            token: self.token,
+            token_value: self.token_value.clone(),
+            token_flags: self.token_flags,
+            error_count: self.errors.len(),
+        }
+    }
+
+    // Errors reported since the state was saved are discarded along with the tokens.
+    pub(crate) fn restore_state(&mut self, state: ScannerState) {
+        self.pos = state.pos;
+        self.start_pos = state.start_pos;
+        self.token_pos = state.token_pos;
+        self.token = state.token;
+        self.token_value = state.token_value;
+        self.token_flags = state.token_flags;
+        self.errors.truncate(state.error_count);
+    }
+
+    // The code unit at `pos`, or `None` at or past the end of the range being scanned.
+    fn char_code_at(&self, pos: usize) -> Option<u32> {
+        if pos < self.end {
+            self.text.char_code_at(pos)
+        } else {
+            None
+        }
+    }
+
+    // Like `char_code_at`, but combines a surrogate pair that lies within the range.
+    fn code_point_at(&self, pos: usize) -> Option<u32> {
+        let first = self.char_code_at(pos)?;
+        match self.char_code_at(pos + 1) {
+            Some(second)
+                if (0xD800..=0xDBFF).contains(&first) && (0xDC00..=0xDFFF).contains(&second) =>
+            {
+                Some((first - 0xD800) * 0x400 + second - 0xDC00 + 0x10000)
+            }
+            _ => Some(first),
+        }
+    }
+
+    fn char_at(&self, pos: usize) -> Option<CharacterCodes> {
+        self.char_code_at(pos).and_then(FromPrimitive::from_u32)
+    }
+
+    fn substring(&self, start: usize, end: usize) -> String {
+        self.text.substring(start, end)
+    }
+
+    fn substring_utf16(&self, start: usize, end: usize) -> Vec<u16> {
+        let mut result = Vec::new();
+        self.push_substring(&mut result, start, end);
+        result
+    }
+
+    fn push_substring(&self, result: &mut Vec<u16>, start: usize, end: usize) {
+        result.extend_from_slice(self.text.slice(start, end));
+    }
+
+    fn set_token_value(&mut self, value: &str) {
+        self.token_value = value.encode_utf16().collect();
+    }
+
+    fn error(&mut self, message: &DiagnosticMessage) {
+        self.error_at(message, self.pos, 0);
+    }
+
+    fn error_at(&mut self, message: &DiagnosticMessage, pos: usize, length: usize) {
+        self.errors
+            .push(create_diagnostic(pos, length, message, &[]));
+    }
+
+    fn scan_conflict_marker_trivia(&mut self) -> usize {
+        self.error_at(
+            &diagnostics::MERGE_CONFLICT_MARKER_ENCOUNTERED,
+            self.pos,
+            MERGE_CONFLICT_MARKER_LENGTH,
+        );
+        scan_conflict_marker_trivia(&self.text, self.pos)
+    }
+
+    fn scan_number_fragment(&mut self) -> String {
+        let mut start = self.pos;
+        let mut allow_separator = false;
+        let mut is_previous_token_separator = false;
+        let mut result = String::new();
+        loop {
+            let ch = self.char_code_at(self.pos);
+            if ch == Some(CharacterCodes::Underscore as u32) {
+                self.token_flags |= TokenFlags::CONTAINS_SEPARATOR;
+                if allow_separator {
+                    allow_separator = false;
+                    is_previous_token_separator = true;
+                    result += &self.substring(start, self.pos);
+                } else if is_previous_token_separator {
+                    self.error_at(
+                        &diagnostics::MULTIPLE_CONSECUTIVE_NUMERIC_SEPARATORS_ARE_NOT_PERMITTED,
+                        self.pos,
+                        1,
+                    );
+                } else {
+                    self.error_at(
+                        &diagnostics::NUMERIC_SEPARATORS_ARE_NOT_ALLOWED_HERE,
+                        self.pos,
+                        1,
+                    );
+                }
+                self.pos += 1;
+                start = self.pos;
+                continue;
+            }
+            if ch.is_some_and(is_digit) {
+                allow_separator = true;
+                is_previous_token_separator = false;
+                self.pos += 1;
+                continue;
+            }
+            break;
+        }
+        if self.pos > 0 && self.char_at(self.pos - 1) == Some(CharacterCodes::Underscore) {
+            self.error_at(
+                &diagnostics::NUMERIC_SEPARATORS_ARE_NOT_ALLOWED_HERE,
+                self.pos - 1,
+                1,
+            );
+        }
+        result + &self.substring(start, self.pos)
+    }
+
+    fn scan_number(&mut self) -> (SyntaxKind, String) {
+        let start = self.pos;
+        let main_fragment = self.scan_number_fragment();
+        let mut decimal_fragment = None;
+        let mut scientific_fragment = None;
+        if self.char_at(self.pos) == Some(CharacterCodes::Dot) {
+            self.pos += 1;
+            decimal_fragment = Some(self.scan_number_fragment());
+        }
+        let mut end = self.pos;
+        if self.char_at(self.pos) == Some(CharacterCodes::UppercaseE)
+            || self.char_at(self.pos) == Some(CharacterCodes::LowercaseE)
+        {
+            self.pos += 1;
+            self.token_flags |= TokenFlags::SCIENTIFIC;
+            if self.char_at(self.pos) == Some(CharacterCodes::Plus)
+                || self.char_at(self.pos) == Some(CharacterCodes::Minus)
+            {
+                self.pos += 1;
+            }
+            let pre_numeric_part = self.pos;
+            let final_fragment = self.scan_number_fragment();
+            if final_fragment.is_empty() {
+                self.error(&diagnostics::DIGIT_EXPECTED);
+            } else {
+                scientific_fragment = Some(self.substring(end, pre_numeric_part) + &final_fragment);
+                end = self.pos;
+            }
+        }
+        let result = if self.token_flags.contains(TokenFlags::CONTAINS_SEPARATOR) {
+            let mut result = main_fragment;
+            if let Some(decimal_fragment) = decimal_fragment.as_ref().filter(|f| !f.is_empty()) {
+                result += ".";
+                result += decimal_fragment;
+            }
+            if let Some(scientific_fragment) = scientific_fragment {
+                result += &scientific_fragment;
+            }
+            result
+        } else {
+            self.substring(start, end) // No need to use all the fragments; no _ removal needed
+        };
+
+        if decimal_fragment.is_some() || self.token_flags.contains(TokenFlags::SCIENTIFIC) {
+            self.check_for_identifier_start_after_numeric_literal(
+                start,
+                decimal_fragment.is_none() && self.token_flags.contains(TokenFlags::SCIENTIFIC),
+            );
+            // if value is not an integer, it can be safely coerced to a number
+            let value = result.parse::<f64>().unwrap_or(f64::NAN);
+            (SyntaxKind::NumericLiteral, number_to_string(value))
+        } else {
+            let (token, value) = self.check_big_int_suffix(result); // if value is an integer, check whether it is a bigint
+            self.check_for_identifier_start_after_numeric_literal(start, false);
+            (token, value)
+        }
+    }
+
+    fn check_for_identifier_start_after_numeric_literal(
+        &mut self,
+        numeric_start: usize,
+        is_scientific: bool,
+    ) {
+        let language_version = Some(self.language_version);
+        if !self
+            .code_point_at(self.pos)
+            .is_some_and(|ch| is_identifier_start(ch, language_version))
+        {
+            return;
+        }
+
+        let identifier_start = self.pos;
+        let length = self.scan_identifier_parts().len();
+
+        if length == 1 && self.char_at(identifier_start) == Some(CharacterCodes::LowercaseN) {
+            if is_scientific {
+                self.error_at(
+                    &diagnostics::A_BIGINT_LITERAL_CANNOT_USE_EXPONENTIAL_NOTATION,
+                    numeric_start,
+                    identifier_start - numeric_start + 1,
+                );
+            } else {
+                self.error_at(
+                    &diagnostics::A_BIGINT_LITERAL_MUST_BE_AN_INTEGER,
+                    numeric_start,
+                    identifier_start - numeric_start + 1,
+                );
+            }
+        } else {
+            self.error_at(
+                &diagnostics::AN_IDENTIFIER_OR_KEYWORD_CANNOT_IMMEDIATELY_FOLLOW_A_NUMERIC_LITERAL,
+                identifier_start,
+                length,
+            );
+            self.pos = identifier_start;
+        }
+    }
+
+    fn scan_octal_digits(&mut self) -> f64 {
+        let start = self.pos;
+        while self.char_code_at(self.pos).is_some_and(is_octal_digit) {
+            self.pos += 1;
+        }
+        self.substring(start, self.pos)
+            .parse::<f64>()
+            .unwrap_or(f64::NAN)
+    }
+
+    /// Scans as many hexadecimal digits as are available in the text,
+    /// returning "" if the given number of digits was unavailable.
+    fn scan_minimum_number_of_hex_digits(
+        &mut self,
+        count: usize,
+        can_have_separators: bool,
+    ) -> String {
+        self.scan_hex_digits(count, true, can_have_separators)
+    }
+
+    fn scan_hex_digits(
+        &mut self,
+        min_count: usize,
+        scan_as_many_as_possible: bool,
+        can_have_separators: bool,
+    ) -> String {
+        let mut value_chars = String::new();
+        let mut allow_separator = false;
+        let mut is_previous_token_separator = false;
+        while value_chars.len() < min_count || scan_as_many_as_possible {
+            let ch = match self.char_code_at(self.pos) {
+                Some(ch) if self.pos < self.end => ch,
+                _ => break,
+            };
+            if can_have_separators && ch == CharacterCodes::Underscore as u32 {
+                self.token_flags |= TokenFlags::CONTAINS_SEPARATOR;
+                if allow_separator {
+                    allow_separator = false;
+                    is_previous_token_separator = true;
+                } else if is_previous_token_separator {
+                    self.error_at(
+                        &diagnostics::MULTIPLE_CONSECUTIVE_NUMERIC_SEPARATORS_ARE_NOT_PERMITTED,
+                        self.pos,
+                        1,
+                    );
+                } else {
+                    self.error_at(
+                        &diagnostics::NUMERIC_SEPARATORS_ARE_NOT_ALLOWED_HERE,
+                        self.pos,
+                        1,
+                    );
+                }
+                self.pos += 1;
+                continue;
+            }
+            allow_separator = can_have_separators;
+            if !is_hex_digit(ch) {
+                break;
+            }
+            // standardize hex literals to lowercase
+            value_chars.push((ch as u8 as char).to_ascii_lowercase());
+            self.pos += 1;
+            is_previous_token_separator = false;
+        }
+        if value_chars.len() < min_count {
+            value_chars.clear();
+        }
+        if self.pos > 0 && self.char_at(self.pos - 1) == Some(CharacterCodes::Underscore) {
+            self.error_at(
+                &diagnostics::NUMERIC_SEPARATORS_ARE_NOT_ALLOWED_HERE,
+                self.pos - 1,
+                1,
+            );
+        }
+        value_chars
+    }
+
+    fn scan_identifier_parts(&mut self) -> Vec<u16> {
+        let mut result = Vec::new();
+        let mut start = self.pos;
+        while let Some(ch) = self.code_point_at(self.pos) {
+            if is_identifier_part(ch, Some(self.language_version)) {
+                self.pos += char_size(ch);
+            } else if ch == CharacterCodes::Backslash as u32 {
+                match self.peek_unicode_escape() {
+                    Some(ch) if is_identifier_part(ch, Some(self.language_version)) => {
+                        self.push_substring(&mut result, start, self.pos);
+                        result.extend(utf16_encode(ch));
+                        // Valid Unicode escape is always six characters
+                        self.pos += 6;
+                        self.token_flags |= TokenFlags::UNICODE_ESCAPE;
+                        start = self.pos;
+                    }
+                    _ => break,
+                }
+            } else {
+                break;
+            }
+        }
+        self.push_substring(&mut result, start, self.pos);
+        result
+    }
+
+    // Current character is known to be a backslash. Check for Unicode escape of the form '\uXXXX'
+    // and return code point value if valid Unicode escape is found.
+    fn peek_unicode_escape(&mut self) -> Option<u32> {
+        if self.pos + 5 < self.end && self.char_at(self.pos + 1) == Some(CharacterCodes::LowercaseU)
+        {
+            let start = self.pos;
+            self.pos += 2;
+            let value = self.scan_exact_number_of_hex_digits(4, false);
+            self.pos = start;
+            return value;
+        }
+        None
+    }
+
+    fn scan_binary_or_octal_digits(&mut self, base: u32) -> String {
+        let mut value = String::new();
+        // For counting number of digits; Valid binaryIntegerLiteral must have at least one binary digit following B or b.
+        // Similarly valid octalIntegerLiteral must have at least one octal digit following o or O.
+        let mut separator_allowed = false;
+        let mut is_previous_token_separator = false;
+        loop {
+            let ch = self.char_code_at(self.pos);
+            // Numeric separators are allowed anywhere within a numeric literal, except not at the beginning, or following another separator
+            if ch == Some(CharacterCodes::Underscore as u32) {
+                self.token_flags |= TokenFlags::CONTAINS_SEPARATOR;
+                if separator_allowed {
+                    separator_allowed = false;
+                    is_previous_token_separator = true;
+                } else if is_previous_token_separator {
+                    self.error_at(
+                        &diagnostics::MULTIPLE_CONSECUTIVE_NUMERIC_SEPARATORS_ARE_NOT_PERMITTED,
+                        self.pos,
+                        1,
+                    );
+                } else {
+                    self.error_at(
+                        &diagnostics::NUMERIC_SEPARATORS_ARE_NOT_ALLOWED_HERE,
+                        self.pos,
+                        1,
+                    );
+                }
+                self.pos += 1;
+                continue;
+            }
+            separator_allowed = true;
+            match ch {
+                Some(ch) if is_digit(ch) && ch - (CharacterCodes::_0 as u32) < base => {
+                    value.push(ch as u8 as char);
+                    self.pos += 1;
+                    is_previous_token_separator = false;
+                }
+                _ => break,
+            }
+        }
+        if self.pos > 0 && self.char_at(self.pos - 1) == Some(CharacterCodes::Underscore) {
+            // Literal ends with underscore - not allowed
+            self.error_at(
+                &diagnostics::NUMERIC_SEPARATORS_ARE_NOT_ALLOWED_HERE,
+                self.pos - 1,
+                1,
+            );
+        }
+        value
+    }
+
+    fn check_big_int_suffix(&mut self, value: String) -> (SyntaxKind, String) {
+        if self.char_at(self.pos) == Some(CharacterCodes::LowercaseN) {
+            self.pos += 1;
+            // Use base 10 instead of base 2 or base 8 for shorter literals
+            if self
+                .token_flags
+                .intersects(TokenFlags::BINARY_OR_OCTAL_SPECIFIER)
+            {
+                (
+                    SyntaxKind::BigIntLiteral,
+                    parse_pseudo_big_int(&value) + "n",
+                )
+            } else {
+                (SyntaxKind::BigIntLiteral, value + "n")
+            }
+        } else {
+            // not a bigint, so can convert to number in simplified form
+            let numeric_value = if self.token_flags.contains(TokenFlags::BINARY_SPECIFIER) {
+                parse_int(&value[2..], 2) // skip "0b"
+            } else if self.token_flags.contains(TokenFlags::OCTAL_SPECIFIER) {
+                parse_int(&value[2..], 8) // skip "0o"
+            } else if self.token_flags.contains(TokenFlags::HEX_SPECIFIER) {
+                parse_int(&value[2..], 16) // skip "0x"
+            } else {
+                value.parse::<f64>().unwrap_or(f64::NAN)
+            };
+            (SyntaxKind::NumericLiteral, number_to_string(numeric_value))
+        }
+    }
+
+    fn scan_string(&mut self, jsx_attribute_string: bool) -> Vec<u16> {
+        let quote = self.char_code_at(self.pos);
+        self.pos += 1;
+        let mut result = Vec::new();
+        let mut start = self.pos;
+        loop {
+            let ch = match self.char_code_at(self.pos) {
+                Some(ch) => ch,
+                None => {
+                    self.push_substring(&mut result, start, self.pos);
+                    self.token_flags |= TokenFlags::UNTERMINATED;
+                    self.error(&diagnostics::UNTERMINATED_STRING_LITERAL);
+                    break;
+                }
+            };
+            if Some(ch) == quote {
+                self.push_substring(&mut result, start, self.pos);
+                self.pos += 1;
+                break;
+            }
+            if ch == CharacterCodes::Backslash as u32 && !jsx_attribute_string {
+                self.push_substring(&mut result, start, self.pos);
+                result.extend(self.scan_escape_sequence(false));
+                start = self.pos;
+                continue;
+            }
+            if is_line_break(ch) && !jsx_attribute_string {
+                self.push_substring(&mut result, start, self.pos);
+                self.token_flags |= TokenFlags::UNTERMINATED;
+                self.error(&diagnostics::UNTERMINATED_STRING_LITERAL);
+                break;
+            }
+            self.pos += 1;
+        }
+        result
+    }
+
+    /// Sets the current 'token_value' and returns a NoSubstitutionTemplateLiteral or
+    /// a literal component of a TemplateExpression.
+    fn scan_template_and_set_token_value(&mut self, is_tagged_template: bool) -> SyntaxKind {
+        let started_with_backtick = self.char_at(self.pos) == Some(CharacterCodes::Backtick);
+
+        self.pos += 1;
+        let mut start = self.pos;
+        let mut contents = Vec::new();
+        let resulting_token;
+
+        loop {
+            if self.pos >= self.end {
+                self.push_substring(&mut contents, start, self.pos);
+                self.token_flags |= TokenFlags::UNTERMINATED;
+                self.error(&diagnostics::UNTERMINATED_TEMPLATE_LITERAL);
+                resulting_token = if started_with_backtick {
+                    SyntaxKind::NoSubstitutionTemplateLiteral
+                } else {
+                    SyntaxKind::TemplateTail
+                };
+                break;
+            }
+
+            let curr_char = self.char_at(self.pos);
+
+            // '`'
+            if curr_char == Some(CharacterCodes::Backtick) {
+                self.push_substring(&mut contents, start, self.pos);
+                self.pos += 1;
+                resulting_token = if started_with_backtick {
+                    SyntaxKind::NoSubstitutionTemplateLiteral
+                } else {
+                    SyntaxKind::TemplateTail
+                };
+                break;
+            }
+
+            // '${'
+            if curr_char == Some(CharacterCodes::Dollar)
+                && self.pos + 1 < self.end
+                && self.char_at(self.pos + 1) == Some(CharacterCodes::OpenBrace)
+            {
+                self.push_substring(&mut contents, start, self.pos);
+                self.pos += 2;
+                resulting_token = if started_with_backtick {
+                    SyntaxKind::TemplateHead
+                } else {
+                    SyntaxKind::TemplateMiddle
+                };
+                break;
+            }
+
+            // Escape character
+            if curr_char == Some(CharacterCodes::Backslash) {
+                self.push_substring(&mut contents, start, self.pos);
+                contents.extend(self.scan_escape_sequence(is_tagged_template));
+                start = self.pos;
+                continue;
+            }
+
+            // Speculated ECMAScript 6 Spec 11.8.6.1:
+            // <CR><LF> and <CR> LineTerminatorSequences are normalized to <LF> for Template Values
+            if curr_char == Some(CharacterCodes::CarriageReturn) {
+                self.push_substring(&mut contents, start, self.pos);
+                self.pos += 1;
+
+                if self.pos < self.end && self.char_at(self.pos) == Some(CharacterCodes::LineFeed) {
+                    self.pos += 1;
+                }
+
+                contents.push(CharacterCodes::LineFeed as u16);
+                start = self.pos;
+                continue;
+            }
+
+            self.pos += 1;
+        }
+
+        self.token_value = contents;
+        resulting_token
+    }
+
+    /// Scans the escape sequence at the current position. In tagged templates, escapes that would
+    /// be errors elsewhere are kept verbatim and flagged with `TokenFlags::CONTAINS_INVALID_ESCAPE` instead,
+    /// since their cooked value is `undefined` but their raw text is still available.
+    fn scan_escape_sequence(&mut self, is_tagged_template: bool) -> Vec<u16> {
+        let start = self.pos;
+        self.pos += 1;
+        let ch = match self.char_code_at(self.pos) {
+            Some(ch) => ch,
+            None => {
+                self.error(&diagnostics::UNEXPECTED_END_OF_TEXT);
+                return Vec::new();
+            }
+        };
+        self.pos += 1;
+        match FromPrimitive::from_u32(ch) {
+            // '\1' through '\9' and '\0' followed by a digit are not allowed in templates
+            _ if is_tagged_template
+                && is_digit(ch)
+                && (ch != CharacterCodes::_0 as u32
+                    || self.char_code_at(self.pos).is_some_and(is_digit)) =>
+            {
+                self.token_flags |= TokenFlags::CONTAINS_INVALID_ESCAPE;
+                self.substring_utf16(start, self.pos)
+            }
+            // '\0' not followed by a digit is the null character; '\08' is '\0' followed by '8'
+            Some(CharacterCodes::_0) if !self.char_code_at(self.pos).is_some_and(is_digit) => {
+                vec![0]
+            }
+            Some(CharacterCodes::_0)
+            | Some(CharacterCodes::_1)
+            | Some(CharacterCodes::_2)
+            | Some(CharacterCodes::_3)
+            | Some(CharacterCodes::_4)
+            | Some(CharacterCodes::_5)
+            | Some(CharacterCodes::_6)
+            | Some(CharacterCodes::_7) => {
+                // Legacy octal escape: '\1', '\17' and '\177', but not '\477'
+                let max_digits = if ch <= CharacterCodes::_3 as u32 {
+                    3
+                } else {
+                    2
+                };
+                while self.pos - start <= max_digits
+                    && self.pos < self.end
+                    && self.char_code_at(self.pos).is_some_and(is_octal_digit)
+                {
+                    self.pos += 1;
+                }
+                vec![parse_int(&self.substring(start + 1, self.pos), 8) as u16]
+            }
+            Some(CharacterCodes::LowercaseB) => vec![CharacterCodes::Backspace as u16],
+            Some(CharacterCodes::LowercaseT) => vec![CharacterCodes::Tab as u16],
+            Some(CharacterCodes::LowercaseN) => vec![CharacterCodes::LineFeed as u16],
+            Some(CharacterCodes::LowercaseV) => vec![CharacterCodes::VerticalTab as u16],
+            Some(CharacterCodes::LowercaseF) => vec![CharacterCodes::FormFeed as u16],
+            Some(CharacterCodes::LowercaseR) => vec![CharacterCodes::CarriageReturn as u16],
+            Some(CharacterCodes::SingleQuote) => vec![CharacterCodes::SingleQuote as u16],
+            Some(CharacterCodes::DoubleQuote) => vec![CharacterCodes::DoubleQuote as u16],
+            Some(CharacterCodes::LowercaseU) => {
+                // '\u{DDDDDDDD}'
+                if self.pos < self.end && self.char_at(self.pos) == Some(CharacterCodes::OpenBrace)
+                {
+                    self.pos += 1;
+
+                    if is_tagged_template {
+                        // '\u{'
+                        if !self.char_code_at(self.pos).is_some_and(is_hex_digit) {
+                            self.token_flags |= TokenFlags::CONTAINS_INVALID_ESCAPE;
+                            return self.substring_utf16(start, self.pos);
+                        }
+
+                        let save_pos = self.pos;
+                        let escaped_value_string = self.scan_minimum_number_of_hex_digits(1, false);
+                        let escaped_value = parse_int(&escaped_value_string, 16);
+
+                        // '\u{Not Code Point' or '\u{CodePoint'
+                        if !is_code_point(escaped_value)
+                            || self.char_at(self.pos) != Some(CharacterCodes::CloseBrace)
+                        {
+                            self.token_flags |= TokenFlags::CONTAINS_INVALID_ESCAPE;
+                            return self.substring_utf16(start, self.pos);
+                        }
+                        self.pos = save_pos;
+                    }
+
+                    self.token_flags |= TokenFlags::EXTENDED_UNICODE_ESCAPE;
+                    return self.scan_extended_unicode_escape();
+                }
+
+                if is_tagged_template {
+                    // '\u' or '\u0' or '\u00' or '\u000'
+                    let invalid_pos = (self.pos..self.pos + 4).find(|&escape_pos| {
+                        escape_pos < self.end
+                            && !self.char_code_at(escape_pos).is_some_and(is_hex_digit)
+                    });
+                    if let Some(escape_pos) = invalid_pos {
+                        self.pos = escape_pos;
+                        self.token_flags |= TokenFlags::CONTAINS_INVALID_ESCAPE;
+                        return self.substring_utf16(start, self.pos);
+                    }
+                }
+
+                // '\uDDDD'
+                self.scan_hexadecimal_escape(4)
+            }
+            Some(CharacterCodes::LowercaseX) => {
+                if is_tagged_template {
+                    if !self.char_code_at(self.pos).is_some_and(is_hex_digit) {
+                        self.token_flags |= TokenFlags::CONTAINS_INVALID_ESCAPE;
+                        return self.substring_utf16(start, self.pos);
+                    } else if !self.char_code_at(self.pos + 1).is_some_and(is_hex_digit) {
+                        self.pos += 1;
+                        self.token_flags |= TokenFlags::CONTAINS_INVALID_ESCAPE;
+                        return self.substring_utf16(start, self.pos);
+                    }
+                }
+
+                // '\xDD'
+                self.scan_hexadecimal_escape(2)
+            }
+            // when encountering a LineContinuation (i.e. a backslash and a line terminator sequence),
+            // the line terminator is interpreted to be "the empty code unit sequence".
+            Some(CharacterCodes::CarriageReturn) => {
+                if self.pos < self.end && self.char_at(self.pos) == Some(CharacterCodes::LineFeed) {
+                    self.pos += 1;
+                }
+                Vec::new()
+            }
+            Some(CharacterCodes::LineFeed)
+            | Some(CharacterCodes::LineSeparator)
+            | Some(CharacterCodes::ParagraphSeparator) => Vec::new(),
+            _ => self.substring_utf16(self.pos - 1, self.pos),
+        }
+    }
+
+    fn scan_hexadecimal_escape(&mut self, num_digits: usize) -> Vec<u16> {
+        match self.scan_exact_number_of_hex_digits(num_digits, false) {
+            Some(escaped_value) => vec![escaped_value as u16],
+            None => {
+                self.error(&diagnostics::HEXADECIMAL_DIGIT_EXPECTED);
+                Vec::new()
+            }
+        }
+    }
+
+    fn scan_extended_unicode_escape(&mut self) -> Vec<u16> {
+        let escaped_value_string = self.scan_minimum_number_of_hex_digits(1, false);
+        let escaped_value = if escaped_value_string.is_empty() {
+            None
+        } else {
+            Some(parse_int(&escaped_value_string, 16))
+        };
+        let mut is_invalid_extended_escape = false;
+
+        // Validate the value of the digit
+        match escaped_value {
+            None => {
+                self.error(&diagnostics::HEXADECIMAL_DIGIT_EXPECTED);
+                is_invalid_extended_escape = true;
+            }
+            Some(value) if !is_code_point(value) => {
+                self.error(
+                    &diagnostics::AN_EXTENDED_UNICODE_ESCAPE_VALUE_MUST_BE_BETWEEN_0X0_AND_0X10FFFF_INCLUSIVE,
+                );
+                is_invalid_extended_escape = true;
+            }
+            Some(_) => {}
+        }
+
+        if self.pos >= self.end {
+            self.error(&diagnostics::UNEXPECTED_END_OF_TEXT);
+            is_invalid_extended_escape = true;
+        } else if self.char_at(self.pos) == Some(CharacterCodes::CloseBrace) {
+            // Only swallow the following character up if it's a '}'.
+            self.pos += 1;
+        } else {
+            self.error(&diagnostics::UNTERMINATED_UNICODE_ESCAPE_SEQUENCE);
+            is_invalid_extended_escape = true;
+        }
+
+        match escaped_value {
+            Some(value) if !is_invalid_extended_escape => utf16_encode(value as u32),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Scans the given number of hexadecimal digits in the text,
+    /// returning `None` if the given number is unavailable.
+    fn scan_exact_number_of_hex_digits(
+        &mut self,
+        count: usize,
+        can_have_separators: bool,
+    ) -> Option<u32> {
+        let value_string = self.scan_hex_digits(count, false, can_have_separators);
+        u32::from_str_radix(&value_string, 16).ok()
+    }
+
+    fn get_identifier_token(&self) -> SyntaxKind {
+        // Reserved words are between 2 and 11 characters long and start with a lowercase letter
+        let len = self.token_value.len();
+        if (2..=11).contains(&len) {
+            let ch = self.token_value[0];
+            if ch >= CharacterCodes::LowercaseA as u16 && ch <= CharacterCodes::LowercaseZ as u16 {
+                if let Some(keyword) = string_to_token(&String::from_utf16_lossy(&self.token_value))
+                {
+                    return keyword;
+                }
+            }
+        }
+        SyntaxKind::Identifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DiagnosticCategory;
+
+    // The kind, value and flags of every token up to the end of the file.
+    fn scan_all(text: &str, target: ScriptTarget) -> Vec<(SyntaxKind, String, TokenFlags)> {
+        let mut scanner = Scanner::new(true);
+        scanner.set_script_target(target);
+        scanner.set_text(text, None, None);
+        let mut tokens = Vec::new();
+        loop {
+            let token = scanner.scan();
+            if token == SyntaxKind::EndOfFileToken {
+                return tokens;
+            }
+            tokens.push((token, scanner.get_token_value(), scanner.get_token_flags()));
+        }
+    }
+
+    // The code, start and length of every error reported while scanning `text`.
+    fn errors(text: &str, target: ScriptTarget) -> Vec<(u32, usize, usize)> {
+        let mut scanner = Scanner::new(true);
+        scanner.set_script_target(target);
+        scanner.set_text(text, None, None);
+        while scanner.scan() != SyntaxKind::EndOfFileToken {}
+        scanner
+            .get_errors()
+            .iter()
+            .map(|error| (error.code, error.start, error.length))
+            .collect()
+    }
+
+    #[test]
+    fn token_positions() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("a /* b */ c\n  d", None, None);
+        let mut tokens = Vec::new();
+        while scanner.scan() != SyntaxKind::EndOfFileToken {
+            tokens.push((
+                scanner.get_start_pos(),
+                scanner.get_token_pos(),
+                scanner.get_text_pos(),
+                scanner.get_token_text(),
+                scanner.has_preceding_line_break(),
+            ));
+        }
+        assert_eq!(
+            tokens,
+            vec![
+                (0, 0, 1, String::from("a"), false),
+                (1, 10, 11, String::from("c"), false),
+                (11, 14, 15, String::from("d"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn trivia_tokens() {
+        let mut scanner = Scanner::new(false);
+        scanner.set_text("a /* b */\n// c", None, None);
+        let mut kinds = Vec::new();
+        while scanner.scan() != SyntaxKind::EndOfFileToken {
+            kinds.push(scanner.get_token());
+        }
+        assert_eq!(
+            kinds,
+            vec![
+                SyntaxKind::Identifier,
+                SyntaxKind::WhitespaceTrivia,
+                SyntaxKind::MultiLineCommentTrivia,
+                SyntaxKind::NewLineTrivia,
+                SyntaxKind::SingleLineCommentTrivia,
+            ]
+        );
+    }
+
+    #[test]
+    fn text_ranges() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("abc def ghi", Some(4), Some(3));
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!(scanner.get_token_value(), "def");
+        assert_eq!(scanner.scan(), SyntaxKind::EndOfFileToken);
+        assert_eq!(scanner.get_text_pos(), 7);
+    }
+
+    #[test]
+    fn ranges_past_the_end_are_clamped() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("a 'b", Some(0), Some(10));
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!(scanner.scan(), SyntaxKind::StringLiteral);
+        assert!(scanner.is_unterminated());
+        assert_eq!(scanner.scan(), SyntaxKind::EndOfFileToken);
+        assert_eq!(scanner.get_text_pos(), 4);
+
+        let scanned = scanner.scan_range(2, 10, |scanner| {
+            (scanner.scan(), scanner.get_token_value(), scanner.scan())
+        });
+        assert_eq!(
+            scanned,
+            (
+                SyntaxKind::StringLiteral,
+                String::from("b"),
+                SyntaxKind::EndOfFileToken
+            )
+        );
+        assert_eq!(scanner.scan(), SyntaxKind::EndOfFileToken);
+    }
+
+    fn jsx_scanner(text: &str) -> Scanner {
+        let mut scanner = Scanner::new(true);
+        scanner.set_language_variant(LanguageVariant::JSX);
+        scanner.set_text(text, None, None);
+        scanner
+    }
+
+    #[test]
+    fn jsx_tokens() {
+        let mut scanner = jsx_scanner("<my-div data-x='a\\b\nc'>  hello {x}</my-div>");
+        assert_eq!(scanner.scan(), SyntaxKind::LessThanToken);
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!(scanner.scan_jsx_identifier(), SyntaxKind::Identifier);
+        assert_eq!(scanner.get_token_value(), "my-div");
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!(scanner.scan_jsx_identifier(), SyntaxKind::Identifier);
+        assert_eq!(scanner.get_token_value(), "data-x");
+        assert_eq!(scanner.scan(), SyntaxKind::EqualsToken);
+        // Attribute strings have no escapes and can span lines.
+        assert_eq!(
+            scanner.scan_jsx_attribute_value(),
+            SyntaxKind::StringLiteral
+        );
+        assert_eq!(scanner.get_token_value(), "a\\b\nc");
+        assert_eq!(scanner.scan(), SyntaxKind::GreaterThanToken);
+        assert_eq!(scanner.scan_jsx_token(), SyntaxKind::JsxText);
+        assert_eq!(scanner.get_token_value(), "  hello ");
+        assert_eq!(scanner.scan_jsx_token(), SyntaxKind::OpenBraceToken);
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!(scanner.scan(), SyntaxKind::CloseBraceToken);
+        assert_eq!(scanner.scan_jsx_token(), SyntaxKind::LessThanSlashToken);
+    }
+
+    #[test]
+    fn jsx_text_whitespace() {
+        let text_kind = |text: &str| {
+            let mut scanner = jsx_scanner(text);
+            scanner.scan();
+            scanner.scan();
+            scanner.scan();
+            scanner.scan_jsx_token()
+        };
+        assert_eq!(
+            text_kind("<a>\n   \n</a>"),
+            SyntaxKind::JsxTextAllWhiteSpaces
+        );
+        assert_eq!(text_kind("<a>    </a>"), SyntaxKind::JsxText);
+        assert_eq!(text_kind("<a>\n  b\n</a>"), SyntaxKind::JsxText);
+        assert_eq!(
+            text_kind("<a>  \n  </a>"),
+            SyntaxKind::JsxTextAllWhiteSpaces
+        );
+    }
+
+    #[test]
+    fn re_scan_less_than_token() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("a<</b>", None, None);
+        scanner.scan();
+        assert_eq!(scanner.scan(), SyntaxKind::LessThanLessThanToken);
+        assert_eq!(scanner.re_scan_less_than_token(), SyntaxKind::LessThanToken);
+        assert_eq!(scanner.get_text_pos(), 2);
+    }
+
+    fn token(text: &str) -> (SyntaxKind, String) {
+        let (kind, value, _) = scan_all(text, ScriptTarget::Latest).remove(0);
+        (kind, value)
+    }
+
+    #[test]
+    fn numeric_literal_values() {
+        let numeric = |value: &str| (SyntaxKind::NumericLiteral, String::from(value));
+        assert_eq!(token("123"), numeric("123"));
+        assert_eq!(token("1.50"), numeric("1.5"));
+        assert_eq!(token(".5"), numeric("0.5"));
+        assert_eq!(token("1e21"), numeric("1e+21"));
+        assert_eq!(token("1e-7"), numeric("1e-7"));
+        assert_eq!(token("1.5e3"), numeric("1500"));
+        assert_eq!(token("0.000001"), numeric("0.000001"));
+        assert_eq!(
+            token("123456789012345678901234567890"),
+            numeric("1.2345678901234568e+29")
+        );
+        assert_eq!(token("0xFF"), numeric("255"));
+        assert_eq!(token("0b1010"), numeric("10"));
+        assert_eq!(token("0o17"), numeric("15"));
+        assert_eq!(token("09"), numeric("9"));
+        assert_eq!(token("1_000_000"), numeric("1000000"));
+        assert_eq!(token("1_0.2_5e1_0"), numeric("102500000000"));
+    }
+
+    #[test]
+    fn big_int_literal_values() {
+        let big_int = |value: &str| (SyntaxKind::BigIntLiteral, String::from(value));
+        assert_eq!(token("123n"), big_int("123n"));
+        assert_eq!(token("0xFFn"), big_int("0xffn"));
+        assert_eq!(token("0o777n"), big_int("511n"));
+        assert_eq!(
+            token("0b1111111111111111111111111111111111111111111111111111111111111111111111n"),
+            big_int("1180591620717411303423n")
+        );
+    }
+
+    #[test]
+    fn numeric_literal_flags() {
+        let flags = |text: &str| scan_all(text, ScriptTarget::Latest)[0].2;
+        assert_eq!(flags("1e5"), TokenFlags::SCIENTIFIC);
+        assert_eq!(flags("0x1"), TokenFlags::HEX_SPECIFIER);
+        assert_eq!(flags("0b1"), TokenFlags::BINARY_SPECIFIER);
+        assert_eq!(flags("0o1"), TokenFlags::OCTAL_SPECIFIER);
+        assert_eq!(
+            flags("0x1_0"),
+            TokenFlags::HEX_SPECIFIER | TokenFlags::CONTAINS_SEPARATOR
+        );
+    }
+
+    #[test]
+    fn numeric_literal_errors() {
+        let latest = |text: &str| errors(text, ScriptTarget::Latest);
+        assert_eq!(latest("1__0"), vec![(6189, 2, 1)]);
+        assert_eq!(latest("1_"), vec![(6188, 1, 1)]);
+        assert_eq!(latest("1._5"), vec![(6188, 2, 1)]);
+        assert_eq!(latest("0x_1"), vec![(6188, 2, 1)]);
+        assert_eq!(latest("1e"), vec![(1124, 2, 0)]);
+        assert_eq!(latest("0b2"), vec![(1177, 2, 0)]);
+        assert_eq!(latest("0o8"), vec![(1178, 2, 0)]);
+        assert_eq!(latest("1.5n"), vec![(1353, 0, 4)]);
+        assert_eq!(latest("1e5n"), vec![(1352, 0, 4)]);
+        assert_eq!(latest("3in"), vec![(1351, 1, 2)]);
+        assert_eq!(
+            scan_all("3in", ScriptTarget::Latest)
+                .into_iter()
+                .map(|(kind, _, _)| kind)
+                .collect::<Vec<_>>(),
+            vec![SyntaxKind::NumericLiteral, SyntaxKind::InKeyword]
+        );
+    }
+
+    // Like TypeScript, the value of a legacy octal literal is its digits read as decimal.
+    #[test]
+    fn legacy_octal_literals() {
+        for &target in &[
+            ScriptTarget::ES3,
+            ScriptTarget::ES5,
+            ScriptTarget::ES2015,
+            ScriptTarget::ES2016,
+            ScriptTarget::ES2017,
+            ScriptTarget::ES2018,
+            ScriptTarget::ES2019,
+            ScriptTarget::ESNext,
+        ] {
+            assert_eq!(
+                scan_all("010", target),
+                vec![(
+                    SyntaxKind::NumericLiteral,
+                    String::from("10"),
+                    TokenFlags::OCTAL
+                )]
+            );
+            let expected = if target == ScriptTarget::ES3 {
+                vec![]
+            } else {
+                vec![(1085, 0, 3)]
+            };
+            assert_eq!(errors("010", target), expected, "{:?}", target);
+        }
+    }
+
+    #[test]
+    fn string_literal_values() {
+        let string = |value: &str| (SyntaxKind::StringLiteral, String::from(value));
+        assert_eq!(token(r#""abc""#), string("abc"));
+        assert_eq!(token(r#"'a\nb'"#), string("a\nb"));
+        assert_eq!(token(r#""\x41\u0042""#), string("AB"));
+        assert_eq!(token(r#""\u{1F600}""#), string("\u{1F600}"));
+        assert_eq!(token(r#""\u{10FFFF}""#), string("\u{10FFFF}"));
+        assert_eq!(token("\"a\\\r\nb\""), string("ab"));
+        assert_eq!(token(r#""\08""#), string("\u{0}8"));
+        assert_eq!(token(r#""\101\477""#), string("A'7"));
+    }
+
+    #[test]
+    fn string_literal_code_units() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text(r#""\uD800" "\u{10FFFF}""#, None, None);
+        assert_eq!(scanner.scan(), SyntaxKind::StringLiteral);
+        assert_eq!(scanner.get_token_value_utf16(), vec![0xD800]);
+        assert_eq!(scanner.scan(), SyntaxKind::StringLiteral);
+        assert_eq!(scanner.get_token_value_utf16(), vec![0xDBFF, 0xDFFF]);
+    }
+
+    #[test]
+    fn string_literal_errors() {
+        let latest = |text: &str| errors(text, ScriptTarget::Latest);
+        assert_eq!(latest(r#""abc"#), vec![(1002, 4, 0)]);
+        assert_eq!(latest("'a\nb'"), vec![(1002, 2, 0), (1002, 5, 0)]);
+        assert_eq!(latest(r#""\xZ""#), vec![(1125, 3, 0)]);
+        assert_eq!(latest(r#""\u{}""#), vec![(1125, 4, 0)]);
+        assert_eq!(latest(r#""\u{110000}""#), vec![(1198, 10, 0)]);
+        assert_eq!(latest(r#""\u{12""#), vec![(1199, 6, 0)]);
+        assert!(scan_all(r#""abc"#, ScriptTarget::Latest)[0]
+            .2
+            .contains(TokenFlags::UNTERMINATED));
+    }
+
+    #[test]
+    fn template_literal_values() {
+        let template = |value: &str| {
+            (
+                SyntaxKind::NoSubstitutionTemplateLiteral,
+                String::from(value),
+            )
+        };
+        assert_eq!(token("`abc`"), template("abc"));
+        assert_eq!(token("`a\r\nb`"), template("a\nb"));
+        assert_eq!(token("`a${"), (SyntaxKind::TemplateHead, String::from("a")));
+        assert_eq!(errors("`abc", ScriptTarget::Latest), vec![(1160, 4, 0)]);
+    }
+
+    #[test]
+    fn re_scan_template_token() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("`a${x}b\\n${y}c\r`", None, None);
+        assert_eq!(scanner.scan(), SyntaxKind::TemplateHead);
+        assert_eq!(scanner.get_token_value(), "a");
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!(scanner.scan(), SyntaxKind::CloseBraceToken);
+        assert_eq!(
+            scanner.re_scan_template_token(false),
+            SyntaxKind::TemplateMiddle
+        );
+        assert_eq!(scanner.get_token_value(), "b\n");
+        assert_eq!(scanner.get_template_raw_text(), "b\\n");
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!(scanner.scan(), SyntaxKind::CloseBraceToken);
+        assert_eq!(
+            scanner.re_scan_template_token(false),
+            SyntaxKind::TemplateTail
+        );
+        assert_eq!(scanner.get_token_value(), "c\n");
+        assert_eq!(scanner.get_template_raw_text(), "c\n");
+        assert_eq!(scanner.scan(), SyntaxKind::EndOfFileToken);
+        assert!(scanner.get_errors().is_empty());
+    }
+
+    #[test]
+    fn tagged_template_invalid_escapes() {
+        let text = r"`\unicode \xg \u{110000} \1 \u{41}`";
+        let mut scanner = Scanner::new(true);
+        scanner.set_text(text, None, None);
+        assert_eq!(scanner.scan(), SyntaxKind::NoSubstitutionTemplateLiteral);
+        assert!(!scanner.has_invalid_escape());
+        assert_eq!(scanner.get_token_value(), "nicode g  \u{1} A");
+        let reported = vec![(1125, 3, 0), (1125, 12, 0), (1198, 23, 0)];
+        assert_eq!(errors(text, ScriptTarget::Latest), reported);
+
+        // A tagged template keeps the raw text of a bad escape and reports nothing new.
+        assert_eq!(
+            scanner.re_scan_template_head_or_no_substitution_template(),
+            SyntaxKind::NoSubstitutionTemplateLiteral
+        );
+        assert!(scanner.has_invalid_escape());
+        assert_eq!(scanner.get_token_value(), r"\unicode \xg \u{110000} \1 A");
+        assert_eq!(scanner.get_template_raw_text(), &text[1..text.len() - 1]);
+        assert_eq!(scanner.get_errors().len(), reported.len());
+    }
+
+    // The kind, value and errors of `text` rescanned as a regular expression.
+    fn regex(text: &str) -> (SyntaxKind, String, Vec<(u32, usize, usize)>) {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text(text, None, None);
+        scanner.scan();
+        let kind = scanner.re_scan_slash_token();
+        let errors = scanner
+            .get_errors()
+            .iter()
+            .map(|error| (error.code, error.start, error.length))
+            .collect();
+        (kind, scanner.get_token_value(), errors)
+    }
+
+    #[test]
+    fn regular_expression_literals() {
+        let literal = |value: &str| {
+            (
+                SyntaxKind::RegularExpressionLiteral,
+                String::from(value),
+                vec![],
+            )
+        };
+        assert_eq!(regex("/a[/]\\/b/gi;"), literal("/a[/]\\/b/gi"));
+        assert_eq!(regex("/=a/"), literal("/=a/"));
+        assert_eq!(regex("/a/gimsuy"), literal("/a/gimsuy"));
+    }
+
+    #[test]
+    fn regular_expression_flag_errors() {
+        let (_, value, errors) = regex("/a/gxg");
+        assert_eq!(value, "/a/gxg");
+        assert_eq!(errors, vec![(1499, 4, 1), (1500, 5, 1)]);
+
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("/a/gxg", None, None);
+        scanner.scan();
+        scanner.re_scan_slash_token();
+        let messages: Vec<_> = scanner
+            .get_errors()
+            .iter()
+            .map(|error| error.message_text.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Unknown regular expression flag.",
+                "Duplicate regular expression flag."
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_regular_expressions() {
+        let unterminated = |value: &str| {
+            (
+                SyntaxKind::RegularExpressionLiteral,
+                String::from(value),
+                vec![(1161, 1, 0)],
+            )
+        };
+        assert_eq!(regex("/abc\nx/"), unterminated("/abc"));
+        assert_eq!(regex("/[/"), unterminated("/[/"));
+        assert_eq!(regex("/a\\/"), unterminated("/a\\/"));
+    }
+
+    #[test]
+    fn jsdoc_tokens() {
+        let mut scanner = Scanner::new(false);
+        scanner.set_text("@param {string} `a b` x.y\n *", None, None);
+        let mut tokens = Vec::new();
+        loop {
+            let token = scanner.scan_jsdoc_token();
+            if token == SyntaxKind::EndOfFileToken {
+                break;
+            }
+            tokens.push((token, scanner.get_token_text()));
+        }
+        let expected: Vec<_> = vec![
+            (SyntaxKind::AtToken, "@"),
+            (SyntaxKind::Identifier, "param"),
+            (SyntaxKind::WhitespaceTrivia, " "),
+            (SyntaxKind::OpenBraceToken, "{"),
+            (SyntaxKind::StringKeyword, "string"),
+            (SyntaxKind::CloseBraceToken, "}"),
+            (SyntaxKind::WhitespaceTrivia, " "),
+            (SyntaxKind::NoSubstitutionTemplateLiteral, "`a b`"),
+            (SyntaxKind::WhitespaceTrivia, " "),
+            (SyntaxKind::Identifier, "x"),
+            (SyntaxKind::DotToken, "."),
+            (SyntaxKind::Identifier, "y"),
+            (SyntaxKind::NewLineTrivia, "\n"),
+            (SyntaxKind::WhitespaceTrivia, " "),
+            (SyntaxKind::AsteriskToken, "*"),
+        ]
+        .into_iter()
+        .map(|(kind, text)| (kind, String::from(text)))
+        .collect();
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn jsdoc_type_skips_line_decorations() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("string\n * number * x", None, None);
+        assert_eq!(scanner.scan(), SyntaxKind::StringKeyword);
+        assert_eq!(scanner.scan(), SyntaxKind::AsteriskToken);
+
+        scanner.set_in_jsdoc_type(true);
+        scanner.set_text("string\n * number * x", None, None);
+        assert_eq!(scanner.scan(), SyntaxKind::StringKeyword);
+        assert_eq!(scanner.scan(), SyntaxKind::NumberKeyword);
+        assert!(scanner.has_preceding_line_break());
+        assert_eq!(scanner.scan(), SyntaxKind::AsteriskToken);
+    }
+
+    #[test]
+    fn conflict_marker_trivia() {
+        let text = "x\n<<<<<<< HEAD\na\n||||||| base\nb\n=======\nc\n>>>>>>> other\n";
+        let mut scanner = Scanner::new(false);
+        scanner.set_text(text, None, None);
+        let mut markers = Vec::new();
+        while scanner.scan() != SyntaxKind::EndOfFileToken {
+            if scanner.get_token() == SyntaxKind::ConflictMarkerTrivia {
+                markers.push(scanner.get_token_text());
+            }
+        }
+        assert_eq!(
+            markers,
+            vec![
+                "<<<<<<< HEAD",
+                "||||||| base\nb\n",
+                "=======\nc\n",
+                ">>>>>>> other"
+            ]
+        );
+        assert_eq!(
+            errors(text, ScriptTarget::Latest),
+            vec![(1185, 2, 7), (1185, 17, 7), (1185, 32, 7), (1185, 42, 7)]
+        );
+        assert_eq!(
+            scanner.get_errors()[0].message_text,
+            "Merge conflict marker encountered."
+        );
+    }
+
+    #[test]
+    fn conflict_markers_start_a_line() {
+        let kinds = |text: &str| -> Vec<SyntaxKind> {
+            scan_all(text, ScriptTarget::Latest)
+                .into_iter()
+                .map(|(kind, _, _)| kind)
+                .collect()
+        };
+        assert_eq!(
+            kinds("<<<<<<<"),
+            vec![
+                SyntaxKind::LessThanLessThanToken,
+                SyntaxKind::LessThanLessThanToken,
+                SyntaxKind::LessThanLessThanToken,
+                SyntaxKind::LessThanToken,
+            ]
+        );
+        assert!(errors(" <<<<<<< x", ScriptTarget::Latest).is_empty());
+        assert!(kinds("x\n>>>>>>> y").ends_with(&[SyntaxKind::Identifier]));
+        assert_eq!(
+            errors("x\n>>>>>>> y", ScriptTarget::Latest),
+            vec![(1185, 2, 7)]
+        );
+    }
+
+    #[test]
+    fn shebang_trivia() {
+        let mut scanner = Scanner::new(false);
+        scanner.set_text("#!/usr/bin/env node\nx", None, None);
+        assert_eq!(scanner.scan(), SyntaxKind::ShebangTrivia);
+        assert_eq!(scanner.get_token_text(), "#!/usr/bin/env node");
+        assert_eq!(scanner.scan(), SyntaxKind::NewLineTrivia);
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+
+        assert_eq!(get_shebang("#!node\r\nx"), Some(String::from("#!node")));
+        assert_eq!(get_shebang("#!a\u{2028}b"), Some(String::from("#!a")));
+        assert_eq!(get_shebang("#!"), Some(String::from("#!")));
+        assert_eq!(get_shebang("x\n#!node"), None);
+    }
+
+    #[test]
+    fn skip_trivia_positions() {
+        let skip = |text: &str, pos: i32| skip_trivia(&SourceText::from(text), pos, None, None);
+        assert_eq!(skip("  // c\n  /* d */ x", 0), 17);
+        assert_eq!(skip("  // c\n  /* d */ x", 17), 17);
+        assert_eq!(skip("\u{3000}\r\ny", 0), 3);
+        assert_eq!(skip("/*\u{1F600}*/x", 0), 6);
+        assert_eq!(skip("/* a", 0), 4);
+        assert_eq!(skip("  ", 0), 2);
+        assert_eq!(skip("  ", 5), 5);
+        assert_eq!(skip("#!a\n<<<<<<< H\n=======\nb\n>>>>>>> o\nq", 0), 34);
+        assert_eq!(skip("x #!a", 1), 2);
+        // Synthesized positions are returned as-is
+        assert_eq!(skip("x", -1), -1);
+    }
+
+    #[test]
+    fn skip_trivia_stops() {
+        let text = SourceText::from("  // c\n  x");
+        assert_eq!(skip_trivia(&text, 0, Some(true), None), 7);
+        assert_eq!(skip_trivia(&text, 0, None, Some(true)), 2);
+        assert_eq!(skip_trivia(&text, 0, Some(true), Some(true)), 2);
+        assert_eq!(skip_trivia_in(&text, 2, true, false), 7);
+        assert_eq!(skip_trivia_in(&text, 7, false, true), 9);
+    }
+
+    fn comment(
+        pos: usize,
+        end: usize,
+        kind: SyntaxKind,
+        has_trailing_new_line: bool,
+    ) -> CommentRange {
+        CommentRange {
+            pos,
+            end,
+            kind,
+            has_trailing_new_line,
+        }
+    }
+
+    const COMMENTS: &str = "#!sh\n// a\n/* b */ // c\n\nx /* d */ // e\ny";
+
+    #[test]
+    fn leading_comment_ranges() {
+        let text = SourceText::from(COMMENTS);
+        assert_eq!(
+            get_leading_comment_ranges(&text, 0),
+            vec![
+                comment(5, 9, SyntaxKind::SingleLineCommentTrivia, true),
+                comment(10, 17, SyntaxKind::MultiLineCommentTrivia, false),
+                comment(18, 22, SyntaxKind::SingleLineCommentTrivia, true),
+            ]
+        );
+        assert!(get_leading_comment_ranges(&text, 25).is_empty());
+
+        // Away from the start of the file, leading comments only begin after a line break
+        let text = SourceText::from("x /* a */\n/* b */y");
+        assert_eq!(
+            get_leading_comment_ranges(&text, 1),
+            vec![comment(10, 17, SyntaxKind::MultiLineCommentTrivia, false)]
+        );
+    }
+
+    #[test]
+    fn trailing_comment_ranges() {
+        let text = SourceText::from(COMMENTS);
+        assert_eq!(
+            get_trailing_comment_ranges(&text, 25),
+            vec![
+                comment(26, 33, SyntaxKind::MultiLineCommentTrivia, false),
+                comment(34, 38, SyntaxKind::SingleLineCommentTrivia, true),
+            ]
+        );
+        assert!(get_trailing_comment_ranges(&text, 38).is_empty());
+    }
+
+    #[test]
+    fn comment_range_callbacks() {
+        let text = SourceText::from(COMMENTS);
+        let first_multi_line = for_each_leading_comment_range(&text, 0, |range| {
+            if range.kind == SyntaxKind::MultiLineCommentTrivia {
+                Some(range.pos)
+            } else {
+                None
+            }
+        });
+        assert_eq!(first_multi_line, Some(10));
+        assert_eq!(
+            for_each_trailing_comment_range(&text, 25, |_| None::<()>),
+            None
+        );
+        let length = reduce_each_trailing_comment_range(
+            &text,
+            25,
+            |range, length| length + range.end - range.pos,
+            0,
+        );
+        assert_eq!(length, 11);
+    }
+
+    #[test]
+    fn source_text_code_units() {
+        let text = SourceText::new("a\u{1F600}");
+        assert_eq!(text.len(), 3);
+        assert_eq!(text.char_code_at(1), Some(0xD83D));
+        assert_eq!(text.char_code_at(2), Some(0xDE00));
+        assert_eq!(text.char_code_at(3), None);
+        assert_eq!(text.code_point_at(1), Some(0x1F600));
+        assert_eq!(text.code_point_at(2), Some(0xDE00));
+        assert_eq!(text.substring(1, 3), "\u{1F600}");
+        assert_eq!(text.substring(0, 2), "a\u{FFFD}");
+        assert_eq!(text.slice(2, 10), &[0xDE00]);
+        assert_eq!(text.to_string(), "a\u{1F600}");
+    }
+
+    #[test]
+    fn positions_count_code_units() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("'\u{1F600}' x `\u{1F600}${", None, None);
+        assert_eq!(scanner.scan(), SyntaxKind::StringLiteral);
+        assert_eq!((scanner.get_token_pos(), scanner.get_text_pos()), (0, 4));
+        assert_eq!(scanner.get_token_value(), "\u{1F600}");
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!((scanner.get_token_pos(), scanner.get_text_pos()), (5, 6));
+        assert_eq!(scanner.scan(), SyntaxKind::TemplateHead);
+        assert_eq!((scanner.get_token_pos(), scanner.get_text_pos()), (7, 12));
+        assert_eq!(scanner.get_token_text(), "`\u{1F600}${");
+    }
+
+    #[test]
+    fn could_start_trivia_positions() {
+        // Any code unit above ASCII may start trivia, including either half of a surrogate pair
+        let text = SourceText::from("\u{1F600} a#");
+        assert!(could_start_trivia(&text, 0));
+        assert!(could_start_trivia(&text, 1));
+        assert!(could_start_trivia(&text, 2));
+        assert!(!could_start_trivia(&text, 3));
+        assert!(!could_start_trivia(&text, 4));
+        assert!(!could_start_trivia(&text, 5));
+        assert!(could_start_trivia(&SourceText::from("#!"), 0));
+    }
+
+    #[test]
+    fn unicode_identifier_tables() {
+        // U+0221 was assigned in Unicode 4.0, after the ES3 tables were drawn up
+        assert!(!is_identifier_start(0x221, Some(ScriptTarget::ES3)));
+        assert!(is_identifier_start(0x221, Some(ScriptTarget::ES5)));
+        assert!(is_identifier_start(0x221, Some(ScriptTarget::ESNext)));
+        // Astral letters are only identifiers from ES2015 on
+        assert!(!is_identifier_start(0x1D465, Some(ScriptTarget::ES5)));
+        assert!(is_identifier_start(0x1D465, Some(ScriptTarget::ES2015)));
+        assert!(is_identifier_part(0x0301, Some(ScriptTarget::ES3)));
+        assert!(!is_identifier_start(0x0301, Some(ScriptTarget::ES3)));
+        assert!(!is_identifier_start(0x00D7, Some(ScriptTarget::ESNext)));
+    }
+
+    #[test]
+    fn identifier_text() {
+        let latest = Some(ScriptTarget::ESNext);
+        assert!(is_identifier_text("h\u{E9}llo", latest));
+        assert!(is_identifier_text("\u{1D465}y", latest));
+        assert!(!is_identifier_text("\u{1D465}y", Some(ScriptTarget::ES5)));
+        assert!(!is_identifier_text("", latest));
+        assert!(!is_identifier_text("1a", latest));
+        assert!(!is_identifier_text("a-b", latest));
+    }
+
+    #[test]
+    fn unicode_identifiers_per_target() {
+        let text = "\u{43F}\u{440}\u{438} \u{53D8}\u{91CF} \u{221} \u{1D465}1";
+        let identifiers = |target: ScriptTarget| -> Vec<String> {
+            scan_all(text, target)
+                .into_iter()
+                .filter(|(kind, _, _)| *kind == SyntaxKind::Identifier)
+                .map(|(_, value, _)| value)
+                .collect()
+        };
+        assert_eq!(
+            identifiers(ScriptTarget::ES3),
+            vec!["\u{43F}\u{440}\u{438}", "\u{53D8}\u{91CF}"]
+        );
+        assert_eq!(
+            identifiers(ScriptTarget::ES5),
+            vec!["\u{43F}\u{440}\u{438}", "\u{53D8}\u{91CF}", "\u{221}"]
+        );
+        assert_eq!(
+            identifiers(ScriptTarget::ESNext),
+            vec![
+                "\u{43F}\u{440}\u{438}",
+                "\u{53D8}\u{91CF}",
+                "\u{221}",
+                "\u{1D465}1"
+            ]
+        );
+        assert_eq!(
+            errors(text, ScriptTarget::ES3),
+            vec![(1127, 7, 0), (1127, 9, 0)]
+        );
+        assert_eq!(errors(text, ScriptTarget::ESNext), vec![]);
+    }
+
+    #[test]
+    fn unicode_escapes_in_identifiers() {
+        let (kind, value, flags) = scan_all("\\u0061b\\u0063", ScriptTarget::Latest).remove(0);
+        assert_eq!((kind, value.as_str()), (SyntaxKind::Identifier, "abc"));
+        assert!(flags.contains(TokenFlags::UNICODE_ESCAPE));
+        assert_eq!(token("\\u0069f").0, SyntaxKind::IfKeyword);
+    }
+
+    #[test]
+    fn string_to_token_round_trips() {
+        let mut count = 0;
+        for kind in 0..SyntaxKind::Count as u32 {
+            if let Some(text) = token_to_string(kind) {
+                assert_eq!(
+                    string_to_token(&text).map(|t| t as u32),
+                    Some(kind),
+                    "{}",
+                    text
+                );
+                count += 1;
+            }
+        }
+        assert_eq!(count, 129);
+    }
+
+    #[test]
+    fn string_to_token_lookups() {
+        assert_eq!(
+            string_to_token("=>"),
+            Some(SyntaxKind::EqualsGreaterThanToken)
+        );
+        assert_eq!(
+            string_to_token(">>>="),
+            Some(SyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken)
+        );
+        assert_eq!(string_to_token("..."), Some(SyntaxKind::DotDotDotToken));
+        assert_eq!(string_to_token("@"), Some(SyntaxKind::AtToken));
+        assert_eq!(string_to_token("keyof"), Some(SyntaxKind::KeyOfKeyword));
+        assert_eq!(
+            string_to_token("instanceof"),
+            Some(SyntaxKind::InstanceOfKeyword)
+        );
+        assert_eq!(
+            string_to_token("constructor"),
+            Some(SyntaxKind::ConstructorKeyword)
+        );
+        assert_eq!(string_to_token("constructo"), None);
+        assert_eq!(string_to_token("Constructor"), None);
+        assert_eq!(string_to_token("if "), None);
+        assert_eq!(string_to_token("#"), None);
+        assert_eq!(string_to_token("\u{FC}nique"), None);
+        assert_eq!(string_to_token(""), None);
+    }
+
+    #[test]
+    fn token_flags_per_token() {
+        let flags: Vec<_> = scan_all(
+            "0x10 16\n1e3 0b1_0 /** doc */ a\\u0062 \"\\u{62}\" 'x",
+            ScriptTarget::Latest,
+        )
+        .into_iter()
+        .map(|(_, _, flags)| flags)
+        .collect();
+        assert_eq!(
+            flags,
+            vec![
+                TokenFlags::HEX_SPECIFIER,
+                TokenFlags::empty(),
+                TokenFlags::PRECEDING_LINE_BREAK | TokenFlags::SCIENTIFIC,
+                TokenFlags::BINARY_SPECIFIER | TokenFlags::CONTAINS_SEPARATOR,
+                TokenFlags::PRECEDING_JSDOC_COMMENT | TokenFlags::UNICODE_ESCAPE,
+                TokenFlags::EXTENDED_UNICODE_ESCAPE,
+                TokenFlags::UNTERMINATED,
+            ]
+        );
+    }
+
+    #[test]
+    fn token_flag_accessors() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("/** a */\n0o1_7 '\\u{62}", None, None);
+        scanner.scan();
+        assert!(scanner.has_preceding_line_break());
+        assert_eq!(
+            scanner.get_token_flags_bits(),
+            (TokenFlags::PRECEDING_LINE_BREAK
+                | TokenFlags::PRECEDING_JSDOC_COMMENT
+                | TokenFlags::OCTAL_SPECIFIER
+                | TokenFlags::CONTAINS_SEPARATOR)
+                .bits()
+        );
+        // The numeric literal flags leave out everything that is not about the number itself
+        assert_eq!(
+            scanner.get_numeric_literal_flags(),
+            TokenFlags::OCTAL_SPECIFIER | TokenFlags::CONTAINS_SEPARATOR
+        );
+        assert_eq!(scanner.get_numeric_literal_flags_bits(), 256 | 512);
+
+        scanner.scan();
+        assert!(!scanner.has_preceding_line_break());
+        assert!(scanner.has_extended_unicode_escape());
+        assert!(!scanner.has_unicode_escape());
+        assert!(scanner.is_unterminated());
+    }
+
+    // The bits are part of the JavaScript API, so they must stay those of `ts.TokenFlags`.
+    #[test]
+    fn token_flag_bits() {
+        assert_eq!(TokenFlags::PRECEDING_LINE_BREAK.bits(), 1);
+        assert_eq!(TokenFlags::PRECEDING_JSDOC_COMMENT.bits(), 2);
+        assert_eq!(TokenFlags::UNTERMINATED.bits(), 4);
+        assert_eq!(TokenFlags::EXTENDED_UNICODE_ESCAPE.bits(), 8);
+        assert_eq!(TokenFlags::SCIENTIFIC.bits(), 16);
+        assert_eq!(TokenFlags::OCTAL.bits(), 32);
+        assert_eq!(TokenFlags::HEX_SPECIFIER.bits(), 64);
+        assert_eq!(TokenFlags::BINARY_SPECIFIER.bits(), 128);
+        assert_eq!(TokenFlags::OCTAL_SPECIFIER.bits(), 256);
+        assert_eq!(TokenFlags::CONTAINS_SEPARATOR.bits(), 512);
+        assert_eq!(TokenFlags::UNICODE_ESCAPE.bits(), 1024);
+        assert_eq!(TokenFlags::NUMERIC_LITERAL_FLAGS.bits(), 1008);
+    }
+
+    #[test]
+    fn scanner_diagnostics() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("0777 'a", None, None);
+        while scanner.scan() != SyntaxKind::EndOfFileToken {}
+        assert_eq!(
+            scanner.get_errors(),
+            &[
+                Diagnostic {
+                    start: 0,
+                    length: 4,
+                    message_text: String::from(
+                        "Octal literals are not available when targeting ECMAScript 5 and higher. Use the syntax '0o777'."
+                    ),
+                    category: DiagnosticCategory::Error,
+                    code: 1085,
+                },
+                Diagnostic {
+                    start: 7,
+                    length: 0,
+                    message_text: String::from("Unterminated string literal."),
+                    category: DiagnosticCategory::Error,
+                    code: 1002,
+                },
+            ]
+        );
+
+        // Setting new text starts a fresh list
+        scanner.set_text("a", None, None);
+        assert!(scanner.get_errors().is_empty());
+    }
+
+    // What a speculative scan must put back: position, token, value, flags and errors.
+    fn snapshot(scanner: &Scanner) -> (usize, usize, SyntaxKind, String, TokenFlags, usize) {
+        (
+            scanner.get_token_pos(),
+            scanner.get_text_pos(),
+            scanner.get_token(),
+            scanner.get_token_value(),
+            scanner.get_token_flags(),
+            scanner.get_errors().len(),
+        )
+    }
+
+    #[test]
+    fn look_ahead_rolls_back() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("\n0x1 ( b ) => 'x", None, None);
+        scanner.scan();
+        let before = snapshot(&scanner);
+        assert_eq!(
+            before,
+            (
+                1,
+                4,
+                SyntaxKind::NumericLiteral,
+                String::from("1"),
+                TokenFlags::PRECEDING_LINE_BREAK | TokenFlags::HEX_SPECIFIER,
+                0
+            )
+        );
+
+        let next = scanner.look_ahead(|scanner| {
+            scanner.scan();
+            scanner.scan()
+        });
+        assert_eq!(next, SyntaxKind::Identifier);
+        assert_eq!(snapshot(&scanner), before);
+
+        // Errors reported while looking ahead are dropped along with the tokens
+        scanner.look_ahead(|scanner| while scanner.scan() != SyntaxKind::EndOfFileToken {});
+        assert_eq!(snapshot(&scanner), before);
+        assert_eq!(scanner.scan(), SyntaxKind::OpenParenToken);
+    }
+
+    #[test]
+    fn try_scan_keeps_successful_scans() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("a ( b ) => 'x", None, None);
+        scanner.scan();
+        let before = snapshot(&scanner);
+
+        let arrow = scanner.try_scan(|scanner| {
+            if scanner.scan() == SyntaxKind::CloseParenToken {
+                Some(())
+            } else {
+                None
+            }
+        });
+        assert_eq!(arrow, None);
+        assert_eq!(snapshot(&scanner), before);
+
+        let paren = scanner.try_scan(|scanner| {
+            if scanner.scan() == SyntaxKind::OpenParenToken {
+                Some(scanner.get_text_pos())
+            } else {
+                None
+            }
+        });
+        assert_eq!(paren, Some(3));
+        assert_eq!(scanner.get_token(), SyntaxKind::OpenParenToken);
+
+        let errors = scanner.try_scan(|scanner| {
+            while scanner.scan() != SyntaxKind::EndOfFileToken {}
+            Some(scanner.get_errors().len())
+        });
+        assert_eq!(errors, Some(1));
+        assert_eq!(scanner.get_errors().len(), 1);
+        assert_eq!(scanner.get_token(), SyntaxKind::EndOfFileToken);
+    }
+
+    #[test]
+    fn scan_range_restores_the_scanner() {
+        let mut scanner = Scanner::new(true);
+        scanner.set_text("a ( b ) => 'x", None, None);
+        scanner.scan();
+        scanner.scan();
+        let before = snapshot(&scanner);
+
+        let inner = scanner.scan_range(4, 3, |scanner| {
+            (scanner.scan(), scanner.scan(), scanner.scan())
+        });
+        assert_eq!(
+            inner,
+            (
+                SyntaxKind::Identifier,
+                SyntaxKind::CloseParenToken,
+                SyntaxKind::EndOfFileToken
+            )
+        );
+        assert_eq!(snapshot(&scanner), before);
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+
+        // Errors reported inside the range are kept
+        scanner.scan_range(11, 2, |scanner| scanner.scan());
+        assert_eq!(scanner.get_errors().len(), 1);
+        assert_eq!(scanner.get_token(), SyntaxKind::Identifier);
+        assert_eq!(scanner.scan(), SyntaxKind::CloseParenToken);
+        assert_eq!(scanner.scan(), SyntaxKind::EqualsGreaterThanToken);
+        assert_eq!(scanner.scan(), SyntaxKind::StringLiteral);
+    }
+}
@@ -0,0 +1,356 @@
+use crate::compiler::scanner::Scanner;
+use crate::types::{LanguageVariant, ScriptTarget, SyntaxKind};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// The number of `u32`s describing each token in a `TokenBuffer`: kind, pos, end and flags.
+pub const TOKEN_STRIDE: usize = 4;
+
+/// The tokens of a whole file, packed so they can be handed to JavaScript in one call.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct TokenBuffer {
+    tokens: Vec<u32>,
+    // The values as UTF-16, so that lone surrogates from escapes survive, stored back to back.
+    value_units: Vec<u16>,
+    value_ends: Vec<u32>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl TokenBuffer {
+    /// The packed `(kind, pos, end, flags)` tuples, ending with the `EndOfFileToken`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTokens"))]
+    pub fn get_tokens(&self) -> Vec<u32> {
+        self.tokens.clone()
+    }
+
+    /// The UTF-16 code units of the values of the tokens that carry one, in token order and back
+    /// to back. See `token_has_value`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getValueUnits"))]
+    pub fn get_value_units(&self) -> Vec<u16> {
+        self.value_units.clone()
+    }
+
+    /// Where each value ends in `getValueUnits`; each one starts where the previous one ends.
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getValueEnds"))]
+    pub fn get_value_ends(&self) -> Vec<u32> {
+        self.value_ends.clone()
+    }
+
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getTokenCount"))]
+    pub fn get_token_count(&self) -> usize {
+        self.tokens.len() / TOKEN_STRIDE
+    }
+}
+
+impl TokenBuffer {
+    pub fn tokens(&self) -> &[u32] {
+        &self.tokens
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &[u16]> {
+        let starts = std::iter::once(0).chain(self.value_ends.iter().cloned());
+        starts
+            .zip(self.value_ends.iter().cloned())
+            .map(move |(start, end)| &self.value_units[start as usize..end as usize])
+    }
+}
+
+/// Whether tokens of this kind have an entry in the value table of a `TokenBuffer`. The value of
+/// any other token is its text, or the spelling given by `tokenToString`.
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "tokenHasValue"))]
+pub fn token_has_value(kind: SyntaxKind) -> bool {
+    kind == SyntaxKind::Identifier
+        || kind.is_literal_kind()
+        || kind == SyntaxKind::TemplateHead
+        || kind == SyntaxKind::TemplateMiddle
+        || kind == SyntaxKind::TemplateTail
+}
+
+/// Scans all of `text`, skipping trivia. Without a parser to say what is expected, `}` and `/`
+/// are rescanned the way the parser would in well-formed code: a `}` that closes a `${`
+/// continues its template, and `/` or `/=` where an expression can start, judged by the previous
+/// token, begins a regular expression. `>` is left unmerged and JSX text is not recognized.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn tokenize(text: &str, target: ScriptTarget, variant: LanguageVariant) -> TokenBuffer {
+    let mut scanner = Scanner::new(true);
+    scanner.set_script_target(target);
+    scanner.set_language_variant(variant);
+    scanner.set_text(text, None, None);
+
+    let mut buffer = TokenBuffer {
+        tokens: Vec::new(),
+        value_units: Vec::new(),
+        value_ends: Vec::new(),
+    };
+    // One entry per open brace, true for the `${` of a template substitution.
+    let mut braces: Vec<bool> = Vec::new();
+    let mut previous = SyntaxKind::Unknown;
+    loop {
+        let mut kind = scanner.scan();
+        match kind {
+            SyntaxKind::OpenBraceToken => braces.push(false),
+            SyntaxKind::CloseBraceToken if braces.last() == Some(&true) => {
+                braces.pop();
+                kind = scanner.re_scan_template_token(false);
+            }
+            SyntaxKind::CloseBraceToken => {
+                braces.pop();
+            }
+            SyntaxKind::SlashToken | SyntaxKind::SlashEqualsToken
+                if can_precede_expression(previous) =>
+            {
+                kind = scanner.re_scan_slash_token();
+            }
+            _ => {}
+        }
+        if kind == SyntaxKind::TemplateHead || kind == SyntaxKind::TemplateMiddle {
+            braces.push(true);
+        }
+
+        buffer.tokens.extend_from_slice(&[
+            kind as u32,
+            scanner.get_token_pos() as u32,
+            scanner.get_text_pos() as u32,
+            scanner.get_token_flags().bits(),
+        ]);
+        if token_has_value(kind) {
+            buffer
+                .value_units
+                .extend_from_slice(&scanner.get_token_value_utf16());
+            buffer.value_ends.push(buffer.value_units.len() as u32);
+        }
+        if kind == SyntaxKind::EndOfFileToken {
+            return buffer;
+        }
+        previous = kind;
+    }
+}
+
+// Whether an expression can start after a token of this kind, i.e. it doesn't end one. `)` and
+// `}` are taken to end one, as in `(a) / b` and `{} / 1`, though they can also close a statement.
+fn can_precede_expression(kind: SyntaxKind) -> bool {
+    match kind {
+        SyntaxKind::Identifier
+        | SyntaxKind::NoSubstitutionTemplateLiteral
+        | SyntaxKind::TemplateTail
+        | SyntaxKind::CloseParenToken
+        | SyntaxKind::CloseBracketToken
+        | SyntaxKind::CloseBraceToken
+        | SyntaxKind::PlusPlusToken
+        | SyntaxKind::MinusMinusToken
+        | SyntaxKind::ThisKeyword
+        | SyntaxKind::SuperKeyword
+        | SyntaxKind::NullKeyword
+        | SyntaxKind::TrueKeyword
+        | SyntaxKind::FalseKeyword => false,
+        _ if kind.is_literal_kind() => false,
+        // Other keywords, like `return` or `typeof`, can be followed by an expression, while
+        // contextual keywords such as `of` or `type` are usually identifiers.
+        _ if kind.is_keyword() => kind.is_reserved_word(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TokenFlags;
+    use SyntaxKind::*;
+
+    // The expected tuples are what TypeScript's scanner produces when driven by its parser.
+    type Token = (SyntaxKind, u32, u32, u32);
+
+    fn tokens(text: &str) -> (Vec<Token>, Vec<String>) {
+        let buffer = tokenize(text, ScriptTarget::Latest, LanguageVariant::Standard);
+        let tokens = buffer
+            .tokens()
+            .chunks(TOKEN_STRIDE)
+            .map(|token| {
+                let kind = num_traits::FromPrimitive::from_u32(token[0]).unwrap();
+                (kind, token[1], token[2], token[3])
+            })
+            .collect();
+        let values = buffer
+            .values()
+            .map(|value| String::from_utf16(value).unwrap())
+            .collect();
+        (tokens, values)
+    }
+
+    fn kinds(text: &str) -> Vec<SyntaxKind> {
+        tokens(text).0.into_iter().map(|token| token.0).collect()
+    }
+
+    #[test]
+    fn template_substitutions() {
+        assert_eq!(
+            tokens("x = `a${b}c${d}e`;"),
+            (
+                vec![
+                    (Identifier, 0, 1, 0),
+                    (EqualsToken, 2, 3, 0),
+                    (TemplateHead, 4, 8, 0),
+                    (Identifier, 8, 9, 0),
+                    (TemplateMiddle, 9, 13, 0),
+                    (Identifier, 13, 14, 0),
+                    (TemplateTail, 14, 17, 0),
+                    (SemicolonToken, 17, 18, 0),
+                    (EndOfFileToken, 18, 18, 0),
+                ],
+                vec!["x", "a", "b", "c", "d", "e"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            )
+        );
+    }
+
+    #[test]
+    fn braces_inside_substitutions() {
+        assert_eq!(
+            kinds("`a${ {b: `c${d}`} }e`"),
+            vec![
+                TemplateHead,
+                OpenBraceToken,
+                Identifier,
+                ColonToken,
+                TemplateHead,
+                Identifier,
+                TemplateTail,
+                CloseBraceToken,
+                TemplateTail,
+                EndOfFileToken,
+            ]
+        );
+        assert_eq!(
+            kinds("f(() => { return `${x}`; }) / 2"),
+            vec![
+                Identifier,
+                OpenParenToken,
+                OpenParenToken,
+                CloseParenToken,
+                EqualsGreaterThanToken,
+                OpenBraceToken,
+                ReturnKeyword,
+                TemplateHead,
+                Identifier,
+                TemplateTail,
+                SemicolonToken,
+                CloseBraceToken,
+                CloseParenToken,
+                SlashToken,
+                NumericLiteral,
+                EndOfFileToken,
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_substitution() {
+        assert_eq!(
+            tokens("`a${b").0,
+            vec![
+                (TemplateHead, 0, 4, 0),
+                (Identifier, 4, 5, 0),
+                (EndOfFileToken, 5, 5, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn regular_expressions() {
+        assert_eq!(
+            tokens("x = /ab+c/g.test(y);"),
+            (
+                vec![
+                    (Identifier, 0, 1, 0),
+                    (EqualsToken, 2, 3, 0),
+                    (RegularExpressionLiteral, 4, 11, 0),
+                    (DotToken, 11, 12, 0),
+                    (Identifier, 12, 16, 0),
+                    (OpenParenToken, 16, 17, 0),
+                    (Identifier, 17, 18, 0),
+                    (CloseParenToken, 18, 19, 0),
+                    (SemicolonToken, 19, 20, 0),
+                    (EndOfFileToken, 20, 20, 0),
+                ],
+                vec!["x", "/ab+c/g", "test", "y"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            )
+        );
+        let (after_return, values) = tokens("return /=/;");
+        assert_eq!(after_return[1], (RegularExpressionLiteral, 7, 10, 0));
+        assert_eq!(values, vec![String::from("/=/")]);
+        assert_eq!(kinds("if (!/[/]/.test(s)) {}")[3], RegularExpressionLiteral);
+        let (unterminated, _) = tokens("x = /a\ny");
+        assert_eq!(
+            unterminated[2],
+            (
+                RegularExpressionLiteral,
+                4,
+                6,
+                TokenFlags::UNTERMINATED.bits()
+            )
+        );
+    }
+
+    #[test]
+    fn values_keep_lone_surrogates() {
+        let buffer = tokenize(
+            r"'\uD800' `\uDC00${x}`",
+            ScriptTarget::Latest,
+            LanguageVariant::Standard,
+        );
+        let values: Vec<&[u16]> = buffer.values().collect();
+        assert_eq!(
+            values,
+            vec![
+                &[0xD800][..],
+                &[0xDC00][..],
+                &[u16::from(b'x')][..],
+                &[][..]
+            ]
+        );
+        assert_eq!(buffer.get_value_ends(), vec![1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn divisions() {
+        assert_eq!(
+            kinds("a / b /= c"),
+            vec![
+                Identifier,
+                SlashToken,
+                Identifier,
+                SlashEqualsToken,
+                Identifier,
+                EndOfFileToken,
+            ]
+        );
+        assert_eq!(
+            kinds("(a) / 2 / `t` / this / x[0] / 1"),
+            vec![
+                OpenParenToken,
+                Identifier,
+                CloseParenToken,
+                SlashToken,
+                NumericLiteral,
+                SlashToken,
+                NoSubstitutionTemplateLiteral,
+                SlashToken,
+                ThisKeyword,
+                SlashToken,
+                Identifier,
+                OpenBracketToken,
+                NumericLiteral,
+                CloseBracketToken,
+                SlashToken,
+                NumericLiteral,
+                EndOfFileToken,
+            ]
+        );
+        assert_eq!(kinds("of / 2")[1], SlashToken);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::types::{Diagnostic, DiagnosticMessage, SyntaxKind};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+/// The associativity of an expression of the given kind. `operator` is only consulted for
+/// binary expressions, and `has_arguments` only for `new` expressions.
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "getOperatorAssociativity"))]
+pub fn get_operator_associativity(
+    kind: SyntaxKind,
+    operator: SyntaxKind,
+    has_arguments: Option<bool>,
+) -> Associativity {
+    match kind {
+        SyntaxKind::NewExpression => {
+            if has_arguments.unwrap_or(false) {
+                Associativity::Left
+            } else {
+                Associativity::Right
+            }
+        }
+        SyntaxKind::PrefixUnaryExpression
+        | SyntaxKind::TypeOfExpression
+        | SyntaxKind::VoidExpression
+        | SyntaxKind::DeleteExpression
+        | SyntaxKind::AwaitExpression
+        | SyntaxKind::ConditionalExpression
+        | SyntaxKind::YieldExpression => Associativity::Right,
+        // `a ** b ** c` is `a ** (b ** c)`, and likewise for every assignment operator
+        SyntaxKind::BinaryExpression
+            if operator == SyntaxKind::AsteriskAsteriskToken
+                || operator.is_assignment_operator() =>
+        {
+            Associativity::Right
+        }
+        _ => Associativity::Left,
+    }
+}
+
+/// The precedence of a binary operator; higher binds tighter. Returns -1 for tokens that are
+/// not binary operators, which is lower than every other precedence so that binary expression
+/// parsing stops there.
+#[cfg_attr(
+    feature = "wasm",
+    wasm_bindgen(js_name = "getBinaryOperatorPrecedence")
+)]
+pub fn get_binary_operator_precedence(kind: SyntaxKind) -> i32 {
+    match kind {
+        SyntaxKind::BarBarToken => 5,
+        SyntaxKind::AmpersandAmpersandToken => 6,
+        SyntaxKind::BarToken => 7,
+        SyntaxKind::CaretToken => 8,
+        SyntaxKind::AmpersandToken => 9,
+        SyntaxKind::EqualsEqualsToken
+        | SyntaxKind::ExclamationEqualsToken
+        | SyntaxKind::EqualsEqualsEqualsToken
+        | SyntaxKind::ExclamationEqualsEqualsToken => 10,
+        SyntaxKind::LessThanToken
+        | SyntaxKind::GreaterThanToken
+        | SyntaxKind::LessThanEqualsToken
+        | SyntaxKind::GreaterThanEqualsToken
+        | SyntaxKind::InstanceOfKeyword
+        | SyntaxKind::InKeyword
+        | SyntaxKind::AsKeyword => 11,
+        SyntaxKind::LessThanLessThanToken
+        | SyntaxKind::GreaterThanGreaterThanToken
+        | SyntaxKind::GreaterThanGreaterThanGreaterThanToken => 12,
+        SyntaxKind::PlusToken | SyntaxKind::MinusToken => 13,
+        SyntaxKind::AsteriskToken | SyntaxKind::SlashToken | SyntaxKind::PercentToken => 14,
+        SyntaxKind::AsteriskAsteriskToken => 15,
+        _ => -1,
+    }
+}
+
+/// Substitutes `args` for the `{0}`, `{1}`, ... placeholders of a diagnostic message.
+pub fn format_string_from_args(text: &str, args: &[&str]) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        let placeholder = rest[open + 1..].find('}').and_then(|close| {
+            let index: usize = rest[open + 1..open + 1 + close].parse().ok()?;
+            Some((index, open + 1 + close))
+        });
+        match placeholder {
+            Some((index, close)) if index < args.len() => {
+                result.push_str(&rest[..open]);
+                result.push_str(args[index]);
+                rest = &rest[close + 1..];
+            }
+            _ => {
+                result.push_str(&rest[..=open]);
+                rest = &rest[open + 1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+pub fn create_diagnostic(
+    start: usize,
+    length: usize,
+    message: &DiagnosticMessage,
+    args: &[&str],
+) -> Diagnostic {
+    Diagnostic {
+        start,
+        length,
+        message_text: format_string_from_args(message.message, args),
+        category: message.category,
+        code: message.code,
+    }
+}
+
+/// Escapes an identifier so that it cannot collide with the internal symbol names, which start
+/// with two underscores: identifiers starting with `__` get one more underscore.
+pub fn escape_leading_underscores(identifier: &str) -> String {
+    if identifier.starts_with("__") {
+        format!("_{}", identifier)
+    } else {
+        identifier.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DiagnosticCategory;
+
+    #[test]
+    fn binary_operator_precedence() {
+        let precedence = get_binary_operator_precedence;
+        assert_eq!(precedence(SyntaxKind::BarBarToken), 5);
+        assert_eq!(precedence(SyntaxKind::AmpersandAmpersandToken), 6);
+        assert_eq!(precedence(SyntaxKind::EqualsEqualsEqualsToken), 10);
+        assert_eq!(precedence(SyntaxKind::InKeyword), 11);
+        assert_eq!(precedence(SyntaxKind::InstanceOfKeyword), 11);
+        assert_eq!(precedence(SyntaxKind::AsKeyword), 11);
+        assert_eq!(
+            precedence(SyntaxKind::GreaterThanGreaterThanGreaterThanToken),
+            12
+        );
+        assert_eq!(precedence(SyntaxKind::MinusToken), 13);
+        assert_eq!(precedence(SyntaxKind::PercentToken), 14);
+        assert_eq!(precedence(SyntaxKind::AsteriskAsteriskToken), 15);
+        assert_eq!(precedence(SyntaxKind::EqualsToken), -1);
+        assert_eq!(precedence(SyntaxKind::QuestionToken), -1);
+        assert_eq!(precedence(SyntaxKind::CommaToken), -1);
+    }
+
+    #[test]
+    fn operator_associativity() {
+        let binary =
+            |operator| get_operator_associativity(SyntaxKind::BinaryExpression, operator, None);
+        assert_eq!(
+            binary(SyntaxKind::AsteriskAsteriskToken),
+            Associativity::Right
+        );
+        assert_eq!(binary(SyntaxKind::EqualsToken), Associativity::Right);
+        assert_eq!(binary(SyntaxKind::PlusEqualsToken), Associativity::Right);
+        assert_eq!(binary(SyntaxKind::MinusToken), Associativity::Left);
+        assert_eq!(binary(SyntaxKind::InKeyword), Associativity::Left);
+        assert_eq!(binary(SyntaxKind::CommaToken), Associativity::Left);
+
+        let new_expression = |has_arguments| {
+            get_operator_associativity(
+                SyntaxKind::NewExpression,
+                SyntaxKind::Unknown,
+                has_arguments,
+            )
+        };
+        assert_eq!(new_expression(Some(true)), Associativity::Left);
+        assert_eq!(new_expression(Some(false)), Associativity::Right);
+        assert_eq!(new_expression(None), Associativity::Right);
+
+        let unary = |kind| get_operator_associativity(kind, SyntaxKind::Unknown, None);
+        assert_eq!(
+            unary(SyntaxKind::ConditionalExpression),
+            Associativity::Right
+        );
+        assert_eq!(unary(SyntaxKind::AwaitExpression), Associativity::Right);
+        assert_eq!(
+            unary(SyntaxKind::PostfixUnaryExpression),
+            Associativity::Left
+        );
+        assert_eq!(unary(SyntaxKind::CallExpression), Associativity::Left);
+    }
+
+    #[test]
+    fn format_diagnostic_arguments() {
+        assert_eq!(
+            format_string_from_args("'{0}' expected.", &[";"]),
+            "';' expected."
+        );
+        assert_eq!(
+            format_string_from_args("{1} and {0}", &["a", "b"]),
+            "b and a"
+        );
+        assert_eq!(format_string_from_args("{0}{0}", &["a"]), "aa");
+        // Placeholders without an argument, and braces that are not placeholders, are kept
+        assert_eq!(
+            format_string_from_args("{0} {1} {x} {", &["a"]),
+            "a {1} {x} {"
+        );
+        assert_eq!(format_string_from_args("no arguments", &[]), "no arguments");
+    }
+
+    #[test]
+    fn create_diagnostic_from_message() {
+        let message = DiagnosticMessage {
+            key: "_0_expected_1005",
+            category: DiagnosticCategory::Error,
+            code: 1005,
+            message: "'{0}' expected.",
+            reports_unnecessary: false,
+        };
+        assert_eq!(
+            create_diagnostic(3, 1, &message, &["}"]),
+            Diagnostic {
+                start: 3,
+                length: 1,
+                message_text: String::from("'}' expected."),
+                category: DiagnosticCategory::Error,
+                code: 1005,
+            }
+        );
+    }
+}
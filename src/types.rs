@@ -1,6 +1,10 @@
+use bitflags::bitflags;
 use num_derive::FromPrimitive;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
 
-#[derive(FromPrimitive)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(FromPrimitive, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SyntaxKind {
     Unknown = 0,
     EndOfFileToken,
@@ -368,7 +372,282 @@ pub enum SyntaxKind {
     Count,
 }
 
-#[derive(FromPrimitive, PartialEq, PartialOrd)]
+// Markers delimiting ranges of SyntaxKind. Rust enums cannot have two variants with the same
+// discriminant, so unlike in TypeScript these are associated constants rather than variants.
+#[allow(non_upper_case_globals)]
+impl SyntaxKind {
+    pub const FirstAssignment: SyntaxKind = SyntaxKind::EqualsToken;
+    pub const LastAssignment: SyntaxKind = SyntaxKind::CaretEqualsToken;
+    pub const FirstCompoundAssignment: SyntaxKind = SyntaxKind::PlusEqualsToken;
+    pub const LastCompoundAssignment: SyntaxKind = SyntaxKind::CaretEqualsToken;
+    pub const FirstReservedWord: SyntaxKind = SyntaxKind::BreakKeyword;
+    pub const LastReservedWord: SyntaxKind = SyntaxKind::WithKeyword;
+    pub const FirstKeyword: SyntaxKind = SyntaxKind::BreakKeyword;
+    pub const LastKeyword: SyntaxKind = SyntaxKind::OfKeyword;
+    // Internal in TypeScript, so not part of the public API baseline
+    pub const FirstContextualKeyword: SyntaxKind = SyntaxKind::AbstractKeyword;
+    pub const LastContextualKeyword: SyntaxKind = SyntaxKind::OfKeyword;
+    pub const FirstFutureReservedWord: SyntaxKind = SyntaxKind::ImplementsKeyword;
+    pub const LastFutureReservedWord: SyntaxKind = SyntaxKind::YieldKeyword;
+    pub const FirstTypeNode: SyntaxKind = SyntaxKind::TypePredicate;
+    pub const LastTypeNode: SyntaxKind = SyntaxKind::ImportType;
+    pub const FirstPunctuation: SyntaxKind = SyntaxKind::OpenBraceToken;
+    pub const LastPunctuation: SyntaxKind = SyntaxKind::CaretEqualsToken;
+    pub const FirstToken: SyntaxKind = SyntaxKind::Unknown;
+    pub const LastToken: SyntaxKind = SyntaxKind::OfKeyword;
+    pub const FirstTriviaToken: SyntaxKind = SyntaxKind::SingleLineCommentTrivia;
+    pub const LastTriviaToken: SyntaxKind = SyntaxKind::ConflictMarkerTrivia;
+    pub const FirstLiteralToken: SyntaxKind = SyntaxKind::NumericLiteral;
+    pub const LastLiteralToken: SyntaxKind = SyntaxKind::NoSubstitutionTemplateLiteral;
+    pub const FirstTemplateToken: SyntaxKind = SyntaxKind::NoSubstitutionTemplateLiteral;
+    pub const LastTemplateToken: SyntaxKind = SyntaxKind::TemplateTail;
+    pub const FirstBinaryOperator: SyntaxKind = SyntaxKind::LessThanToken;
+    pub const LastBinaryOperator: SyntaxKind = SyntaxKind::CaretEqualsToken;
+    pub const FirstNode: SyntaxKind = SyntaxKind::QualifiedName;
+    pub const FirstJSDocNode: SyntaxKind = SyntaxKind::JSDocTypeExpression;
+    pub const LastJSDocNode: SyntaxKind = SyntaxKind::JSDocPropertyTag;
+    pub const FirstJSDocTagNode: SyntaxKind = SyntaxKind::JSDocTag;
+    pub const LastJSDocTagNode: SyntaxKind = SyntaxKind::JSDocPropertyTag;
+}
+
+impl SyntaxKind {
+    pub fn is_keyword(self) -> bool {
+        SyntaxKind::FirstKeyword <= self && self <= SyntaxKind::LastKeyword
+    }
+
+    /// Keywords that are only reserved in particular contexts, e.g. `async`, `type` or `of`.
+    pub fn is_contextual_keyword(self) -> bool {
+        SyntaxKind::FirstContextualKeyword <= self && self <= SyntaxKind::LastContextualKeyword
+    }
+
+    /// Keywords that can never be used as identifiers, from `break` to `with`.
+    pub fn is_reserved_word(self) -> bool {
+        SyntaxKind::FirstReservedWord <= self && self <= SyntaxKind::LastReservedWord
+    }
+
+    /// Keywords that are reserved only in strict mode code, from `implements` to `yield`.
+    pub fn is_strict_mode_reserved_word(self) -> bool {
+        SyntaxKind::FirstFutureReservedWord <= self && self <= SyntaxKind::LastFutureReservedWord
+    }
+
+    pub fn is_punctuation(self) -> bool {
+        SyntaxKind::FirstPunctuation <= self && self <= SyntaxKind::LastPunctuation
+    }
+
+    pub fn is_assignment_operator(self) -> bool {
+        SyntaxKind::FirstAssignment <= self && self <= SyntaxKind::LastAssignment
+    }
+
+    pub fn is_literal_kind(self) -> bool {
+        SyntaxKind::FirstLiteralToken <= self && self <= SyntaxKind::LastLiteralToken
+    }
+
+    pub fn is_trivia(self) -> bool {
+        SyntaxKind::FirstTriviaToken <= self && self <= SyntaxKind::LastTriviaToken
+    }
+
+    pub fn token_is_identifier_or_keyword(self) -> bool {
+        self >= SyntaxKind::Identifier
+    }
+
+    pub fn token_is_identifier_or_keyword_or_greater_than(self) -> bool {
+        self == SyntaxKind::GreaterThanToken || self.token_is_identifier_or_keyword()
+    }
+}
+
+bitflags! {
+    #[derive(Default)]
+    pub struct NodeFlags: u32 {
+        const NONE = 0;
+        const LET = 1 << 0; // Variable declaration
+        const CONST = 1 << 1; // Variable declaration
+        const NESTED_NAMESPACE = 1 << 2; // Namespace declaration
+        const SYNTHESIZED = 1 << 3; // Node was synthesized during transformation
+        const NAMESPACE = 1 << 4; // Namespace declaration
+        const EXPORT_CONTEXT = 1 << 5; // Export context (initialized by binding)
+        const CONTAINS_THIS = 1 << 6; // Interface contains references to "this"
+        const HAS_IMPLICIT_RETURN = 1 << 7; // If function implicitly returns on one of codepaths (initialized by binding)
+        const HAS_EXPLICIT_RETURN = 1 << 8; // If function has explicit reachable return on one of codepaths (initialized by binding)
+        const GLOBAL_AUGMENTATION = 1 << 9; // Set if module declaration is an augmentation for the global scope
+        const HAS_ASYNC_FUNCTIONS = 1 << 10; // If the file has async functions (initialized by binding)
+        const DISALLOW_IN_CONTEXT = 1 << 11; // If node was parsed in a context where 'in-expressions' are not allowed
+        const YIELD_CONTEXT = 1 << 12; // If node was parsed in the 'yield' context created when parsing a generator
+        const DECORATOR_CONTEXT = 1 << 13; // If node was parsed as part of a decorator
+        const AWAIT_CONTEXT = 1 << 14; // If node was parsed in the 'await' context created when parsing an async function
+        const THIS_NODE_HAS_ERROR = 1 << 15; // If the parser encountered an error when parsing the code that created this node
+        const JAVASCRIPT_FILE = 1 << 16; // If node was parsed in a JavaScript
+        const THIS_NODE_OR_ANY_SUB_NODES_HAS_ERROR = 1 << 17; // If this node or any of its children had an error
+        const HAS_AGGREGATED_CHILD_DATA = 1 << 18; // If we've computed data from children and cached it in this node
+        const JSDOC = 1 << 21; // If node was parsed inside jsdoc
+        const JSON_FILE = 1 << 24; // If node was parsed in a Json
+
+        const BLOCK_SCOPED = Self::LET.bits | Self::CONST.bits;
+        const REACHABILITY_CHECK_FLAGS = Self::HAS_IMPLICIT_RETURN.bits | Self::HAS_EXPLICIT_RETURN.bits;
+        const REACHABILITY_AND_EMIT_FLAGS =
+            Self::REACHABILITY_CHECK_FLAGS.bits | Self::HAS_ASYNC_FUNCTIONS.bits;
+
+        // Parsing context flags
+        const CONTEXT_FLAGS = Self::DISALLOW_IN_CONTEXT.bits
+            | Self::YIELD_CONTEXT.bits
+            | Self::DECORATOR_CONTEXT.bits
+            | Self::AWAIT_CONTEXT.bits
+            | Self::JAVASCRIPT_FILE.bits
+            | Self::JSDOC.bits
+            | Self::JSON_FILE.bits;
+
+        // Exclude these flags when parsing a Type
+        const TYPE_EXCLUDES_FLAGS = Self::YIELD_CONTEXT.bits | Self::AWAIT_CONTEXT.bits;
+    }
+}
+
+bitflags! {
+    #[derive(Default)]
+    pub struct ModifierFlags: u32 {
+        const NONE = 0;
+        const EXPORT = 1 << 0; // Declarations
+        const AMBIENT = 1 << 1; // Declarations
+        const PUBLIC = 1 << 2; // Property/Method
+        const PRIVATE = 1 << 3; // Property/Method
+        const PROTECTED = 1 << 4; // Property/Method
+        const STATIC = 1 << 5; // Property/Method
+        const READONLY = 1 << 6; // Property/Method
+        const ABSTRACT = 1 << 7; // Class/Method/ConstructSignature
+        const ASYNC = 1 << 8; // Property/Method/Function
+        const DEFAULT = 1 << 9; // Function/Class (export default declaration)
+        const CONST = 1 << 11; // Const enum
+        const HAS_COMPUTED_FLAGS = 1 << 29; // Modifier flags have been computed
+
+        const ACCESSIBILITY_MODIFIER = Self::PUBLIC.bits | Self::PRIVATE.bits | Self::PROTECTED.bits;
+        // Accessibility modifiers and 'readonly' can be attached to a parameter in a constructor to make it a property.
+        const PARAMETER_PROPERTY_MODIFIER = Self::ACCESSIBILITY_MODIFIER.bits | Self::READONLY.bits;
+        const NON_PUBLIC_ACCESSIBILITY_MODIFIER = Self::PRIVATE.bits | Self::PROTECTED.bits;
+
+        const TYPESCRIPT_MODIFIER = Self::AMBIENT.bits
+            | Self::PUBLIC.bits
+            | Self::PRIVATE.bits
+            | Self::PROTECTED.bits
+            | Self::READONLY.bits
+            | Self::ABSTRACT.bits
+            | Self::CONST.bits;
+        const EXPORT_DEFAULT = Self::EXPORT.bits | Self::DEFAULT.bits;
+        const ALL = Self::EXPORT.bits
+            | Self::AMBIENT.bits
+            | Self::PUBLIC.bits
+            | Self::PRIVATE.bits
+            | Self::PROTECTED.bits
+            | Self::STATIC.bits
+            | Self::READONLY.bits
+            | Self::ABSTRACT.bits
+            | Self::ASYNC.bits
+            | Self::DEFAULT.bits
+            | Self::CONST.bits;
+    }
+}
+
+bitflags! {
+    /// Facts about the current token that its kind alone doesn't capture. The bit values match
+    /// TypeScript's `TokenFlags` enum.
+    #[derive(Default)]
+    pub struct TokenFlags: u32 {
+        const NONE = 0;
+        const PRECEDING_LINE_BREAK = 1 << 0;
+        const PRECEDING_JSDOC_COMMENT = 1 << 1;
+        const UNTERMINATED = 1 << 2;
+        const EXTENDED_UNICODE_ESCAPE = 1 << 3;
+        const SCIENTIFIC = 1 << 4; // e.g. `10e2`
+        const OCTAL = 1 << 5; // e.g. `0777`
+        const HEX_SPECIFIER = 1 << 6; // e.g. `0x00000000`
+        const BINARY_SPECIFIER = 1 << 7; // e.g. `0b0110010000000000`
+        const OCTAL_SPECIFIER = 1 << 8; // e.g. `0o777`
+        const CONTAINS_SEPARATOR = 1 << 9; // e.g. `0b1100_0101`
+        const UNICODE_ESCAPE = 1 << 10; // e.g. `\u0061bc`
+        const CONTAINS_INVALID_ESCAPE = 1 << 11; // e.g. `\uhello`
+        const BINARY_OR_OCTAL_SPECIFIER = Self::BINARY_SPECIFIER.bits | Self::OCTAL_SPECIFIER.bits;
+        const NUMERIC_LITERAL_FLAGS = Self::SCIENTIFIC.bits
+            | Self::OCTAL.bits
+            | Self::HEX_SPECIFIER.bits
+            | Self::BINARY_OR_OCTAL_SPECIFIER.bits
+            | Self::CONTAINS_SEPARATOR.bits;
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineAndCharacter {
+    /// 0-based.
+    pub line: usize,
+    pub character: usize,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommentRange {
+    pub pos: usize,
+    pub end: usize,
+    // SingleLineCommentTrivia or MultiLineCommentTrivia
+    pub kind: SyntaxKind,
+    #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = "hasTrailingNewLine"))]
+    pub has_trailing_new_line: bool,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticCategory {
+    Warning,
+    Error,
+    Suggestion,
+    Message,
+}
+
+/// An entry of the diagnostic message catalog (see `compiler::diagnostics`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DiagnosticMessage {
+    pub key: &'static str,
+    pub category: DiagnosticCategory,
+    pub code: u32,
+    pub message: &'static str,
+    pub reports_unnecessary: bool,
+}
+
+/// A diagnostic covering `length` characters starting at `start`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub start: usize,
+    pub length: usize,
+    pub message_text: String,
+    pub category: DiagnosticCategory,
+    pub code: u32,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LanguageVariant {
+    Standard,
+    JSX,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScriptTarget {
+    ES3 = 0,
+    ES5 = 1,
+    ES2015 = 2,
+    ES2016 = 3,
+    ES2017 = 4,
+    ES2018 = 5,
+    ES2019 = 6,
+    ESNext = 7,
+    JSON = 100,
+}
+
+#[allow(non_upper_case_globals)]
+impl ScriptTarget {
+    pub const Latest: ScriptTarget = ScriptTarget::ESNext;
+}
+
+#[derive(FromPrimitive, Clone, Copy, Debug, PartialEq, PartialOrd)]
 pub enum CharacterCodes {
     NullCharacter = 0,
     MaxAsciiCharacter = 0x7F,
@@ -504,3 +783,134 @@ pub enum CharacterCodes {
     Tab = 0x09,         // \t
     VerticalTab = 0x0B, // \v
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SyntaxKind;
+    use num_traits::FromPrimitive;
+    use std::collections::HashMap;
+
+    const API_BASELINE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/TypeScript-replace/tests/baselines/reference/api/typescript.d.ts"
+    );
+
+    // Parses the `Name = value` members of `enum SyntaxKind` in the public API baseline.
+    fn baseline_syntax_kinds() -> Vec<(String, u32)> {
+        let baseline = std::fs::read_to_string(API_BASELINE).unwrap();
+        let start = baseline.find("    enum SyntaxKind {").unwrap();
+        let end = start + baseline[start..].find('}').unwrap();
+        baseline[start..end]
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (name, value) = line.trim().trim_end_matches(',').split_once(" = ").unwrap();
+                (name.to_string(), value.parse().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn syntax_kind_discriminants_match_typescript() {
+        let mut kinds: HashMap<String, u32> = (0..=SyntaxKind::Count as u32)
+            .map(|value| {
+                let kind: SyntaxKind = FromPrimitive::from_u32(value).unwrap();
+                (format!("{:?}", kind), value)
+            })
+            .collect();
+        let markers = [
+            ("FirstAssignment", SyntaxKind::FirstAssignment),
+            ("LastAssignment", SyntaxKind::LastAssignment),
+            (
+                "FirstCompoundAssignment",
+                SyntaxKind::FirstCompoundAssignment,
+            ),
+            ("LastCompoundAssignment", SyntaxKind::LastCompoundAssignment),
+            ("FirstReservedWord", SyntaxKind::FirstReservedWord),
+            ("LastReservedWord", SyntaxKind::LastReservedWord),
+            ("FirstKeyword", SyntaxKind::FirstKeyword),
+            ("LastKeyword", SyntaxKind::LastKeyword),
+            (
+                "FirstFutureReservedWord",
+                SyntaxKind::FirstFutureReservedWord,
+            ),
+            ("LastFutureReservedWord", SyntaxKind::LastFutureReservedWord),
+            ("FirstTypeNode", SyntaxKind::FirstTypeNode),
+            ("LastTypeNode", SyntaxKind::LastTypeNode),
+            ("FirstPunctuation", SyntaxKind::FirstPunctuation),
+            ("LastPunctuation", SyntaxKind::LastPunctuation),
+            ("FirstToken", SyntaxKind::FirstToken),
+            ("LastToken", SyntaxKind::LastToken),
+            ("FirstTriviaToken", SyntaxKind::FirstTriviaToken),
+            ("LastTriviaToken", SyntaxKind::LastTriviaToken),
+            ("FirstLiteralToken", SyntaxKind::FirstLiteralToken),
+            ("LastLiteralToken", SyntaxKind::LastLiteralToken),
+            ("FirstTemplateToken", SyntaxKind::FirstTemplateToken),
+            ("LastTemplateToken", SyntaxKind::LastTemplateToken),
+            ("FirstBinaryOperator", SyntaxKind::FirstBinaryOperator),
+            ("LastBinaryOperator", SyntaxKind::LastBinaryOperator),
+            ("FirstNode", SyntaxKind::FirstNode),
+            ("FirstJSDocNode", SyntaxKind::FirstJSDocNode),
+            ("LastJSDocNode", SyntaxKind::LastJSDocNode),
+            ("FirstJSDocTagNode", SyntaxKind::FirstJSDocTagNode),
+            ("LastJSDocTagNode", SyntaxKind::LastJSDocTagNode),
+        ];
+        for &(name, kind) in markers.iter() {
+            kinds.insert(name.to_string(), kind as u32);
+        }
+
+        let baseline = baseline_syntax_kinds();
+        for (name, value) in baseline.iter() {
+            assert_eq!(kinds.get(name), Some(value), "SyntaxKind.{}", name);
+        }
+        assert_eq!(kinds.len(), baseline.len());
+    }
+
+    #[test]
+    fn keyword_predicates() {
+        use SyntaxKind::*;
+        assert!(BreakKeyword.is_keyword() && OfKeyword.is_keyword());
+        assert!(!Identifier.is_keyword() && !CaretEqualsToken.is_keyword());
+
+        assert!(AbstractKeyword.is_contextual_keyword());
+        assert!(AsyncKeyword.is_contextual_keyword() && OfKeyword.is_contextual_keyword());
+        assert!(!WithKeyword.is_contextual_keyword() && !YieldKeyword.is_contextual_keyword());
+
+        assert!(BreakKeyword.is_reserved_word() && WithKeyword.is_reserved_word());
+        assert!(!ImplementsKeyword.is_reserved_word() && !LetKeyword.is_reserved_word());
+
+        assert!(ImplementsKeyword.is_strict_mode_reserved_word());
+        assert!(LetKeyword.is_strict_mode_reserved_word());
+        assert!(YieldKeyword.is_strict_mode_reserved_word());
+        assert!(!WithKeyword.is_strict_mode_reserved_word());
+        assert!(!AbstractKeyword.is_strict_mode_reserved_word());
+    }
+
+    #[test]
+    fn token_predicates() {
+        use SyntaxKind::*;
+        assert!(OpenBraceToken.is_punctuation() && CaretEqualsToken.is_punctuation());
+        assert!(!Identifier.is_punctuation() && !TemplateTail.is_punctuation());
+
+        assert!(EqualsToken.is_assignment_operator());
+        assert!(PlusEqualsToken.is_assignment_operator());
+        assert!(CaretEqualsToken.is_assignment_operator());
+        assert!(!EqualsEqualsToken.is_assignment_operator());
+        assert!(!EqualsGreaterThanToken.is_assignment_operator());
+
+        assert!(NumericLiteral.is_literal_kind() && BigIntLiteral.is_literal_kind());
+        assert!(NoSubstitutionTemplateLiteral.is_literal_kind());
+        assert!(!TemplateHead.is_literal_kind() && !Identifier.is_literal_kind());
+
+        assert!(SingleLineCommentTrivia.is_trivia() && ConflictMarkerTrivia.is_trivia());
+        assert!(ShebangTrivia.is_trivia() && !NumericLiteral.is_trivia());
+        assert!(!Unknown.is_trivia() && !EndOfFileToken.is_trivia());
+
+        assert!(Identifier.token_is_identifier_or_keyword());
+        assert!(OfKeyword.token_is_identifier_or_keyword());
+        assert!(!GreaterThanToken.token_is_identifier_or_keyword());
+        assert!(GreaterThanToken.token_is_identifier_or_keyword_or_greater_than());
+        assert!(!LessThanToken.token_is_identifier_or_keyword_or_greater_than());
+    }
+}